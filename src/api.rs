@@ -1,34 +1,672 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write;
+#[cfg(feature = "record")]
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use futures::channel::oneshot;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 
-use crate::client::HttpClient;
+#[cfg(feature = "chrono")]
+use crate::calendar::{CalendarEntry, Horizon};
+use crate::batch::Batch;
+use crate::cache::Cache;
+use crate::calendar::{EarningsCalendarBuilder, IpoCalendarBuilder};
+use crate::client::{rapid_api_headers, HttpClient, HttpResponse, RAPID_API_HEADERS};
 use crate::crypto::{CryptoBuilder, CryptoFunction};
-use crate::custom::CustomBuilder;
+use crate::custom::{CustomBuilder, DataType};
 use crate::earning::EarningBuilder;
-use crate::economic_indicator::EconomicIndicatorBuilder;
-use crate::error::{Error, Result};
-use crate::exchange::ExchangeBuilder;
+use crate::economic_indicator::{
+    AlignedIndicators, EconomicIndicatorBuilder, EconomicIndicatorFunction, EconomicIndicatorSpec,
+};
+use crate::error::{body_snippet, detect_common_helper_error, Error, Result};
+use crate::exchange::{CrossRate, Currency, CurrencyCode, CurrencyKind, ExchangeBuilder};
 use crate::forex::{ForexBuilder, ForexFunction};
+use crate::hook::{
+    RequestHook, RequestInfo, ResponseHook, ResponseInfo, SharedRequestHook, SharedResponseHook,
+};
+use crate::metrics::{Metrics, MetricsSnapshot};
+use crate::middleware::{Middleware, PendingRequest, SharedMiddleware};
 use crate::quote::QuoteBuilder;
-use crate::search::SearchBuilder;
+#[cfg(feature = "rate-limit")]
+use crate::rate_limit::{RateLimitPolicy, RateLimiter};
+#[cfg(feature = "record")]
+use crate::record::{self, Cassette};
+#[cfg(feature = "retry")]
+use crate::retry::RetryPolicy;
+use crate::search::{Match, ResolvePrefs, SearchBuilder};
 use crate::stock_time::{StockFunction, TimeSeriesBuilder};
-use crate::technical_indicator::{TechnicalIndicatorBuilder, TechnicalIndicatorInterval};
+use crate::technical_indicator::{
+    IndicatorResult, IndicatorSpec, SingleValueIndicator, TechnicalIndicatorBuilder,
+    TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+};
 
 const BASE_URL: &str = "https://www.alphavantage.co/";
-const RAPID_API_BASE_URL: &str = "https://alpha-vantage.p.rapidapi.com/query";
+const RAPID_API_BASE_URL: &str = "https://alpha-vantage.p.rapidapi.com/";
+/// Name of environment variable from which API key is read by
+/// [`ApiClient::from_env`] and [`ApiClientBuilder::from_env`]
+pub const API_KEY_ENV: &str = "ALPHAVANTAGE_API_KEY";
+
+/// Struct for detecting error returned by API for CSV request
+#[derive(Deserialize)]
+struct CsvErrorHelper {
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+}
+
+// Error of body which is JSON containing information, error message or note
+// returned by API instead of data
+fn envelope_error(body: &[u8]) -> Option<Error> {
+    let helper = serde_json::from_slice::<CsvErrorHelper>(body).ok()?;
+    detect_common_helper_error(helper.information, helper.error_message, helper.note).err()
+}
+
+// Check whether body is JSON containing information, error message or note
+// returned by API instead of data
+fn is_error_envelope(body: &[u8]) -> bool {
+    envelope_error(body).is_some()
+}
+
+/// Parameters of query which identify symbol or currency of request
+#[cfg(feature = "tracing")]
+const SYMBOL_PARAMS: [&str; 8] = [
+    "symbol",
+    "keywords",
+    "tickers",
+    "from_currency",
+    "to_currency",
+    "from_symbol",
+    "to_symbol",
+    "market",
+];
+
+// Values of symbol like parameters of path joined with `/`, such as `IBM` or
+// `EUR/USD`
+#[cfg(feature = "tracing")]
+fn symbol_of(path: &str) -> Option<String> {
+    let (_, query) = path.split_once('?')?;
+    let values = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .filter(|(key, _)| SYMBOL_PARAMS.contains(key))
+        .map(|(_, value)| value)
+        .collect::<Vec<_>>();
+    (!values.is_empty()).then(|| values.join("/"))
+}
+
+// Error for JSON output which cannot be decoded into struct
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn decode_json_error(error: &serde_json::Error) -> Error {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(%error, "failed to decode JSON output");
+    Error::DecodeJsonToStruct
+}
+
+// Error for CSV output whose row cannot be decoded into struct
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn decode_csv_error(error: &csv::Error) -> Error {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(%error, "failed to decode CSV output");
+    Error::DecodeJsonToStruct
+}
+
+// Name of API function of path, empty if path does not contain function
+fn function_name(path: &str) -> &str {
+    path.split_once('?')
+        .and_then(|(_, query)| {
+            query
+                .split('&')
+                .find_map(|param| param.strip_prefix("function="))
+        })
+        .unwrap_or_default()
+}
+
+/// Functions whose output is always CSV so `datatype` is not accepted
+const CSV_ONLY_FUNCTIONS: [&str; 3] = ["LISTING_STATUS", "EARNINGS_CALENDAR", "IPO_CALENDAR"];
+
+// Format float with at most 10 decimal places without trailing zeros so
+// rounding error of float is not sent to API
+pub(crate) fn format_float(value: f64) -> String {
+    let formatted = format!("{value:.10}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    if formatted == "-0" {
+        "0".to_string()
+    } else {
+        formatted.to_string()
+    }
+}
+
+// Percent-encode value of query parameter. Unreserved characters along with
+// `,` and `:` used by list of symbols are kept as it is, and already encoded
+// sequence is not encoded again
+pub(crate) fn encode_query_value(value: &str) -> Cow<'_, str> {
+    let bytes = value.as_bytes();
+    let is_kept = |index: usize, byte: u8| {
+        byte.is_ascii_alphanumeric()
+            || b"-._~,:".contains(&byte)
+            || (byte == b'%'
+                && bytes
+                    .get(index + 1..index + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)))
+    };
+    if bytes
+        .iter()
+        .enumerate()
+        .all(|(index, &byte)| is_kept(index, byte))
+    {
+        return Cow::Borrowed(value);
+    }
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for (index, &byte) in bytes.iter().enumerate() {
+        if is_kept(index, byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+// Append `datatype=csv` to query path of function which supports both json
+// and csv output if data type is not already set
+fn csv_path(path: &str) -> String {
+    let Some(query) = path.strip_prefix("query?") else {
+        return path.to_string();
+    };
+    let mut params = query.split('&').filter_map(|param| param.split_once('='));
+    let csv_only = params
+        .clone()
+        .any(|(key, value)| key == "function" && CSV_ONLY_FUNCTIONS.contains(&value));
+    if csv_only || params.any(|(key, _)| key == "datatype") {
+        path.to_string()
+    } else {
+        format!("{path}&datatype=csv")
+    }
+}
+
+// Create vector of struct from rows of csv using header of csv
+pub(crate) fn parse_csv_rows<T>(text: &str) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    csv::Reader::from_reader(text.as_bytes())
+        .deserialize()
+        .collect::<std::result::Result<Vec<T>, _>>()
+        .map_err(|error| decode_csv_error(&error))
+}
+
+// Validate base url and normalize it to end with single slash
+fn normalize_base_url(base_url: String) -> Result<String> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    let host = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"));
+    if host.is_none_or(|host| host.is_empty() || host.contains(['?', '#'])) {
+        return Err(Error::InvalidParameter {
+            parameter: "base_url".to_string(),
+            value: base_url,
+        });
+    }
+    Ok(format!("{trimmed}/"))
+}
+
+/// Debug output of `Provider` does not contain value of headers of custom
+/// provider since they usually contain credentials
+impl std::fmt::Debug for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Provider::AlphaVantage => f.write_str("AlphaVantage"),
+            Provider::RapidAPI => f.write_str("RapidAPI"),
+            Provider::Custom {
+                base_url,
+                headers,
+                key_param,
+            } => f
+                .debug_struct("Custom")
+                .field("base_url", base_url)
+                .field(
+                    "headers",
+                    &headers.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+                )
+                .field("key_param", key_param)
+                .finish(),
+        }
+    }
+}
+
+// Drop extra headers which would overwrite headers reserved by provider
+fn unreserved_headers<'a>(
+    headers: &[(&'a str, &'a str)],
+    reserved: &[&str],
+    provider: &str,
+) -> Vec<(&'a str, &'a str)> {
+    headers
+        .iter()
+        .filter(|(name, _)| {
+            let is_reserved = reserved
+                .iter()
+                .any(|reserved| name.eq_ignore_ascii_case(reserved));
+            if is_reserved {
+                log::warn!("extra header {name} is ignored for {provider} provider");
+            }
+            !is_reserved
+        })
+        .copied()
+        .collect()
+}
 
 /// Provider for alpha vantage API
+///
+/// ```no_run
+/// use alpha_vantage::api::{ApiClient, Provider};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let provider = Provider::Custom {
+///         base_url: "https://proxy.internal/av".to_string(),
+///         headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+///         key_param: Some("token".to_string()),
+///     };
+///     let api = ApiClient::builder("demo").provider(provider).build().unwrap();
+///     let quote = api.quote("MSFT").json().await.unwrap();
+///     println!("{}", quote.price());
+/// }
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
 pub enum Provider {
     /// Use alphavantage API provider
     AlphaVantage,
     /// User `RapidAPI` as provider
     RapidAPI,
+    /// Use self hosted provider such as proxy which mimics alpha vantage API
+    Custom {
+        /// base url of provider, which is validated and normalized in same
+        /// way as [`ApiClient::with_base_url`]
+        base_url: String,
+        /// headers which are sent along with every request
+        headers: Vec<(String, String)>,
+        /// name of query parameter used for API key. API key is not sent if
+        /// it is `None`
+        key_param: Option<String>,
+    },
 }
 
-/// Struct for initializing client which contains different method for API call
-pub struct ApiClient {
+/// Builder for configuring and creating `ApiClient`
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::cache::InMemoryCache;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = ApiClient::builder("demo")
+///         .timeout(Duration::from_secs(10))
+///         .cache(InMemoryCache::default())
+///         .build()
+///         .unwrap();
+///     let quote = api.quote("MSFT").json().await.unwrap();
+///     println!("{}", quote.price());
+/// }
+/// ```
+pub struct ApiClientBuilder {
     api: String,
-    client: Box<dyn HttpClient + Send + Sync>,
+    client: Option<Box<dyn HttpClient + Send + Sync>>,
     provider: Provider,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    #[cfg(feature = "rate-limit")]
+    rate_limit: Option<RateLimitPolicy>,
+    #[cfg(feature = "retry")]
+    retry: Option<RetryPolicy>,
+    cache: Option<Box<dyn Cache>>,
+    defaults: Defaults,
+    on_request: Option<RequestHook>,
+    on_response: Option<ResponseHook>,
+    middlewares: Vec<Box<dyn Middleware + Send + Sync>>,
+    coalescing: bool,
+}
+
+impl ApiClientBuilder {
+    /// Create new `ApiClientBuilder` with API key
+    #[must_use]
+    pub fn new<S>(api: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api: api.into(),
+            client: None,
+            provider: Provider::AlphaVantage,
+            base_url: None,
+            timeout: None,
+            #[cfg(feature = "rate-limit")]
+            rate_limit: None,
+            #[cfg(feature = "retry")]
+            retry: None,
+            cache: None,
+            defaults: Defaults::default(),
+            on_request: None,
+            on_response: None,
+            middlewares: Vec::new(),
+            coalescing: false,
+        }
+    }
+
+    /// Create new `ApiClientBuilder` with API key read from
+    /// `ALPHAVANTAGE_API_KEY` environment variable
+    ///
+    /// # Errors
+    /// Raise error if environment variable is missing or empty
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(API_KEY_ENV) {
+            Ok(api) if !api.trim().is_empty() => Ok(Self::new(api.trim())),
+            _ => Err(Error::MissingEnvironmentVariable(API_KEY_ENV.to_string())),
+        }
+    }
+
+    /// Set HTTP client used for request. If client is not set, `reqwest`
+    /// client is used when `reqwest-client` feature is enabled
+    #[must_use]
+    pub fn client<T>(mut self, client: T) -> Self
+    where
+        T: HttpClient + 'static + Send + Sync,
+    {
+        self.client = Some(Box::new(client));
+        self
+    }
+
+    /// Set provider of API. By default alphavantage.co provider is used
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    /// Override base url of provider. See [`ApiClient::with_base_url`]
+    #[must_use]
+    pub fn base_url<U>(mut self, base_url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set timeout of request for default `reqwest` client. Timeout cannot
+    /// be set along with user provided client, whose timeout needs to be
+    /// configured on client itself
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Limit rate of request. See [`ApiClient::with_rate_limit`]
+    #[cfg(feature = "rate-limit")]
+    #[must_use]
+    pub fn rate_limit(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit = Some(policy);
+        self
+    }
+
+    /// Retry failed request. See [`ApiClient::with_retry`]
+    #[cfg(feature = "retry")]
+    #[must_use]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Cache response of API. See [`ApiClient::with_cache`]
+    #[must_use]
+    pub fn cache<C>(mut self, cache: C) -> Self
+    where
+        C: Cache + 'static,
+    {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Set entitlement of premium API key. See [`ApiClient::with_entitlement`]
+    #[must_use]
+    pub fn entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.defaults.entitlement = Some(entitlement);
+        self
+    }
+
+    /// Set default parameters of builders. See [`ApiClient::with_defaults`]
+    #[must_use]
+    pub fn defaults(mut self, defaults: Defaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Call hook before every request. See [`ApiClient::on_request`]
+    #[must_use]
+    pub fn on_request(mut self, hook: RequestHook) -> Self {
+        self.on_request = Some(hook);
+        self
+    }
+
+    /// Call hook after every response. See [`ApiClient::on_response`]
+    #[must_use]
+    pub fn on_response(mut self, hook: ResponseHook) -> Self {
+        self.on_response = Some(hook);
+        self
+    }
+
+    /// Apply middleware to every request after middlewares which are
+    /// already added. See [`ApiClient::with_middleware`]
+    #[must_use]
+    pub fn middleware(mut self, middleware: Box<dyn Middleware + Send + Sync>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// Coalesce concurrent identical requests. See
+    /// [`ApiClient::with_coalescing`]
+    #[must_use]
+    pub fn coalescing(mut self, coalescing: bool) -> Self {
+        self.coalescing = coalescing;
+        self
+    }
+
+    /// Build `ApiClient`
+    ///
+    /// # Errors
+    /// Raise error if client is not set while `reqwest-client` feature is
+    /// disabled, timeout is set along with user provided client or base url
+    /// is not valid
+    pub fn build(mut self) -> Result<ApiClient> {
+        if let Provider::Custom { base_url, .. } = &mut self.provider {
+            *base_url = normalize_base_url(std::mem::take(base_url))?;
+        }
+        let client = match (self.client.take(), self.timeout) {
+            (Some(_), Some(_)) => {
+                return Err(Error::InvalidConfiguration(
+                    "timeout cannot be set for user provided client".to_string(),
+                ));
+            }
+            (Some(client), None) => client,
+            (None, timeout) => default_client(timeout)?,
+        };
+        let base_url = self.base_url.take();
+        let api_client = self.finish(client);
+        match base_url {
+            Some(base_url) => api_client.with_base_url(base_url),
+            None => Ok(api_client),
+        }
+    }
+
+    // Create `ApiClient` with client without validating options
+    fn finish(self, client: Box<dyn HttpClient + Send + Sync>) -> ApiClient {
+        ApiClient {
+            api: self.api.into(),
+            client: Arc::from(client),
+            provider: Arc::new(self.provider),
+            base_url: self.base_url.map(Arc::from),
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: self
+                .rate_limit
+                .map(|policy| Arc::new(RateLimiter::new(policy))),
+            #[cfg(feature = "retry")]
+            retry: self.retry,
+            cache: self.cache.map(Arc::from),
+            defaults: self.defaults,
+            on_request: self.on_request.map(Arc::from),
+            on_response: self.on_response.map(Arc::from),
+            middlewares: self.middlewares.into_iter().map(Arc::from).collect(),
+            in_flight: self.coalescing.then(Arc::default),
+            metrics: Arc::default(),
+            #[cfg(feature = "record")]
+            cassette: None,
+        }
+    }
+}
+
+// Create default client with timeout
+#[cfg(feature = "reqwest-client")]
+fn default_client(timeout: Option<Duration>) -> Result<Box<dyn HttpClient + Send + Sync>> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(timeout) = timeout {
+        builder = builder.timeout(timeout);
+    }
+    let client = builder
+        .build()
+        .map_err(|error| Error::InvalidConfiguration(error.to_string()))?;
+    Ok(Box::new(client))
+}
+
+// Raise error since there is no default client without reqwest
+#[cfg(not(feature = "reqwest-client"))]
+fn default_client(_: Option<Duration>) -> Result<Box<dyn HttpClient + Send + Sync>> {
+    Err(Error::MissingParameter {
+        parameter: "client".to_string(),
+        function: "ApiClientBuilder".to_string(),
+    })
+}
+
+/// Senders of requests which wait for output of in flight request
+type Waiters = Vec<oneshot::Sender<std::result::Result<String, Arc<Error>>>>;
+
+/// Guard which removes in flight request when request completes or is
+/// cancelled, so waiters of cancelled request send request again
+struct InFlightGuard<'a> {
+    in_flight: &'a Mutex<HashMap<String, Waiters>>,
+    key: Option<String>,
+}
+
+impl InFlightGuard<'_> {
+    // Remove in flight request and return its waiters
+    fn finish(mut self) -> Waiters {
+        self.remove().unwrap_or_default()
+    }
+
+    fn remove(&mut self) -> Option<Waiters> {
+        let key = self.key.take()?;
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&key)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.remove();
+    }
+}
+
+/// Struct for initializing client which contains different method for API call
+///
+/// Client is cheap to clone since configuration is shared between clones, so
+/// clone can be moved into spawned task. Rate limit, cache and in flight
+/// requests are also shared by clones
+///
+/// ```no_run
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let cloned = api.clone();
+///     let task = tokio::spawn(async move { cloned.quote("MSFT").json().await });
+///     let quote = api.quote("IBM").json().await.unwrap();
+///     let spawned = task.await.unwrap().unwrap();
+///     println!("{} {}", quote.price(), spawned.price());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ApiClient {
+    api: Arc<str>,
+    client: Arc<dyn HttpClient + Send + Sync>,
+    provider: Arc<Provider>,
+    base_url: Option<Arc<str>>,
+    #[cfg(feature = "rate-limit")]
+    rate_limiter: Option<Arc<RateLimiter>>,
+    #[cfg(feature = "retry")]
+    retry: Option<RetryPolicy>,
+    cache: Option<Arc<dyn Cache>>,
+    defaults: Defaults,
+    on_request: Option<SharedRequestHook>,
+    on_response: Option<SharedResponseHook>,
+    middlewares: Vec<SharedMiddleware>,
+    in_flight: Option<Arc<Mutex<HashMap<String, Waiters>>>>,
+    metrics: Arc<Metrics>,
+    #[cfg(feature = "record")]
+    cassette: Option<Cassette>,
+}
+
+/// Debug output of `ApiClient` only contains first two characters of API
+/// key so client can be logged without leaking key. Key of at most four
+/// characters is fully hidden
+///
+/// ```
+/// let api = alpha_vantage::set_api("demo1234", reqwest::Client::new());
+/// let debug = format!("{api:?}");
+/// assert!(debug.contains("api: \"de***\""));
+/// assert!(!debug.contains("demo1234"));
+///
+/// let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+/// assert!(format!("{api:?}").contains("api: \"***\""));
+///
+/// let api = alpha_vantage::api::ApiClient::builder("d")
+///     .provider(alpha_vantage::api::Provider::Custom {
+///         base_url: "https://proxy.internal".to_string(),
+///         headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+///         key_param: None,
+///     })
+///     .build()
+///     .unwrap();
+/// let debug = format!("{api:?}");
+/// assert!(debug.contains("api: \"***\""));
+/// assert!(debug.contains("authorization"));
+/// assert!(!debug.contains("secret"));
+/// ```
+impl std::fmt::Debug for ApiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = if self.api.chars().count() <= 4 {
+            "***".to_string()
+        } else {
+            format!("{}***", self.api.chars().take(2).collect::<String>())
+        };
+        f.debug_struct("ApiClient")
+            .field("api", &redacted)
+            .field("provider", &self.provider)
+            .field("base_url", &self.get_base_url())
+            .finish_non_exhaustive()
+    }
 }
 
 impl ApiClient {
@@ -45,11 +683,38 @@ impl ApiClient {
         S: Into<String>,
         T: HttpClient + 'static + Send + Sync,
     {
-        Self {
-            api: api.into(),
-            client: Box::new(client),
-            provider: Provider::AlphaVantage,
-        }
+        Self::builder(api).finish(Box::new(client))
+    }
+
+    /// Method for initializing `ApiClient` struct using user provided client
+    /// and alphavantage.co provider with API key read from
+    /// `ALPHAVANTAGE_API_KEY` environment variable
+    ///
+    /// ```
+    /// use alpha_vantage::api::{ApiClient, API_KEY_ENV};
+    /// use alpha_vantage::error::Error;
+    ///
+    /// std::env::remove_var(API_KEY_ENV);
+    /// let error = ApiClient::from_env(reqwest::Client::new()).err().unwrap();
+    /// assert!(matches!(error, Error::MissingEnvironmentVariable(ref name) if name == API_KEY_ENV));
+    ///
+    /// std::env::set_var(API_KEY_ENV, " ");
+    /// assert!(ApiClient::from_env(reqwest::Client::new()).is_err());
+    ///
+    /// std::env::set_var(API_KEY_ENV, "demo1234");
+    /// let api = ApiClient::from_env(reqwest::Client::new()).unwrap();
+    /// assert_eq!(api.get_api_key(), "demo1234");
+    /// let api = ApiClient::builder_from_env().unwrap().build().unwrap();
+    /// assert_eq!(api.get_api_key(), "demo1234");
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if environment variable is missing or empty
+    pub fn from_env<T>(client: T) -> Result<Self>
+    where
+        T: HttpClient + 'static + Send + Sync,
+    {
+        Ok(ApiClientBuilder::from_env()?.finish(Box::new(client)))
     }
 
     /// Method for initializing `ApiClient` struct using user
@@ -65,10 +730,348 @@ impl ApiClient {
         S: Into<String>,
         T: HttpClient + 'static + Send + Sync,
     {
-        Self {
-            api: api.into(),
-            client: Box::new(client),
-            provider: Provider::RapidAPI,
+        Self::builder(api)
+            .provider(Provider::RapidAPI)
+            .finish(Box::new(client))
+    }
+
+    /// Create `ApiClientBuilder` for configuring `ApiClient` with API key
+    #[must_use]
+    pub fn builder<S>(api: S) -> ApiClientBuilder
+    where
+        S: Into<String>,
+    {
+        ApiClientBuilder::new(api)
+    }
+
+    /// Create `ApiClientBuilder` with API key read from
+    /// `ALPHAVANTAGE_API_KEY` environment variable
+    ///
+    /// # Errors
+    /// Raise error if environment variable is missing or empty
+    pub fn builder_from_env() -> Result<ApiClientBuilder> {
+        ApiClientBuilder::from_env()
+    }
+
+    /// Method for initializing `ApiClient` struct using user provided client
+    /// and alphavantage.co provider where requests are sent to base url
+    /// instead of alphavantage.co, such as proxy or local mock server
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::ApiClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api =
+    ///         ApiClient::set_api_with_base_url("demo", reqwest::Client::new(), "http://localhost:8080")
+    ///             .unwrap();
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     println!("{}", quote.price());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if base url is not http or https url
+    pub fn set_api_with_base_url<S, T, U>(api: S, client: T, base_url: U) -> Result<Self>
+    where
+        S: Into<String>,
+        T: HttpClient + 'static + Send + Sync,
+        U: Into<String>,
+    {
+        Self::set_api(api, client).with_base_url(base_url)
+    }
+
+    /// Override base url of provider of `ApiClient`. Trailing slash of base
+    /// url is normalized so `http://localhost:8080` and
+    /// `http://localhost:8080//` are both used as `http://localhost:8080/`
+    ///
+    /// # Errors
+    /// Raise error if base url is not http or https url or contains query or
+    /// fragment
+    pub fn with_base_url<U>(mut self, base_url: U) -> Result<Self>
+    where
+        U: Into<String>,
+    {
+        self.base_url = Some(normalize_base_url(base_url.into())?.into());
+        Ok(self)
+    }
+
+    /// Limit rate of requests sent by `ApiClient` as per policy. Requests
+    /// wait until slot is available, so limit is shared by all builders
+    /// created from client including requests sent concurrently
+    ///
+    /// ```no_run
+    /// use alpha_vantage::rate_limit::RateLimitPolicy;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let policy = RateLimitPolicy::default().per_minute(5).per_day(25);
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new()).with_rate_limit(policy);
+    ///     for symbol in ["MSFT", "IBM", "AAPL"] {
+    ///         let quote = api.quote(symbol).json().await.unwrap();
+    ///         println!("{symbol} {}", quote.price());
+    ///     }
+    ///     println!("{:?} {:?}", api.remaining_today(), api.next_slot_in());
+    /// }
+    /// ```
+    #[cfg(feature = "rate-limit")]
+    #[must_use]
+    pub fn with_rate_limit(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(policy)));
+        self
+    }
+
+    /// Retry request which fails to get output from server as per policy
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use alpha_vantage::retry::RetryPolicy;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let policy = RetryPolicy::new(2).backoff(Duration::from_secs(1));
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new()).with_retry(policy);
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     println!("{}", quote.price());
+    /// }
+    /// ```
+    #[cfg(feature = "retry")]
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Number of requests which can be sent in current window of one day,
+    /// `None` if number of requests per day is not limited
+    #[cfg(feature = "rate-limit")]
+    #[must_use]
+    pub fn remaining_today(&self) -> Option<u32> {
+        self.rate_limiter
+            .as_deref()
+            .and_then(RateLimiter::remaining_today)
+    }
+
+    /// Duration after which next request can be sent without waiting
+    #[cfg(feature = "rate-limit")]
+    #[must_use]
+    pub fn next_slot_in(&self) -> Duration {
+        self.rate_limiter
+            .as_deref()
+            .map_or(Duration::ZERO, RateLimiter::next_slot_in)
+    }
+
+    // Wait for slot of request if rate of request is limited
+    #[cfg(feature = "rate-limit")]
+    async fn wait_for_slot(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+
+    /// Use cache for storing response of API. Builder whose data does not
+    /// change often such as quote or daily time series uses cached response
+    /// instead of sending request until response is older than time to live
+    /// of builder. Response served from cache is not counted by rate limit
+    ///
+    /// ```no_run
+    /// use alpha_vantage::cache::InMemoryCache;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+    ///         .with_cache(InMemoryCache::default());
+    ///     let first = api.quote("MSFT").json().await.unwrap();
+    ///     // served from cache without request
+    ///     let second = api.quote("MSFT").json().await.unwrap();
+    ///     println!("{} {}", first.price(), second.price());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_cache<C>(mut self, cache: C) -> Self
+    where
+        C: Cache + 'static,
+    {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Record body of every response received from server to file in `dir`,
+    /// which can be replayed later with [`ApiClient::with_replayer`]. API key
+    /// is redacted from recorded response. See [`record`](crate::record) for
+    /// example
+    #[cfg(feature = "record")]
+    #[must_use]
+    pub fn with_recorder(mut self, dir: impl AsRef<Path>) -> Self {
+        self.cassette = Some(Cassette::Record(Arc::from(dir.as_ref())));
+        self
+    }
+
+    /// Serve every request from response recorded in `dir` by
+    /// [`ApiClient::with_recorder`] instead of sending request to server.
+    /// Request whose response is not recorded raises
+    /// `Error::MissingCassette`. See [`record`](crate::record) for example
+    #[cfg(feature = "record")]
+    #[must_use]
+    pub fn with_replayer(mut self, dir: impl AsRef<Path>) -> Self {
+        self.cassette = Some(Cassette::Replay(Arc::from(dir.as_ref())));
+        self
+    }
+
+    /// Append entitlement of premium API key to every request sent by
+    /// `ApiClient`. Entitlement set in builder such as
+    /// [`QuoteBuilder::entitlement`] is used instead of entitlement of client
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::Entitlement;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+    ///         .with_entitlement(Entitlement::Delayed);
+    ///     let quote = api.quote("IBM").json().await.unwrap();
+    ///     println!("{}", quote.price());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.defaults.entitlement = Some(entitlement);
+        self
+    }
+
+    /// Set default parameters which are used by builders when parameter is
+    /// not set in builder. Default is ignored by builder whose API function
+    /// does not accept parameter. Entitlement of defaults replaces entitlement
+    /// set by [`ApiClient::with_entitlement`]
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::{Defaults, OutputSize};
+    /// use alpha_vantage::stock_time::StockFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new()).with_defaults(Defaults {
+    ///         output_size: Some(OutputSize::Full),
+    ///         ..Defaults::default()
+    ///     });
+    ///     let stock_time = api
+    ///         .stock_time(StockFunction::Daily, "IBM")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", stock_time.data().len());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_defaults(mut self, defaults: Defaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Return default parameters used by builders
+    #[must_use]
+    pub fn defaults(&self) -> &Defaults {
+        &self.defaults
+    }
+
+    /// Return snapshot of counters of requests sent by client. Counters are
+    /// shared with clones of client
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     api.quote("MSFT").json().await.unwrap();
+    ///     let metrics = api.metrics();
+    ///     println!(
+    ///         "{} requests, {} errors, {:?}",
+    ///         metrics.requests, metrics.errors, metrics.total_latency
+    ///     );
+    /// }
+    /// ```
+    #[must_use]
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    /// Coalesce concurrent identical requests, so request which is sent
+    /// while identical request is in flight waits for output of in flight
+    /// request instead of being sent. Output is not reused once in flight
+    /// request completes. If in flight request fails while other requests
+    /// wait for it, every request fails with [`Error::CoalescedRequest`]
+    /// which shares error of in flight request, so [`Error::inner`] returns
+    /// same error for all of them. Requests with extra headers are not
+    /// coalesced
+    ///
+    /// ```no_run
+    /// use futures::future::join_all;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new()).with_coalescing(true);
+    ///     // only one request is sent for identical requests in flight
+    ///     let quotes = join_all((0..10).map(|_| async { api.quote("MSFT").json().await })).await;
+    ///     println!("{}", quotes.len());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_coalescing(mut self, coalescing: bool) -> Self {
+        self.in_flight = coalescing.then(Arc::default);
+        self
+    }
+
+    /// Call hook before every request sent to server along with name of
+    /// function and url of request whose API key is redacted
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+    ///         .on_request(Box::new(|info| println!("{} {}", info.function(), info.url())))
+    ///         .on_response(Box::new(|info| println!("{} {}", info.function(), info.body_length())));
+    ///     api.quote("MSFT").json().await.unwrap();
+    /// }
+    /// ```
+    #[must_use]
+    pub fn on_request(mut self, hook: RequestHook) -> Self {
+        self.on_request = Some(Arc::from(hook));
+        self
+    }
+
+    /// Call hook after every response received from server or failure of
+    /// request along with elapsed time, length of body and whether API
+    /// returned error instead of data. See [`ApiClient::on_request`] for
+    /// example
+    #[must_use]
+    pub fn on_response(mut self, hook: ResponseHook) -> Self {
+        self.on_response = Some(Arc::from(hook));
+        self
+    }
+
+    /// Apply middleware to every request before it is sent to provider.
+    /// Middlewares are applied in order in which they are added. See
+    /// [`middleware`](crate::middleware) for example
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Box<dyn Middleware + Send + Sync>) -> Self {
+        self.middlewares.push(Arc::from(middleware));
+        self
+    }
+
+    /// Method to get base url where requests are sent
+    ///
+    /// ```
+    /// use alpha_vantage::api::ApiClient;
+    /// let api = ApiClient::set_api("some_key", reqwest::Client::new());
+    /// assert_eq!(api.get_base_url(), "https://www.alphavantage.co/");
+    /// ```
+    #[must_use]
+    pub fn get_base_url(&self) -> &str {
+        match (&self.base_url, self.provider.as_ref()) {
+            (Some(base_url), _) => base_url,
+            (None, Provider::Custom { base_url, .. }) => base_url,
+            (None, Provider::AlphaVantage) => BASE_URL,
+            (None, Provider::RapidAPI) => RAPID_API_BASE_URL,
         }
     }
 
@@ -84,66 +1087,725 @@ impl ApiClient {
         &self.api
     }
 
-    // Get json from api endpoint and create struct
-    pub(crate) async fn get_json<T>(&self, path: &str) -> Result<T>
+    /// Check whether API key is accepted by sending cheap quote request which
+    /// is not served from cache and counts against rate limit
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::KeyStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     match api.validate_key().await.unwrap() {
+    ///         KeyStatus::Valid => println!("valid"),
+    ///         KeyStatus::Invalid => println!("invalid"),
+    ///         KeyStatus::RateLimited { daily } => println!("rate limited {daily}"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if request fails or response is neither data nor error
+    /// returned by API
+    pub async fn validate_key(&self) -> Result<KeyStatus> {
+        let path = "query?function=GLOBAL_QUOTE&symbol=IBM";
+        let helper: CsvErrorHelper = match self.get_json(path).await {
+            Ok(helper) => helper,
+            Err(error) => match error.inner() {
+                Error::HttpStatus {
+                    code: 401 | 403, ..
+                } => return Ok(KeyStatus::Invalid),
+                Error::HttpStatus { code: 429, .. } => {
+                    return Ok(KeyStatus::RateLimited { daily: false });
+                }
+                _ => return Err(error),
+            },
+        };
+        let error =
+            match detect_common_helper_error(helper.information, helper.error_message, helper.note)
+            {
+                Ok(()) => return Ok(KeyStatus::Valid),
+                Err(error) => error,
+            };
+        match error {
+            Error::AlphaVantageNote(ref message) | Error::AlphaVantageInformation(ref message)
+                if error.is_rate_limit() =>
+            {
+                let message = message.to_lowercase();
+                let daily = (message.contains("per day") || message.contains("daily"))
+                    && !message.contains("per minute");
+                Ok(KeyStatus::RateLimited { daily })
+            }
+            Error::AlphaVantageErrorMessage(_) => Ok(KeyStatus::Invalid),
+            Error::AlphaVantageInformation(ref message)
+                if message.to_lowercase().contains("apikey") =>
+            {
+                Ok(KeyStatus::Invalid)
+            }
+            error => Err(error),
+        }
+    }
+
+    /// Returns body of response for path and query without parsing it. Path
+    /// is relative to base url such as `query?function=GLOBAL_QUOTE&symbol=IBM`
+    /// and must not contain `apikey`, since API key is added as per provider
+    /// same as every other request of client
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let body = api
+    ///         .get_raw("query?function=GLOBAL_QUOTE&symbol=MSFT")
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{body}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error wrapped in `Error::Request` if request fails
+    pub async fn get_raw(&self, path_and_query: &str) -> Result<String> {
+        self.in_request(path_and_query, self.get_text(path_and_query))
+            .await
+    }
+
+    // Client which sends request through provider overriding provider of
+    // client. Base url, rate limiter, cache and hooks of client are still
+    // used by returned client
+    pub(crate) fn with_provider_override(
+        &self,
+        provider: Option<&Provider>,
+    ) -> Result<Cow<'_, Self>> {
+        let Some(provider) = provider else {
+            return Ok(Cow::Borrowed(self));
+        };
+        let mut provider = provider.clone();
+        if let Provider::Custom { base_url, .. } = &mut provider {
+            *base_url = normalize_base_url(std::mem::take(base_url))?;
+        }
+        let mut api_client = self.clone();
+        api_client.provider = Arc::new(provider);
+        Ok(Cow::Owned(api_client))
+    }
+
+    // Get text output from api endpoint
+    pub(crate) async fn get_text(&self, path: &str) -> Result<String> {
+        self.get_text_with_headers(path, &[]).await
+    }
+
+    // Get text output from api endpoint while sending extra headers. Headers
+    // used by RapidAPI or custom provider cannot be overwritten by extra
+    // headers
+    pub(crate) async fn get_text_with_headers(
+        &self,
+        path: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<String> {
+        let path = self.entitled_path(path);
+        match self.apply_middlewares(&path, headers) {
+            Some(request) => {
+                let path = request.path_and_query();
+                self.send_prepared(&path, &request.header_pairs()).await
+            }
+            None => self.send_prepared(&path, headers).await,
+        }
+    }
+
+    // Send request whose middlewares are already applied
+    async fn send_prepared(&self, path: &str, headers: &[(&str, &str)]) -> Result<String> {
+        match &self.in_flight {
+            Some(in_flight) if headers.is_empty() => self.coalesced(in_flight, path).await,
+            _ => self.retrying(|| self.send_text(path, headers)).await,
+        }
+    }
+
+    // Apply middlewares of client in order to request, returns `None` if
+    // client does not have any middleware
+    fn apply_middlewares(&self, path: &str, headers: &[(&str, &str)]) -> Option<PendingRequest> {
+        if self.middlewares.is_empty() {
+            return None;
+        }
+        let mut request = PendingRequest::new(path, headers);
+        for middleware in &self.middlewares {
+            middleware.before(&mut request);
+        }
+        Some(request)
+    }
+
+    // Send request unless identical request is in flight, in which case wait
+    // for output of in flight request
+    async fn coalesced(
+        &self,
+        in_flight: &Mutex<HashMap<String, Waiters>>,
+        path: &str,
+    ) -> Result<String> {
+        let key = self.request_url(path, "[REDACTED]");
+        let receiver = {
+            let mut in_flight = in_flight.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(waiters) = in_flight.get_mut(&key) {
+                let (sender, receiver) = oneshot::channel();
+                waiters.push(sender);
+                Some(receiver)
+            } else {
+                in_flight.insert(key.clone(), Vec::new());
+                None
+            }
+        };
+        if let Some(receiver) = receiver {
+            return match receiver.await {
+                Ok(Ok(body)) => Ok(body),
+                Ok(Err(error)) => Err(Error::CoalescedRequest(error)),
+                // in flight request is cancelled so request is sent again
+                Err(_) => self.retrying(|| self.send_text(path, &[])).await,
+            };
+        }
+
+        let guard = InFlightGuard {
+            in_flight,
+            key: Some(key),
+        };
+        let output = self.retrying(|| self.send_text(path, &[])).await;
+        let waiters = guard.finish();
+        if waiters.is_empty() {
+            return output;
+        }
+        // error is shared with waiters so every request gets same error
+        let output = output.map_err(Arc::new);
+        for waiter in waiters {
+            // waiter may be dropped before request completes
+            let _ = waiter.send(output.clone());
+        }
+        output.map_err(Error::CoalescedRequest)
+    }
+
+    // Append entitlement of client to path unless path already sets
+    // entitlement
+    fn entitled_path<'a>(&self, path: &'a str) -> Cow<'a, str> {
+        let already_set = path.split_once('?').is_some_and(|(_, query)| {
+            query
+                .split('&')
+                .any(|pair| pair.starts_with("entitlement="))
+        });
+        match self.defaults.entitlement {
+            Some(entitlement) if !already_set => {
+                Cow::Owned(format!("{path}&entitlement={}", entitlement.as_str()))
+            }
+            _ => Cow::Borrowed(path),
+        }
+    }
+
+    // Retry request which fails to get output from server as per retry policy
+    async fn retrying<T, F, Fut>(&self, send: F) -> Result<T>
     where
-        T: DeserializeOwned,
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
     {
-        let string_output = match &self.provider {
-            Provider::AlphaVantage => {
-                self.client
-                    .get_alpha_vantage_provider_output(&format!(
-                        "{BASE_URL}{path}&apikey={}",
-                        self.api
-                    ))
-                    .await
+        #[cfg(feature = "retry")]
+        if let Some(policy) = self.retry {
+            let mut retry = 0;
+            loop {
+                match send().await {
+                    Err(error) if error.is_retryable() && retry < policy.max_retries() => {
+                        log::debug!(
+                            "request failed with {error}, retry {} of {}",
+                            retry + 1,
+                            policy.max_retries()
+                        );
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(
+                            %error,
+                            retry = retry + 1,
+                            max_retries = policy.max_retries(),
+                            "retrying failed request"
+                        );
+                        self.metrics.record_retry();
+                        tokio::time::sleep(policy.delay(retry)).await;
+                        retry += 1;
+                    }
+                    output => return output,
+                }
             }
+        }
+        send().await
+    }
+
+    async fn send_text(&self, path: &str, headers: &[(&str, &str)]) -> Result<String> {
+        #[cfg(feature = "record")]
+        if let Some(Cassette::Replay(dir)) = &self.cassette {
+            let body = record::replay(dir, &self.request_url(path, "[REDACTED]"))?;
+            return String::from_utf8(body).map_err(|_| Error::DecodeJsonToStruct);
+        }
+        #[cfg(feature = "rate-limit")]
+        self.wait_for_slot().await;
+        self.notify_request(path);
+        let start = Instant::now();
+        let output = self.dispatch_text(path, headers).await;
+        self.metrics.record_request(start.elapsed());
+        let body = output
+            .as_ref()
+            .ok()
+            .map(|response| response.body.as_bytes());
+        self.notify_response(path, start, body);
+        let response = output.inspect_err(|_| self.metrics.record_error())?;
+        let api_error = envelope_error(response.body.as_bytes());
+        if response.status == 429 || api_error.as_ref().is_some_and(Error::is_rate_limit) {
+            self.metrics.record_rate_limited();
+            #[cfg(feature = "tracing")]
+            tracing::warn!(status = response.status, "rate limit of API reached");
+        }
+        if !response.is_success() || api_error.is_some() {
+            self.metrics.record_error();
+        }
+        if !response.is_success() {
+            return Err(Error::HttpStatus {
+                code: response.status,
+                body_snippet: body_snippet(&response.body, &self.api),
+            });
+        }
+        #[cfg(feature = "record")]
+        self.record(path, response.body.as_bytes())?;
+        Ok(response.body)
+    }
+
+    // Record body of response if client is recording
+    #[cfg(feature = "record")]
+    fn record(&self, path: &str, body: &[u8]) -> Result<()> {
+        match &self.cassette {
+            Some(Cassette::Record(dir)) => {
+                record::record(dir, &self.request_url(path, "[REDACTED]"), body, &self.api)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn dispatch_text(&self, path: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        let (url, headers) = self.request_parts(path, headers);
+        self.client.get(&url, &headers).await
+    }
+
+    // Url and headers of request as per provider. Extra headers cannot
+    // overwrite headers used by RapidAPI or custom provider
+    fn request_parts<'a>(
+        &'a self,
+        path: &str,
+        headers: &[(&'a str, &'a str)],
+    ) -> (String, Vec<(&'a str, &'a str)>) {
+        match self.provider.as_ref() {
+            Provider::AlphaVantage => (self.request_url(path, &self.api), headers.to_vec()),
             Provider::RapidAPI => {
-                self.client
-                    .get_rapid_api_provider_output(
-                        &format!("{RAPID_API_BASE_URL}{path}"),
-                        &self.api,
-                    )
-                    .await
+                let headers = unreserved_headers(headers, &RAPID_API_HEADERS, "RapidAPI");
+                (
+                    format!("{}{path}", self.get_base_url()),
+                    rapid_api_headers(&self.api, &headers),
+                )
+            }
+            Provider::Custom {
+                headers: provider_headers,
+                ..
+            } => {
+                let reserved = provider_headers
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>();
+                let headers = provider_headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+                    .chain(unreserved_headers(headers, &reserved, "custom"))
+                    .collect();
+                (self.request_url(path, &self.api), headers)
             }
-        }?;
-        serde_json::from_str(&string_output).map_err(|_| Error::DecodeJsonToStruct)
+        }
+    }
+
+    // Url of request with API key as per provider. API key is not part of url
+    // for RapidAPI provider and custom provider without key parameter
+    fn request_url(&self, path: &str, api_key: &str) -> String {
+        match self.provider.as_ref() {
+            Provider::AlphaVantage => format!("{}{path}&apikey={api_key}", self.get_base_url()),
+            Provider::Custom {
+                key_param: Some(key_param),
+                ..
+            } => format!("{}{path}&{key_param}={api_key}", self.get_base_url()),
+            _ => format!("{}{path}", self.get_base_url()),
+        }
+    }
+
+    // Call request hook with redacted url of request
+    fn notify_request(&self, path: &str) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            url = self.request_url(path, "[REDACTED]"),
+            "sending request"
+        );
+        if let Some(hook) = &self.on_request {
+            let url = self.request_url(path, "[REDACTED]");
+            hook(&RequestInfo::new(function_name(path), url));
+        }
+    }
+
+    // Call response hook with body of response, which is `None` if request
+    // failed
+    fn notify_response(&self, path: &str, start: Instant, body: Option<&[u8]>) {
+        if let Some(hook) = &self.on_response {
+            let api_error = body.is_some_and(is_error_envelope);
+            hook(&ResponseInfo::new(
+                function_name(path),
+                start.elapsed(),
+                body.map(<[u8]>::len),
+                api_error,
+            ));
+        }
+    }
+
+    // Get raw bytes output from api endpoint while sending extra headers
+    pub(crate) async fn get_bytes(&self, path: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
+        let path = self.entitled_path(path);
+        match self.apply_middlewares(&path, headers) {
+            Some(request) => {
+                let path = request.path_and_query();
+                let headers = request.header_pairs();
+                self.retrying(|| self.send_bytes(&path, &headers)).await
+            }
+            None => self.retrying(|| self.send_bytes(&path, headers)).await,
+        }
+    }
+
+    async fn send_bytes(&self, path: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
+        #[cfg(feature = "record")]
+        if let Some(Cassette::Replay(dir)) = &self.cassette {
+            return record::replay(dir, &self.request_url(path, "[REDACTED]"));
+        }
+        #[cfg(feature = "rate-limit")]
+        self.wait_for_slot().await;
+        self.notify_request(path);
+        let start = Instant::now();
+        let (url, headers) = self.request_parts(path, headers);
+        let output = self.client.get_bytes(&url, &headers).await;
+        self.metrics.record_request(start.elapsed());
+        let body = output
+            .as_ref()
+            .ok()
+            .map(|response| response.body.as_slice());
+        self.notify_response(path, start, body);
+        let response = output.inspect_err(|_| self.metrics.record_error())?;
+        if response.status == 429 {
+            self.metrics.record_rate_limited();
+        }
+        if !response.is_success() || is_error_envelope(&response.body) {
+            self.metrics.record_error();
+        }
+        if !response.is_success() {
+            return Err(Error::HttpStatus {
+                code: response.status,
+                body_snippet: body_snippet(&String::from_utf8_lossy(&response.body), &self.api),
+            });
+        }
+        #[cfg(feature = "record")]
+        self.record(path, &response.body)?;
+        Ok(response.body)
+    }
+
+    // Get text output from cache if cached output is not older than time to
+    // live, otherwise get it from api endpoint and store it in cache. Output
+    // containing error returned by API is never stored
+    pub(crate) async fn get_text_with_ttl(
+        &self,
+        path: &str,
+        ttl: Option<Duration>,
+    ) -> Result<String> {
+        let (Some(cache), Some(ttl)) = (&self.cache, ttl) else {
+            return self.get_text(path).await;
+        };
+        let key = format!("{}{path}", self.get_base_url());
+        if let Some((body, stored)) = cache.get(&key) {
+            if stored.elapsed() < ttl {
+                self.metrics.record_cache_hit();
+                return Ok(body);
+            }
+        }
+        let body = self.get_text(path).await?;
+        if !is_error_envelope(body.as_bytes()) {
+            cache.put(&key, body.clone());
+        }
+        Ok(body)
+    }
+
+    // Get json from api endpoint and create struct
+    pub(crate) async fn get_json<T>(&self, path: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.get_json_with(path, None, Ok).await
+    }
+
+    // Get json from cache or api endpoint, create helper struct and convert
+    // it to output. Error returned by API is detected while converting, so it
+    // is wrapped with context of request same as error of request
+    pub(crate) async fn get_json_with<H, T, F>(
+        &self,
+        path: &str,
+        ttl: Option<Duration>,
+        convert: F,
+    ) -> Result<T>
+    where
+        H: DeserializeOwned,
+        F: FnOnce(H) -> Result<T>,
+    {
+        self.in_request(path, async {
+            let string_output = self.get_text_with_ttl(path, ttl).await?;
+            let helper = serde_json::from_str(&string_output).map_err(|error| {
+                self.metrics.record_error();
+                decode_json_error(&error)
+            })?;
+            convert(helper)
+        })
+        .await
+    }
+
+    // Run request of path and wrap error raised by it with context of
+    // request. Error which is already wrapped is returned as it is
+    pub(crate) async fn in_request<T>(
+        &self,
+        path: &str,
+        output: impl std::future::Future<Output = Result<T>>,
+    ) -> Result<T> {
+        #[cfg(feature = "tracing")]
+        let output = output.instrument(self.request_span(path));
+        output.await.map_err(|source| match source {
+            Error::Request { .. } => source,
+            source => Error::Request {
+                endpoint: function_name(path).to_string(),
+                url: self.request_url(path, "[REDACTED]"),
+                provider: self.provider_name().to_string(),
+                source: Box::new(source),
+            },
+        })
+    }
+
+    // Get csv output from api endpoint. `datatype=csv` is appended to query
+    // of function which also supports json output. Error returned by API is
+    // still JSON so it is detected and returned as error
+    pub(crate) async fn get_csv(&self, path: &str) -> Result<String> {
+        self.get_csv_with_headers(path, &[]).await
+    }
+
+    // Get csv output from api endpoint while sending extra headers
+    pub(crate) async fn get_csv_with_headers(
+        &self,
+        path: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<String> {
+        self.in_request(path, self.fetch_csv(path, headers)).await
+    }
+
+    async fn fetch_csv(&self, path: &str, headers: &[(&str, &str)]) -> Result<String> {
+        let path = csv_path(path);
+        let string_output = self.get_text_with_headers(&path, headers).await?;
+        if string_output.trim_start().starts_with('{') {
+            let helper: CsvErrorHelper = serde_json::from_str(&string_output).map_err(|error| {
+                self.metrics.record_error();
+                decode_json_error(&error)
+            })?;
+            detect_common_helper_error(helper.information, helper.error_message, helper.note)?;
+            return Err(Error::EmptyResponse);
+        }
+        Ok(string_output)
+    }
+
+    // Get csv from api endpoint and create vector of struct from rows
+    pub(crate) async fn get_csv_rows<T>(&self, path: &str) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.in_request(path, async {
+            parse_csv_rows(&self.fetch_csv(path, &[]).await?)
+                .inspect_err(|_| self.metrics.record_error())
+        })
+        .await
+    }
+
+    // Span of request which carries name of function, symbol like parameters
+    // and provider. Path does not contain API key so key is never recorded
+    #[cfg(feature = "tracing")]
+    fn request_span(&self, path: &str) -> tracing::Span {
+        tracing::info_span!(
+            "alpha_vantage_request",
+            function = function_name(path),
+            symbol = symbol_of(path),
+            provider = self.provider_name(),
+        )
+    }
+
+    // Name of provider used in span and error of request
+    fn provider_name(&self) -> &'static str {
+        match self.provider.as_ref() {
+            Provider::AlphaVantage => "AlphaVantage",
+            Provider::RapidAPI => "RapidAPI",
+            Provider::Custom { .. } => "Custom",
+        }
+    }
+
+    /// Create `Batch` for running many requests with bounded concurrency.
+    /// See [`Batch`] for example
+    #[must_use]
+    pub fn batch<K, T>(&self) -> Batch<'_, K, T> {
+        Batch::new(self)
+    }
+
+    /// Crypto method for calling cryptography function with help of
+    /// `CryptoBuilder`
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let crypto = api
+    ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "EUR")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(crypto.digital_code(), "BTC");
+    ///     assert_eq!(crypto.digital_name(), "Bitcoin");
+    ///     assert_eq!(crypto.market_code(), "EUR");
+    ///     assert_eq!(crypto.market_name(), "Euro");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn crypto(
+        &self,
+        function: CryptoFunction,
+        symbol: impl Into<CurrencyCode>,
+        market: impl Into<CurrencyCode>,
+    ) -> CryptoBuilder<'_> {
+        CryptoBuilder::new(self, function, symbol, market)
+    }
+
+    /// Method for fetching list of physical or digital currency supported by
+    /// API. Fetched list can be used for validating [`CurrencyCode`] using
+    /// [`CurrencyCode::validate_against`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alpha_vantage::exchange::{CurrencyCode, CurrencyKind};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let currencies = api.currency_list(CurrencyKind::Physical).await.unwrap();
+    ///     let euro: CurrencyCode = "eur".parse().unwrap();
+    ///     assert!(euro.validate_against(&currencies).is_ok());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if list cannot be fetched or parsed
+    pub async fn currency_list(&self, kind: CurrencyKind) -> Result<Vec<Currency>> {
+        let path = match kind {
+            CurrencyKind::Physical => "physical_currency_list/?",
+            CurrencyKind::Digital => "digital_currency_list/?",
+        };
+        self.get_csv_rows(path).await
+    }
+
+    /// Method for calling custom function not implemented currently in library
+    /// using `CustomBuilder`
+    #[must_use]
+    pub fn custom<'a>(&'a self, function: &'a str) -> CustomBuilder<'a> {
+        CustomBuilder::new(self, function)
+    }
+
+    /// Method for creating `CustomBuilder` from query string or full url
+    /// copied from alpha vantage documentation. Any `apikey` present in
+    /// query is removed so key and provider of client are used instead
+    ///
+    /// # Errors
+    /// Raise error if query contains malformed fragment or does not contain
+    /// `function` parameter
+    pub fn custom_from_query<'a>(&'a self, query: &'a str) -> Result<CustomBuilder<'a>> {
+        CustomBuilder::from_query(self, query)
+    }
+
+    /// Method for returning `EarningsCalendarBuilder` for earnings calendar
+    /// API
+    ///
+    /// # Example
+    /// ```
+    /// use alpha_vantage::calendar::Horizon;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let calendar = api
+    ///         .earnings_calendar()
+    ///         .symbol("IBM")
+    ///         .horizon(Horizon::TwelveMonth)
+    ///         .csv()
+    ///         .await
+    ///         .unwrap();
+    ///     assert!(calendar.iter().all(|entry| entry.symbol() == "IBM"));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn earnings_calendar(&self) -> EarningsCalendarBuilder<'_> {
+        EarningsCalendarBuilder::new(self)
     }
 
-    /// Crypto method for calling cryptography function with help of
-    /// `CryptoBuilder`
+    /// Method for finding next scheduled earning report of symbol within three
+    /// month horizon. Return None if no report is scheduled on or after today
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
-    ///     let crypto = api
-    ///         .crypto(alpha_vantage::crypto::CryptoFunction::Daily, "BTC", "EUR")
-    ///         .json()
-    ///         .await
-    ///         .unwrap();
-    ///     assert_eq!(crypto.digital_code(), "BTC");
-    ///     assert_eq!(crypto.digital_name(), "Bitcoin");
-    ///     assert_eq!(crypto.market_code(), "EUR");
-    ///     assert_eq!(crypto.market_name(), "Euro");
+    ///     if let Some(entry) = api.upcoming_report_date("IBM").await.unwrap() {
+    ///         println!("{}", entry.report_date());
+    ///     }
     /// }
     /// ```
-    #[must_use]
-    pub fn crypto<'a>(
-        &'a self,
-        function: CryptoFunction,
-        symbol: &'a str,
-        market: &'a str,
-    ) -> CryptoBuilder<'a> {
-        CryptoBuilder::new(self, function, symbol, market)
+    ///
+    /// # Errors
+    /// Raise error if calendar cannot be fetched or report date of any entry
+    /// cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub async fn upcoming_report_date(&self, symbol: &str) -> Result<Option<CalendarEntry>> {
+        let today = chrono::Utc::now().date_naive();
+        let mut upcoming = None;
+        for entry in self
+            .earnings_calendar()
+            .symbol(symbol)
+            .horizon(Horizon::ThreeMonth)
+            .csv()
+            .await?
+        {
+            let report_date = entry.report_date_parsed()?;
+            if report_date >= today
+                && upcoming
+                    .as_ref()
+                    .is_none_or(|(date, _)| report_date < *date)
+            {
+                upcoming = Some((report_date, entry));
+            }
+        }
+        Ok(upcoming.map(|(_, entry)| entry))
     }
 
-    /// Method for calling custom function not implemented currently in library
-    /// using `CustomBuilder`
+    /// Method for returning `IpoCalendarBuilder` for IPO calendar API
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let ipos = api.ipo_calendar().csv().await.unwrap();
+    ///     assert!(ipos.iter().all(|ipo| !ipo.symbol().is_empty()));
+    /// }
+    /// ```
     #[must_use]
-    pub fn custom<'a>(&'a self, function: &'a str) -> CustomBuilder<'a> {
-        CustomBuilder::new(self, function)
+    pub fn ipo_calendar(&self) -> IpoCalendarBuilder<'_> {
+        IpoCalendarBuilder::new(self)
     }
 
     /// Method for returning `EarningBuilder` for earning API
@@ -166,11 +1828,13 @@ impl ApiClient {
     ///
     /// # Example
     /// ```
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let economic = api
-    ///         .economic_indicator("REAL_GDP_PER_CAPITA")
+    ///         .economic_indicator(EconomicIndicatorFunction::RealGdpPerCapita)
     ///         .json()
     ///         .await
     ///         .unwrap();
@@ -178,10 +1842,48 @@ impl ApiClient {
     /// }
     /// ```
     #[must_use]
-    pub fn economic_indicator<'a>(&'a self, function: &'a str) -> EconomicIndicatorBuilder<'a> {
+    pub fn economic_indicator(
+        &self,
+        function: impl Into<EconomicIndicatorFunction>,
+    ) -> EconomicIndicatorBuilder<'_> {
         EconomicIndicatorBuilder::new(self, function)
     }
 
+    /// Method for fetching multiple economic indicators concurrently and
+    /// aligning them by date. Every date present in any indicator is a row
+    /// and value of each indicator is most recent value on or before date.
+    /// Failed request is reported per spec without discarding other
+    /// indicators
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::{EconomicIndicatorFunction, EconomicIndicatorSpec};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let aligned = api
+    ///         .economic_indicators(&[
+    ///             EconomicIndicatorSpec::new(EconomicIndicatorFunction::Cpi),
+    ///             EconomicIndicatorSpec::new(EconomicIndicatorFunction::RealGdp),
+    ///         ])
+    ///         .await
+    ///         .unwrap();
+    ///     for row in aligned.rows() {
+    ///         println!("{} {:?}", row.date(), row.values());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error of first spec if request of every spec fails
+    pub async fn economic_indicators(
+        &self,
+        specs: &[EconomicIndicatorSpec],
+    ) -> Result<AlignedIndicators> {
+        crate::economic_indicator::economic_indicators(self, specs).await
+    }
+
     /// Method for creating `ExchangeBuilder` for exchanging currency value from
     /// one currency to another currency.
     ///
@@ -198,14 +1900,119 @@ impl ApiClient {
     /// }
     /// ```
     #[must_use]
-    pub fn exchange<'a>(
-        &'a self,
-        from_currency: &'a str,
-        to_currency: &'a str,
-    ) -> ExchangeBuilder<'a> {
+    pub fn exchange(
+        &self,
+        from_currency: impl Into<CurrencyCode>,
+        to_currency: impl Into<CurrencyCode>,
+    ) -> ExchangeBuilder<'_> {
         ExchangeBuilder::new(self, from_currency, to_currency)
     }
 
+    /// Method for getting realtime exchange rate from `CURRENCY_EXCHANGE_RATE`.
+    /// This is convenience wrapper over [`ApiClient::exchange`] which returns
+    /// only rate, it does not cache rate so every call sends new request
+    /// unless cache is set for client
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let rate = api.rate("USD", "JPY").await.unwrap();
+    ///     assert!(rate > 0.0);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if request fails, API returns error or empty exchange rate
+    /// or either of currency is invalid
+    pub async fn rate(
+        &self,
+        from_currency: impl Into<CurrencyCode>,
+        to_currency: impl Into<CurrencyCode>,
+    ) -> Result<f64> {
+        Ok(self
+            .exchange(from_currency, to_currency)
+            .json()
+            .await?
+            .rate())
+    }
+
+    /// Method for getting historical exchange rate on date in `YYYY-MM-DD`
+    /// format. Kind of currency is guessed from `from_currency` using
+    /// [`CurrencyKind::guess`] and close of `FX_DAILY` or
+    /// `DIGITAL_CURRENCY_DAILY` is returned. If there is no data on date such
+    /// as on weekend close of nearest previous date is returned
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     // close of friday is returned for sunday
+    ///     let rate = api.exchange_on("EUR", "USD", "2024-02-18").await.unwrap();
+    ///     println!("{rate}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error wrapped in `Error::Endpoint` which contains name of API
+    /// function used if request fails, date is not in `YYYY-MM-DD` format or
+    /// there is no data on or before date
+    pub async fn exchange_on(
+        &self,
+        from_currency: &str,
+        to_currency: &str,
+        date: &str,
+    ) -> Result<f64> {
+        let kind = CurrencyKind::guess(from_currency);
+        self.exchange_on_as(from_currency, to_currency, date, kind)
+            .await
+    }
+
+    /// Method for getting historical exchange rate on date in same way as
+    /// [`ApiClient::exchange_on`] using declared kind of `from_currency`
+    /// instead of guessing
+    ///
+    /// # Errors
+    /// Raise error wrapped in `Error::Endpoint` which contains name of API
+    /// function used if request fails, date is not in `YYYY-MM-DD` format or
+    /// there is no data on or before date
+    pub async fn exchange_on_as(
+        &self,
+        from_currency: &str,
+        to_currency: &str,
+        date: &str,
+        kind: CurrencyKind,
+    ) -> Result<f64> {
+        crate::exchange::exchange_on(self, from_currency, to_currency, date, kind).await
+    }
+
+    /// Method for getting exchange rate between two currencies derived from
+    /// exchange rate of both currency against USD. Both legs are fetched
+    /// concurrently and returned along with implied rate so that refresh time
+    /// of legs can be compared. Neither currency should be USD
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let cross = api.cross_exchange("BTC", "ETH").await.unwrap();
+    ///     println!("{}", cross.rate());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise `Error::ExchangeLeg` containing currencies of leg which failed
+    pub async fn cross_exchange(
+        &self,
+        from_currency: &str,
+        to_currency: &str,
+    ) -> Result<CrossRate> {
+        crate::exchange::cross_exchange(self, from_currency, to_currency, "USD").await
+    }
+
     /// Method for creating `ForexBuilder` for `Forex` API
     ///
     /// # Example
@@ -224,12 +2031,12 @@ impl ApiClient {
     /// }
     /// ```
     #[must_use]
-    pub fn forex<'a>(
-        &'a self,
+    pub fn forex(
+        &self,
         function: ForexFunction,
-        from_symbol: &'a str,
-        to_symbol: &'a str,
-    ) -> ForexBuilder<'a> {
+        from_symbol: impl Into<CurrencyCode>,
+        to_symbol: impl Into<CurrencyCode>,
+    ) -> ForexBuilder<'_> {
         ForexBuilder::new(self, function, from_symbol, to_symbol)
     }
 
@@ -249,6 +2056,27 @@ impl ApiClient {
         QuoteBuilder::new(self, symbol)
     }
 
+    /// Method for getting latest price of symbol from `GLOBAL_QUOTE`. This is
+    /// convenience wrapper over [`ApiClient::quote`] which returns only price,
+    /// it does not cache price so every call sends new request unless cache
+    /// is set for client
+    ///
+    /// # Example
+    /// ```
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let price = api.price("IBM").await.unwrap();
+    ///     assert!(price > 0.0);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if request fails or API returns error or empty quote
+    pub async fn price(&self, symbol: &str) -> Result<f64> {
+        Ok(self.quote(symbol).json().await?.price())
+    }
+
     /// Method for creating search builder
     ///
     /// # Example
@@ -256,8 +2084,13 @@ impl ApiClient {
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
-    ///     let search = api.search("BA").json().await.unwrap();
-    ///     let first_search_match = &search.matches()[0];
+    ///     let search = api
+    ///         .search("BA")
+    ///         .error_on_empty(true)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let first_search_match = search.matches().first().unwrap();
     ///     assert_eq!(first_search_match.symbol(), "BA");
     ///     assert_eq!(first_search_match.name(), "Boeing Company");
     ///     assert_eq!(first_search_match.stock_type(), "Equity");
@@ -271,6 +2104,31 @@ impl ApiClient {
         SearchBuilder::new(self, keywords)
     }
 
+    /// Method for resolving keywords such as company name to single symbol
+    /// match using `ResolvePrefs`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alpha_vantage::search::ResolvePrefs;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let prefs = ResolvePrefs::default()
+    ///         .region("united states")
+    ///         .stock_type("Equity");
+    ///     let resolved = api.resolve_symbol("tesco", prefs).await.unwrap();
+    ///     println!("{}", resolved.symbol());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if search fails or no match is found
+    pub async fn resolve_symbol(&self, keywords: &str, prefs: ResolvePrefs) -> Result<Match> {
+        let search = self.search(keywords).json().await?;
+        prefs.select(keywords, search.into_matches())
+    }
+
     /// Method for creating Stock time Builder from `APIClient`
     ///
     /// # Example
@@ -319,16 +2177,107 @@ impl ApiClient {
     #[must_use]
     pub fn technical_indicator<'a>(
         &'a self,
-        function: &'a str,
+        function: impl Into<TechnicalIndicatorFunction>,
         symbol: &'a str,
         interval: TechnicalIndicatorInterval,
     ) -> TechnicalIndicatorBuilder<'a> {
         TechnicalIndicatorBuilder::new(self, function, symbol, interval)
     }
+
+    /// Method for getting volume weighted average price of intraday interval.
+    /// Request is sent without time period and series type and non intraday
+    /// interval is rejected before request
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let vwap = api
+    ///         .vwap("IBM", TechnicalIndicatorInterval::FifteenMin)
+    ///         .await
+    ///         .unwrap();
+    ///     for (time, value) in vwap.points() {
+    ///         println!("{time} {value}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if interval is not intraday or request fails
+    pub async fn vwap(
+        &self,
+        symbol: &str,
+        interval: TechnicalIndicatorInterval,
+    ) -> Result<SingleValueIndicator> {
+        self.technical_indicator("VWAP", symbol, interval)
+            .json_single()
+            .await
+    }
+
+    /// Method for fetching multiple technical indicators of symbol with
+    /// bounded concurrency. Result of every spec is returned with key of spec
+    /// so failed request of one indicator does not discard other indicators
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::{
+    ///     IndicatorSpec, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let results = api
+    ///         .technical_indicators(
+    ///             "IBM",
+    ///             TechnicalIndicatorInterval::FiveMin,
+    ///             &[
+    ///                 IndicatorSpec::new(TechnicalIndicatorFunction::Sma)
+    ///                     .key("SMA10")
+    ///                     .time_period(10)
+    ///                     .series_type("close"),
+    ///                 IndicatorSpec::new(TechnicalIndicatorFunction::Vwap),
+    ///             ],
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    ///     for (key, result) in &results {
+    ///         println!("{key} {}", result.is_ok());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if key of specs are not unique or error of first spec if
+    /// request of every spec fails
+    pub async fn technical_indicators(
+        &self,
+        symbol: &str,
+        interval: TechnicalIndicatorInterval,
+        specs: &[IndicatorSpec],
+    ) -> Result<HashMap<String, IndicatorResult>> {
+        crate::technical_indicator::technical_indicators(self, symbol, interval, specs).await
+    }
+}
+
+/// Default parameters of builders created by `ApiClient`. See
+/// [`ApiClient::with_defaults`]
+#[derive(Clone, Debug, Default)]
+pub struct Defaults {
+    /// output size used by time series builders of stock and forex whose
+    /// function accepts output size
+    pub output_size: Option<OutputSize>,
+    /// data type used by custom builder
+    pub datatype: Option<DataType>,
+    /// entitlement appended to every request
+    pub entitlement: Option<Entitlement>,
 }
 
 /// Enum for declaring output size of API call
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum OutputSize {
     /// Return latest top 100 points recommended if no historical data is
     /// required and decreases api json sizes
@@ -352,3 +2301,834 @@ pub enum TimeSeriesInterval {
     /// 60 min interval
     SixtyMin,
 }
+
+/// Enum for status of API key returned by [`ApiClient::validate_key`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyStatus {
+    /// API key is accepted
+    Valid,
+    /// API key is rejected
+    Invalid,
+    /// API key is accepted but rate limit is reached
+    RateLimited {
+        /// whether daily limit is reached instead of limit per minute
+        daily: bool,
+    },
+}
+
+/// Enum for declaring entitlement of premium API key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entitlement {
+    /// Realtime data
+    Realtime,
+    /// 15 minute delayed data
+    Delayed,
+}
+
+impl Entitlement {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Entitlement::Realtime => "realtime",
+            Entitlement::Delayed => "delayed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use futures::future::join_all;
+
+    use super::{ApiClient, Defaults, Entitlement, KeyStatus, OutputSize, Provider};
+    use crate::cache::{Cache, InMemoryCache};
+    use crate::custom::DataType;
+    use crate::economic_indicator::{
+        AlignedRow, EconomicIndicatorFunction, EconomicIndicatorInterval,
+        EconomicIndicatorMaturity, EconomicIndicatorSpec,
+    };
+    use crate::error::{Error, FromOrTo};
+    use crate::exchange::{CurrencyCode, CurrencyKind};
+    use crate::forex::ForexFunction;
+    use crate::search::ResolvePrefs;
+    use crate::stock_time::StockFunction;
+    use crate::technical_indicator::{
+        IndicatorSpec, TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+    };
+    use crate::test_utils::{FixtureClient, fixture};
+
+    fn headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn custom_provider() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let provider = Provider::Custom {
+            base_url: "https://proxy.internal/av".to_string(),
+            headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+            key_param: None,
+        };
+        let api = ApiClient::builder("demo")
+            .client(client.clone())
+            .provider(provider)
+            .build()
+            .unwrap();
+        assert_eq!(api.get_base_url(), "https://proxy.internal/av/");
+        api.quote("MSFT").json().await.unwrap();
+
+        // header of provider is not overwritten by extra header of builder
+        let provider = Provider::Custom {
+            base_url: "https://proxy.internal/av/".to_string(),
+            headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+            key_param: Some("token".to_string()),
+        };
+        let api = ApiClient::builder("demo")
+            .client(client.clone())
+            .provider(provider)
+            .build()
+            .unwrap();
+        api.custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "IBM")
+            .header("Authorization", "Bearer other")
+            .header("x-request-id", "1")
+            .text()
+            .await
+            .unwrap();
+
+        let requests = client.requests();
+        assert_eq!(
+            requests[0].url,
+            "https://proxy.internal/av/query?function=GLOBAL_QUOTE&symbol=MSFT"
+        );
+        assert_eq!(
+            requests[0].headers,
+            headers(&[("authorization", "Bearer secret")])
+        );
+        assert_eq!(
+            requests[1].url,
+            "https://proxy.internal/av/query?function=GLOBAL_QUOTE&symbol=IBM&token=demo"
+        );
+        assert_eq!(
+            requests[1].headers,
+            headers(&[("authorization", "Bearer secret"), ("x-request-id", "1")])
+        );
+    }
+
+    #[tokio::test]
+    async fn builder() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = ApiClient::builder("demo")
+            .client(client.clone())
+            .build()
+            .unwrap();
+        assert_eq!(api.get_base_url(), "https://www.alphavantage.co/");
+        api.quote("MSFT").json().await.unwrap();
+        assert!(client.urls()[0].ends_with("&apikey=demo"));
+
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = ApiClient::builder("demo")
+            .client(client.clone())
+            .provider(Provider::RapidAPI)
+            .base_url("http://localhost:8080")
+            .cache(InMemoryCache::default())
+            .build()
+            .unwrap();
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("MSFT").json().await.unwrap();
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].url.starts_with("http://localhost:8080/query?"));
+        assert!(
+            requests[0]
+                .headers
+                .contains(&("x-rapidapi-key".to_string(), "demo".to_string()))
+        );
+
+        // timeout is applied only to default client
+        let error = ApiClient::builder("demo")
+            .client(client)
+            .timeout(Duration::from_secs(10))
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::InvalidConfiguration(_)));
+
+        let error = ApiClient::builder("demo")
+            .client(FixtureClient::new("quote/global_quote.json"))
+            .base_url("localhost:8080")
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::InvalidParameter { .. }));
+    }
+
+    #[cfg(feature = "reqwest-client")]
+    #[test]
+    fn builder_timeout_with_default_client() {
+        let api = ApiClient::builder("demo")
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        assert_eq!(api.get_api_key(), "demo");
+    }
+
+    #[tokio::test]
+    async fn clone_is_shared_across_tasks() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone());
+        let cloned = api.clone();
+        let task = tokio::spawn(async move { cloned.quote("MSFT").json().await });
+        let (spawned, quote) = tokio::join!(task, async { api.quote("MSFT").json().await });
+        assert_eq!(spawned.unwrap().unwrap().symbol(), "MSFT");
+        assert_eq!(quote.unwrap().symbol(), "MSFT");
+        assert_eq!(client.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn base_url() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api =
+            ApiClient::set_api_with_base_url("demo", client.clone(), "http://localhost:8080/")
+                .unwrap();
+        assert_eq!(api.get_base_url(), "http://localhost:8080/");
+        let quote = api.quote("MSFT").json().await.unwrap();
+        assert_eq!(quote.symbol(), "MSFT");
+        assert!(client.urls()[0].starts_with("http://localhost:8080/query?function=GLOBAL_QUOTE&"));
+
+        // trailing slash is normalized
+        let api = ApiClient::set_rapid_api("demo", client.clone())
+            .with_base_url("http://localhost:8080")
+            .unwrap();
+        assert_eq!(api.get_base_url(), "http://localhost:8080/");
+        api.quote("MSFT").json().await.unwrap();
+
+        assert!(ApiClient::set_api_with_base_url("demo", client, "localhost:8080").is_err());
+    }
+
+    #[cfg(feature = "rate-limit")]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn rate_limit() {
+        use crate::rate_limit::RateLimitPolicy;
+
+        let start = tokio::time::Instant::now();
+        let policy = RateLimitPolicy::default().per_minute(2).per_day(5);
+        let api = crate::set_api("demo", FixtureClient::new("quote/global_quote.json"))
+            .with_rate_limit(policy);
+        assert_eq!(api.remaining_today(), Some(5));
+
+        let quote = api.quote("MSFT");
+        let (first, second) = tokio::join!(quote.json(), quote.json());
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(api.next_slot_in(), Duration::from_mins(1));
+
+        tokio::time::advance(Duration::from_secs(20)).await;
+        assert_eq!(api.next_slot_in(), Duration::from_secs(40));
+        api.quote("MSFT").json().await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_mins(1));
+        assert_eq!(api.remaining_today(), Some(2));
+
+        let unlimited = crate::set_api("demo", FixtureClient::new("quote/global_quote.json"));
+        assert_eq!(unlimited.remaining_today(), None);
+        assert_eq!(unlimited.next_slot_in(), Duration::ZERO);
+    }
+
+    #[cfg(feature = "retry")]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn retry() {
+        use crate::retry::RetryPolicy;
+
+        let client = FixtureClient::new("quote/global_quote.json")
+            .queue_error()
+            .queue_error();
+        let policy = RetryPolicy::new(2).backoff(Duration::from_secs(1));
+        let api = crate::set_api("demo", client.clone()).with_retry(policy);
+        let start = tokio::time::Instant::now();
+        let quote = api.quote("MSFT").json().await.unwrap();
+        assert_eq!(quote.symbol(), "MSFT");
+        assert_eq!(client.calls(), 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+
+        let client = FixtureClient::new("quote/global_quote.json").route_error("GLOBAL_QUOTE");
+        let api = crate::set_api("demo", client.clone()).with_retry(RetryPolicy::new(1));
+        let error = api.quote("MSFT").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::GetRequestFailed));
+        assert_eq!(client.calls(), 2);
+    }
+
+    // cache which reports every response as stored one hour ago
+    #[derive(Default)]
+    struct Expired(InMemoryCache);
+
+    impl Cache for Expired {
+        fn get(&self, key: &str) -> Option<(String, Instant)> {
+            let (body, _) = self.0.get(key)?;
+            Some((body, Instant::now().checked_sub(Duration::from_hours(1))?))
+        }
+
+        fn put(&self, key: &str, body: String) {
+            self.0.put(key, body);
+        }
+    }
+
+    #[tokio::test]
+    async fn cache() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone()).with_cache(InMemoryCache::default());
+        let first = api.quote("MSFT").json().await.unwrap();
+        let second = api.quote("MSFT").json().await.unwrap();
+        assert_eq!(first.price().to_string(), second.price().to_string());
+        assert_eq!(client.calls(), 1);
+
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone()).with_cache(Expired::default());
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("MSFT").json().await.unwrap();
+        assert_eq!(client.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn entitlement() {
+        let client = FixtureClient::new("stock_time/daily.json")
+            .route("GLOBAL_QUOTE", "quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone()).with_entitlement(Entitlement::Delayed);
+        api.stock_time(StockFunction::Daily, "IBM")
+            .json()
+            .await
+            .unwrap();
+        // entitlement of builder overrides entitlement of client
+        api.quote("IBM")
+            .entitlement(Entitlement::Realtime)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(
+            client.urls(),
+            [
+                "https://www.alphavantage.co/query?function=TIME_SERIES_DAILY&symbol=IBM&entitlement=delayed&apikey=demo",
+                "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&entitlement=realtime&apikey=demo",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn defaults() {
+        let client = FixtureClient::new("error/invalid_call.json");
+        let api = crate::set_api("demo", client.clone()).with_defaults(Defaults {
+            output_size: Some(OutputSize::Full),
+            datatype: Some(DataType::Csv),
+            ..Defaults::default()
+        });
+
+        let _ = api.stock_time(StockFunction::Daily, "IBM").json().await;
+        let _ = api
+            .stock_time(StockFunction::Daily, "IBM")
+            .output_size(OutputSize::Compact)
+            .json()
+            .await;
+        let _ = api.stock_time(StockFunction::Weekly, "IBM").json().await;
+        let _ = api.forex(ForexFunction::Daily, "EUR", "USD").json().await;
+        let _ = api
+            .forex(ForexFunction::Daily, "EUR", "USD")
+            .output_size(OutputSize::Compact)
+            .json()
+            .await;
+        let _ = api.forex(ForexFunction::Monthly, "EUR", "USD").json().await;
+        let _ = api.custom("LISTING_STATUS").text().await;
+        let _ = api.custom("OVERVIEW").datatype(DataType::Json).text().await;
+
+        let paths = client
+            .urls()
+            .iter()
+            .map(|url| {
+                url.trim_start_matches("https://www.alphavantage.co/")
+                    .trim_end_matches("&apikey=demo")
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "query?function=TIME_SERIES_DAILY&symbol=IBM&outputsize=full",
+                "query?function=TIME_SERIES_DAILY&symbol=IBM&outputsize=compact",
+                "query?function=TIME_SERIES_WEEKLY&symbol=IBM",
+                "query?function=FX_DAILY&from_symbol=EUR&to_symbol=USD&outputsize=full",
+                "query?function=FX_DAILY&from_symbol=EUR&to_symbol=USD&outputsize=compact",
+                "query?function=FX_MONTHLY&from_symbol=EUR&to_symbol=USD",
+                "query?function=LISTING_STATUS&datatype=csv",
+                "query?function=OVERVIEW&datatype=json",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route_error("symbol=FAIL")
+            .route_status("keywords=BROKEN", 200, r#"{"bestMatches": ["#)
+            .route("symbol=LIMIT", "error/rate_limit.json")
+            .route("SYMBOL_SEARCH", "search/tesco.json")
+            .delay(Duration::from_millis(1));
+        let api = crate::set_api("demo", client).with_cache(InMemoryCache::default());
+        #[cfg(feature = "retry")]
+        let api =
+            api.with_retry(crate::retry::RetryPolicy::new(1).backoff(Duration::from_millis(1)));
+
+        // second quote is served from cache
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("FAIL").json().await.unwrap_err();
+        let error = api.quote("LIMIT").json().await.unwrap_err();
+        assert!(error.is_rate_limit());
+        api.search("BROKEN").json().await.unwrap_err();
+
+        // counters are shared with clone which sends requests concurrently
+        let cloned = api.clone();
+        let searches =
+            join_all((0..5).map(|_| async { cloned.search("tesco").json().await })).await;
+        assert!(searches.iter().all(Result::is_ok));
+
+        let metrics = api.metrics();
+        #[cfg(not(feature = "retry"))]
+        let (requests, errors, retries) = (9, 3, 0);
+        #[cfg(feature = "retry")]
+        let (requests, errors, retries) = (10, 4, 1);
+        assert_eq!(metrics.requests, requests);
+        assert_eq!(metrics.errors, errors);
+        assert_eq!(metrics.retries, retries);
+        assert_eq!(metrics.rate_limited, 1);
+        assert_eq!(metrics.cache_hits, 1);
+        assert!(metrics.total_latency >= Duration::from_millis(requests));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn coalescing() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route_error("symbol=FAIL")
+            .delay(Duration::from_millis(100));
+        let api = crate::set_api("demo", client.clone()).with_coalescing(true);
+
+        let quotes = join_all((0..10).map(|_| async { api.quote("MSFT").json().await })).await;
+        assert!(quotes.iter().all(Result::is_ok));
+        assert_eq!(client.calls(), 1);
+
+        // output is not reused after request completes
+        api.quote("MSFT").json().await.unwrap();
+        assert_eq!(client.calls(), 2);
+
+        let quotes = join_all((0..10).map(|_| async { api.quote("FAIL").json().await })).await;
+        assert_eq!(client.calls(), 3);
+        assert!(
+            quotes
+                .iter()
+                .all(|quote| quote.as_ref().is_err_and(|error| {
+                    error.to_string().contains("coalesced request failed")
+                        && matches!(error.inner(), Error::GetRequestFailed)
+                        && error.is_retryable()
+                }))
+        );
+    }
+
+    #[tokio::test]
+    async fn hooks() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route_error("symbol=FAIL")
+            .route("symbol=INVALID", "error/invalid_call.json");
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let responses = Arc::new(Mutex::new(Vec::new()));
+        let (request_urls, response_infos) = (Arc::clone(&urls), Arc::clone(&responses));
+        let api = crate::set_api("secret-key", client)
+            .on_request(Box::new(move |info| {
+                assert_eq!(info.function(), "GLOBAL_QUOTE");
+                request_urls.lock().unwrap().push(info.url().to_string());
+            }))
+            .on_response(Box::new(move |info| {
+                response_infos.lock().unwrap().push(info.clone());
+            }));
+
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("INVALID").json().await.unwrap_err();
+        api.quote("FAIL").json().await.unwrap_err();
+
+        let urls = urls.lock().unwrap();
+        assert_eq!(urls.len(), 3);
+        assert_eq!(
+            urls[0],
+            "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=MSFT&apikey=[REDACTED]"
+        );
+        assert!(urls.iter().all(|url| !url.contains("secret-key")));
+
+        let responses = responses.lock().unwrap();
+        assert_eq!(responses.len(), 3);
+        assert!(responses[0].body_length() > 0);
+        assert!(!responses[0].is_api_error() && !responses[0].is_failed());
+        assert!(responses[1].is_api_error());
+        assert!(responses[2].is_failed());
+        assert_eq!(responses[2].body_length(), 0);
+    }
+
+    #[tokio::test]
+    async fn validate_key() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone());
+        assert_eq!(api.validate_key().await.unwrap(), KeyStatus::Valid);
+        assert!(client.urls()[0].contains("query?function=GLOBAL_QUOTE&symbol=IBM&apikey="));
+
+        let api = crate::set_api("wrong", FixtureClient::new("error/invalid_key.json"));
+        assert_eq!(api.validate_key().await.unwrap(), KeyStatus::Invalid);
+
+        let api = crate::set_api("demo", FixtureClient::new("error/rate_limit.json"));
+        assert_eq!(
+            api.validate_key().await.unwrap(),
+            KeyStatus::RateLimited { daily: true }
+        );
+
+        let api = crate::set_api("demo", FixtureClient::new("quote_csv/global_quote.csv"));
+        assert!(api.validate_key().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn get_raw() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone());
+        let body = api
+            .get_raw("query?function=GLOBAL_QUOTE&symbol=MSFT")
+            .await
+            .unwrap();
+        assert_eq!(body.as_bytes(), fixture("quote/global_quote.json"));
+        assert_eq!(
+            client.urls(),
+            ["https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=MSFT&apikey=demo"]
+        );
+
+        // error returned by API is returned as body
+        let client = FixtureClient::new("error/rate_limit.json");
+        let api = crate::set_rapid_api("rapid_key", client.clone());
+        let body = api
+            .get_raw("query?function=GLOBAL_QUOTE&symbol=MSFT")
+            .await
+            .unwrap();
+        assert!(body.contains("Information"));
+        assert_eq!(
+            client.urls(),
+            ["https://alpha-vantage.p.rapidapi.com/query?function=GLOBAL_QUOTE&symbol=MSFT"]
+        );
+    }
+
+    #[tokio::test]
+    async fn currency_list() {
+        let client = FixtureClient::new("exchange/physical_currency_list.csv");
+        let api = crate::set_api("demo", client.clone());
+        let currencies = api.currency_list(CurrencyKind::Physical).await.unwrap();
+        assert!(client.urls()[0].contains("physical_currency_list/"));
+        assert_eq!(currencies[0].code(), "AED");
+        assert_eq!(currencies[0].name(), "United Arab Emirates Dirham");
+
+        let euro: CurrencyCode = "eur".parse().unwrap();
+        assert!(euro.validate_against(&currencies).is_ok());
+        let typo: CurrencyCode = "EURO".parse().unwrap();
+        assert!(typo.validate_against(&currencies).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn upcoming_report_date() {
+        let client = FixtureClient::new("calendar/upcoming.csv");
+        let api = crate::set_api("demo", client.clone());
+        let entry = api.upcoming_report_date("IBM").await.unwrap().unwrap();
+        assert_eq!(entry.report_date(), "2099-04-18");
+        assert!(client.urls()[0].contains("function=EARNINGS_CALENDAR&horizon=3month&symbol=IBM"));
+
+        let api = crate::set_api("demo", FixtureClient::new("calendar/earnings.csv"));
+        assert!(api.upcoming_report_date("IBM").await.unwrap().is_none());
+
+        let api = crate::set_api("demo", FixtureClient::new("error/rate_limit.json"));
+        let error = api.upcoming_report_date("IBM").await.unwrap_err();
+        assert!(error.is_rate_limit());
+    }
+
+    #[tokio::test]
+    async fn economic_indicators() {
+        let client = FixtureClient::new("error/invalid_call.json")
+            .route("function=CPI&", "economic_indicator/cpi_monthly.json")
+            .route(
+                "function=GDP&",
+                "economic_indicator/nominal_gdp_quarterly.json",
+            )
+            .route(
+                "function=TREASURY_YIELD&interval=daily&maturity=10year&",
+                "economic_indicator/treasury_yield_march.json",
+            );
+        let api = crate::set_api("demo", client);
+        let aligned = api
+            .economic_indicators(&[
+                EconomicIndicatorSpec::new(EconomicIndicatorFunction::Cpi),
+                EconomicIndicatorSpec::new("GDP"),
+                EconomicIndicatorSpec::new(EconomicIndicatorFunction::TreasuryYield)
+                    .interval(EconomicIndicatorInterval::Daily)
+                    .maturity(EconomicIndicatorMaturity::TenYear),
+                EconomicIndicatorSpec::new(EconomicIndicatorFunction::Unemployment),
+            ])
+            .await
+            .unwrap();
+
+        let errors = aligned.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 3);
+        assert!(matches!(
+            errors[0].1.inner(),
+            Error::AlphaVantageErrorMessage(_)
+        ));
+        assert!(aligned.indicator(0).is_some());
+        assert!(aligned.indicator(3).is_none());
+
+        let rows = aligned.rows();
+        let dates = rows.iter().map(AlignedRow::date).collect::<Vec<_>>();
+        assert_eq!(
+            dates,
+            [
+                "2022-12-01",
+                "2023-01-01",
+                "2023-02-01",
+                "2023-03-01",
+                "2023-03-30",
+                "2023-03-31",
+                "2023-04-01",
+                "2023-04-03",
+            ]
+        );
+        assert_eq!(rows[0].values(), [Some(298.0), None, None, None]);
+        assert_eq!(rows[3].values(), [Some(310.0), Some(26_000.0), None, None]);
+        assert_eq!(
+            rows[6].values(),
+            [Some(312.0), Some(26_520.0), Some(3.48), None]
+        );
+        assert_eq!(
+            rows[7].values(),
+            [Some(312.0), Some(26_520.0), Some(3.43), None]
+        );
+    }
+
+    #[tokio::test]
+    async fn rate() {
+        let client = FixtureClient::new("exchange/btc_usd.json")
+            .route_status("from_currency=XYZ", 200, "{}")
+            .route("from_currency=EURO", "exchange/invalid_from_currency.json");
+        let api = crate::set_api("demo", client);
+        assert_eq!(api.rate("BTC", "USD").await.unwrap().to_string(), "52000");
+        assert!(matches!(
+            api.rate("XYZ", "USD").await.unwrap_err().inner(),
+            Error::EmptyResponse
+        ));
+        assert!(matches!(
+            api.rate("EURO", "USD").await.unwrap_err().inner(),
+            Error::InvalidCurrency {
+                which: FromOrTo::From,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn exchange_on() {
+        let client = FixtureClient::new("crypto/digital_daily.json")
+            .route("function=FX_DAILY", "forex/fx_daily.json");
+        let api = crate::set_api("demo", client.clone());
+
+        // 2024-02-18 is sunday so close of friday is returned
+        let rate = api.exchange_on("EUR", "USD", "2024-02-18").await.unwrap();
+        assert_eq!(rate.to_string(), "1.0777");
+        let rate = api.exchange_on("EUR", "USD", "2024-02-15").await.unwrap();
+        assert_eq!(rate.to_string(), "1.0772");
+        assert!(client.urls()[0].contains("outputsize=full"));
+
+        let rate = api.exchange_on("BTC", "EUR", "2024-02-17").await.unwrap();
+        assert_eq!(rate.to_string(), "48140.61");
+        assert!(client.urls()[2].contains("function=DIGITAL_CURRENCY_DAILY"));
+
+        // unknown code can be declared as digital currency
+        let rate = api
+            .exchange_on_as("XYZ", "EUR", "2024-02-20", CurrencyKind::Digital)
+            .await
+            .unwrap();
+        assert_eq!(rate.to_string(), "48210.5");
+
+        let error = api
+            .exchange_on("EUR", "USD", "2024-01-01")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "FX_DAILY request failed: no data found on or before 2024-01-01"
+        );
+        assert!(matches!(
+            error,
+            Error::Endpoint { function, .. } if function == "FX_DAILY"
+        ));
+    }
+
+    #[tokio::test]
+    async fn cross_exchange() {
+        let client = FixtureClient::new("error/rate_limit.json")
+            .route("from_currency=BTC", "exchange/btc_usd.json")
+            .route("from_currency=ETH", "exchange/eth_usd.json");
+        let api = crate::set_api("demo", client.clone());
+        let cross = api.cross_exchange("BTC", "ETH").await.unwrap();
+        // 52000 / 2600
+        assert_eq!(cross.rate().to_string(), "20");
+        assert_eq!(cross.from_leg().rate().to_string(), "52000");
+        assert_eq!(cross.to_leg().rate().to_string(), "2600");
+        assert_eq!(cross.from_leg().refreshed_time(), "2024-02-16 14:31:02");
+        assert_eq!(cross.to_leg().refreshed_time(), "2024-02-16 14:29:32");
+        #[cfg(feature = "chrono")]
+        assert_eq!(cross.skew().unwrap(), chrono::Duration::seconds(90));
+
+        let error = api.cross_exchange("BTC", "DOGE").await.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::ExchangeLeg { from_currency, source, .. }
+                if from_currency == "DOGE" && source.is_rate_limit()
+        ));
+        assert!(
+            client
+                .urls()
+                .iter()
+                .all(|url| url.contains("to_currency=USD"))
+        );
+    }
+
+    #[tokio::test]
+    async fn price() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route_status("symbol=EMPTY", 200, "{}")
+            .route("symbol=INVALID", "error/invalid_call.json");
+        let api = crate::set_api("demo", client);
+        assert_eq!(api.price("MSFT").await.unwrap().to_string(), "406.32");
+        assert!(matches!(
+            api.price("EMPTY").await.unwrap_err().inner(),
+            Error::EmptyResponse
+        ));
+        assert!(matches!(
+            api.price("INVALID").await.unwrap_err().inner(),
+            Error::AlphaVantageErrorMessage(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolve_symbol() {
+        // tesco is listed in United Kingdom, United States and Frankfurt
+        let api = crate::set_api("demo", FixtureClient::new("search/tesco.json"));
+        let prefs = ResolvePrefs::default()
+            .region("united states")
+            .stock_type("Equity");
+        let resolved = api.resolve_symbol("tesco", prefs).await.unwrap();
+        assert_eq!(resolved.symbol(), "TSCDF");
+
+        let prefs = ResolvePrefs::default().currency("EUR");
+        let resolved = api.resolve_symbol("tesco", prefs).await.unwrap();
+        assert_eq!(resolved.symbol(), "TCO0.FRK");
+
+        // exact symbol equality wins over higher match score
+        let prefs = ResolvePrefs::default().region("United States");
+        let resolved = api.resolve_symbol("tscdy", prefs).await.unwrap();
+        assert_eq!(resolved.symbol(), "TSCDY");
+
+        // falls back to best overall match when preference is not satisfied
+        let prefs = ResolvePrefs::default().region("Japan");
+        let resolved = api.resolve_symbol("tesco", prefs.clone()).await.unwrap();
+        assert_eq!(resolved.symbol(), "TSCO.LON");
+        let error = api
+            .resolve_symbol("tesco", prefs.fallback(false))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::NoMatchFound { .. }));
+
+        let api = crate::set_api("demo", FixtureClient::new("search/empty.json"));
+        let error = api
+            .resolve_symbol("qwertyuiop", ResolvePrefs::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::NoMatchFound { keywords } if keywords == "qwertyuiop"));
+    }
+
+    #[tokio::test]
+    async fn vwap() {
+        let client = FixtureClient::new("technical_indicator/vwap.json");
+        let api = crate::set_api("demo", client.clone());
+        let vwap = api
+            .vwap("IBM", TechnicalIndicatorInterval::FifteenMin)
+            .await
+            .unwrap();
+        assert!(client.urls()[0].contains("query?function=VWAP&symbol=IBM&interval=15min&apikey="));
+        assert_eq!(vwap.meta().interval(), "15min");
+        assert_eq!(
+            vwap.points().last().unwrap(),
+            &("2024-02-16 19:45:00".to_string(), 187.3921)
+        );
+
+        let error = api
+            .vwap("IBM", TechnicalIndicatorInterval::Daily)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnsupportedParameter { .. }));
+        assert_eq!(error.to_string(), "daily interval is not supported by VWAP");
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn technical_indicators() {
+        let client = FixtureClient::new("error/premium.json")
+            .route("function=SMA&", "technical_indicator/sma_month.json")
+            .route("function=RSI&", "technical_indicator/rsi.json");
+        let api = crate::set_api("demo", client);
+        let results = api
+            .technical_indicators(
+                "IBM",
+                TechnicalIndicatorInterval::FiveMin,
+                &[
+                    IndicatorSpec::new(TechnicalIndicatorFunction::Sma)
+                        .key("SMA10")
+                        .time_period(10)
+                        .series_type("close"),
+                    IndicatorSpec::new(TechnicalIndicatorFunction::Rsi)
+                        .time_period(10)
+                        .series_type("open"),
+                    IndicatorSpec::new(TechnicalIndicatorFunction::Vwap),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 3);
+        let sma = results["SMA10"].as_ref().unwrap();
+        assert_eq!(sma.data().unwrap().len(), 2);
+        let rsi = results["RSI"].as_ref().unwrap();
+        assert_eq!(rsi.meta().time_period(), Some(10));
+        assert!(matches!(
+            results["VWAP"].as_ref().unwrap_err().inner(),
+            Error::AlphaVantageInformation(_)
+        ));
+
+        let error = api
+            .technical_indicators(
+                "IBM",
+                TechnicalIndicatorInterval::FiveMin,
+                &[
+                    IndicatorSpec::new("SMA")
+                        .time_period(10)
+                        .series_type("close"),
+                    IndicatorSpec::new("SMA")
+                        .time_period(20)
+                        .series_type("close"),
+                ],
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParameter { .. }));
+    }
+}
@@ -0,0 +1,235 @@
+//! Module for running many requests of `ApiClient` with bounded concurrency
+//!
+//! Requests are queued in `Batch` along with id supplied by user and sent
+//! when batch is run. Failure of one request does not abort other requests
+//! of batch. If rate limit is set for `ApiClient`, requests of batch also wait
+//! for slot as per policy
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+
+use crate::api::ApiClient;
+use crate::error::Result;
+use crate::quote::Quote;
+use crate::stock_time::{StockFunction, TimeSeries};
+
+/// Executor which runs queued requests with bounded concurrency. Output of
+/// request is converted into `T`, so single kind of output can be collected
+/// with `T` as output itself while different kinds can be collected with enum
+/// which implements `From` for each kind
+///
+/// ```no_run
+/// use alpha_vantage::quote::Quote;
+/// use alpha_vantage::stock_time::{StockFunction, TimeSeries};
+///
+/// enum Output {
+///     Quote(Quote),
+///     Series(TimeSeries),
+/// }
+///
+/// impl From<Quote> for Output {
+///     fn from(quote: Quote) -> Self {
+///         Output::Quote(quote)
+///     }
+/// }
+///
+/// impl From<TimeSeries> for Output {
+///     fn from(series: TimeSeries) -> Self {
+///         Output::Series(series)
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let mut batch = api.batch::<_, Output>();
+///     batch
+///         .quote("MSFT", "MSFT")
+///         .stock_time("IBM", StockFunction::Daily, "IBM");
+///     for (id, result) in batch.run(2).await {
+///         match result {
+///             Ok(Output::Quote(quote)) => println!("{id}: {}", quote.price()),
+///             Ok(Output::Series(series)) => println!("{id}: {}", series.data().len()),
+///             Err(error) => println!("{id}: {error}"),
+///         }
+///     }
+/// }
+/// ```
+pub struct Batch<'a, K, T> {
+    api_client: &'a ApiClient,
+    jobs: Vec<(K, BoxFuture<'a, Result<T>>)>,
+}
+
+impl<'a, K, T> Batch<'a, K, T> {
+    /// Create new empty batch for `ApiClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self {
+            api_client,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Queue request created by closure with id. Request is not sent until
+    /// batch is run
+    pub fn push<F, Fut>(&mut self, id: K, job: F) -> &mut Self
+    where
+        F: FnOnce(&'a ApiClient) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'a,
+    {
+        self.jobs.push((id, job(self.api_client).boxed()));
+        self
+    }
+
+    /// Queue request of quote of symbol with id
+    pub fn quote(&mut self, id: K, symbol: &'a str) -> &mut Self
+    where
+        T: From<Quote> + 'a,
+    {
+        self.push(id, move |api_client| async move {
+            api_client.quote(symbol).json().await.map(T::from)
+        })
+    }
+
+    /// Queue request of time series of symbol with id
+    pub fn stock_time(&mut self, id: K, function: StockFunction, symbol: &'a str) -> &mut Self
+    where
+        T: From<TimeSeries> + 'a,
+    {
+        self.push(id, move |api_client| async move {
+            api_client
+                .stock_time(function, symbol)
+                .json()
+                .await
+                .map(T::from)
+        })
+    }
+
+    /// Return number of queued requests
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Check whether batch does not have any queued request
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Run queued requests with at most `max_concurrency` requests in flight
+    /// at same time and return result of each request along with its id in
+    /// order in which requests were queued. `max_concurrency` of 0 is
+    /// treated as 1
+    ///
+    /// ```no_run
+    /// use alpha_vantage::quote::Quote;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let mut batch = api.batch::<_, Quote>();
+    ///     for symbol in ["MSFT", "IBM", "AAPL"] {
+    ///         batch.quote(symbol, symbol);
+    ///     }
+    ///     for (symbol, result) in batch.run(2).await {
+    ///         println!("{symbol}: {:?}", result.map(|quote| quote.price()));
+    ///     }
+    /// }
+    /// ```
+    pub async fn run(self, max_concurrency: usize) -> Vec<(K, Result<T>)> {
+        futures::stream::iter(self.jobs)
+            .map(|(id, job)| async move { (id, job.await) })
+            .buffered(max_concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use crate::error::Error;
+    use crate::quote::Quote;
+    use crate::stock_time::{StockFunction, TimeSeries};
+    use crate::test_utils::FixtureClient;
+
+    enum Output {
+        Quote(Quote),
+        Series(TimeSeries),
+    }
+
+    impl From<Quote> for Output {
+        fn from(quote: Quote) -> Self {
+            Output::Quote(quote)
+        }
+    }
+
+    impl From<TimeSeries> for Output {
+        fn from(series: TimeSeries) -> Self {
+            Output::Series(series)
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn bounded_concurrency() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route_error("symbol=FAIL")
+            .route("TIME_SERIES_DAILY", "stock_time/daily_ibm.json")
+            .delay(Duration::from_secs(1));
+        let api = crate::set_api("demo", client.clone());
+
+        let mut batch = api.batch::<_, Quote>();
+        for symbol in ["MSFT", "FAIL", "IBM", "AAPL", "TSLA", "NVDA"] {
+            batch.quote(symbol, symbol);
+        }
+        let start = Instant::now();
+        let results = batch.run(2).await;
+        // six requests of one second each with two in flight
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        assert_eq!(client.calls(), 6);
+        assert_eq!(results.len(), 6);
+        assert_eq!(results[0].0, "MSFT");
+        assert!(results[0].1.is_ok());
+        let error = results[1].1.as_ref().unwrap_err();
+        assert!(matches!(error.inner(), Error::GetRequestFailed));
+        assert!(results[2..].iter().all(|(_, result)| result.is_ok()));
+
+        let mut batch = api.batch::<usize, Output>();
+        batch
+            .quote(0, "MSFT")
+            .stock_time(1, StockFunction::Daily, "IBM")
+            .push(2, |api| async move {
+                api.quote("MSFT").json().await.map(Output::Quote)
+            });
+        let results = batch.run(3).await;
+        assert!(matches!(&results[0], (0, Ok(Output::Quote(quote))) if quote.symbol() == "MSFT"));
+        assert!(matches!(&results[1], (1, Ok(Output::Series(series))) if series.symbol() == "IBM"));
+        assert!(matches!(&results[2], (2, Ok(Output::Quote(quote))) if quote.symbol() == "MSFT"));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn zero_concurrency() {
+        let api = crate::set_api("demo", FixtureClient::new("quote/global_quote.json"));
+        #[cfg(feature = "rate-limit")]
+        let api = api.with_rate_limit(crate::rate_limit::RateLimitPolicy::default().per_minute(2));
+
+        let mut batch = api.batch::<_, Quote>();
+        for (id, symbol) in ["MSFT", "IBM", "AAPL"].into_iter().enumerate() {
+            batch.quote(id, symbol);
+        }
+        assert_eq!(batch.len(), 3);
+        let start = Instant::now();
+        let results = batch.run(0).await;
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+        // third request waits for slot of rate limit
+        #[cfg(feature = "rate-limit")]
+        assert_eq!(start.elapsed(), Duration::from_mins(1));
+        #[cfg(not(feature = "rate-limit"))]
+        assert!(start.elapsed().is_zero());
+    }
+}
@@ -0,0 +1,560 @@
+//! Module which provides blocking client for using API without async runtime
+//!
+//! Blocking client drives same builders used by async [`ApiClient`] on
+//! runtime owned by client, so output struct and error are same as async
+//! client. Blocking client must not be used inside async runtime.
+//!
+//! [`ApiClient`]: crate::api::ApiClient
+//!
+//! ```
+//! # fn serve(file: &'static str, expected: &'static str) -> String {
+//! #     use std::io::{Read, Write};
+//! #     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+//! #     let address = listener.local_addr().unwrap();
+//! #     std::thread::spawn(move || {
+//! #         let (mut stream, _) = listener.accept().unwrap();
+//! #         let mut request = [0; 4096];
+//! #         let read = stream.read(&mut request).unwrap();
+//! #         let request = String::from_utf8_lossy(&request[..read]);
+//! #         assert!(request.starts_with(expected), "{request}");
+//! #         let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/");
+//! #         let body = std::fs::read_to_string(format!("{dir}{file}")).unwrap();
+//! #         write!(
+//! #             stream,
+//! #             "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+//! #             body.len()
+//! #         )
+//! #         .unwrap();
+//! #     });
+//! #     format!("http://{address}/")
+//! # }
+//! use alpha_vantage::api::OutputSize;
+//! use alpha_vantage::blocking::ApiClient;
+//! use alpha_vantage::stock_time::StockFunction;
+//!
+//! let client = reqwest::Client::builder().no_proxy().build().unwrap();
+//! let base_url = serve(
+//!     "quote/global_quote.json",
+//!     "GET /query?function=GLOBAL_QUOTE&symbol=MSFT&apikey=demo ",
+//! );
+//! let api = alpha_vantage::api::ApiClient::set_api("demo", client.clone())
+//!     .with_base_url(base_url)
+//!     .unwrap();
+//! let api = ApiClient::new(api).unwrap();
+//! let quote = api.quote("MSFT").json().unwrap();
+//! assert_eq!(quote.symbol(), "MSFT");
+//!
+//! let base_url = serve(
+//!     "stock_time/daily_ibm.json",
+//!     "GET /query?function=TIME_SERIES_DAILY&symbol=IBM&outputsize=compact&apikey=demo ",
+//! );
+//! let api = alpha_vantage::api::ApiClient::set_api("demo", client)
+//!     .with_base_url(base_url)
+//!     .unwrap();
+//! let api = ApiClient::new(api).unwrap();
+//! let series = api
+//!     .stock_time(StockFunction::Daily, "IBM")
+//!     .with(|builder| builder.output_size(OutputSize::Compact))
+//!     .json()
+//!     .unwrap();
+//! assert_eq!(series.symbol(), "IBM");
+//! assert_eq!(series.data().len(), 25);
+//! ```
+use std::collections::HashMap;
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use tokio::runtime::Runtime;
+
+use crate::api::KeyStatus;
+use crate::calendar::{CalendarEntry, EarningsCalendarBuilder, Ipo, IpoCalendarBuilder};
+use crate::client::HttpClient;
+use crate::crypto::{Crypto, CryptoBuilder, CryptoFunction};
+use crate::custom::{CustomBuilder, PagedError};
+use crate::earning::{Earning, EarningBuilder};
+use crate::economic_indicator::{
+    AlignedIndicators, EconomicIndicator, EconomicIndicatorBuilder, EconomicIndicatorFunction,
+    EconomicIndicatorSpec,
+};
+use crate::error::{Error, Result};
+use crate::exchange::{CrossRate, Currency, CurrencyCode, CurrencyKind, Exchange, ExchangeBuilder};
+use crate::forex::{Forex, ForexBuilder, ForexFunction};
+use crate::quote::{Quote, QuoteBuilder};
+use crate::search::{Match, ResolvePrefs, Search, SearchBuilder};
+use crate::stock_time::{StockFunction, TimeSeries, TimeSeriesBuilder};
+use crate::technical_indicator::{
+    AroonIndicator, BbandsIndicator, IndicatorResult, IndicatorSpec, MacdIndicator,
+    SingleValueIndicator, StochIndicator, TechnicalIndicator, TechnicalIndicatorBuilder,
+    TechnicalIndicatorFunction, TechnicalIndicatorInterval,
+};
+
+/// Blocking client which contains same method for API call as async
+/// [`ApiClient`](crate::api::ApiClient)
+pub struct ApiClient {
+    api_client: crate::api::ApiClient,
+    runtime: Runtime,
+}
+
+impl ApiClient {
+    /// Create blocking client from async client
+    ///
+    /// # Errors
+    /// Raise error if runtime used for driving request cannot be created
+    pub fn new(api_client: crate::api::ApiClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|_| Error::CreateRuntime)?;
+        Ok(Self {
+            api_client,
+            runtime,
+        })
+    }
+
+    /// Create blocking client using user provided client and alphavantage.co
+    /// provider
+    ///
+    /// # Errors
+    /// Raise error if runtime used for driving request cannot be created
+    pub fn set_api<S, T>(api: S, client: T) -> Result<Self>
+    where
+        S: Into<String>,
+        T: HttpClient + 'static + Send + Sync,
+    {
+        Self::new(crate::api::ApiClient::set_api(api, client))
+    }
+
+    /// Create blocking client using user provided client and `RapidAPI`
+    /// provider
+    ///
+    /// # Errors
+    /// Raise error if runtime used for driving request cannot be created
+    pub fn set_rapid_api<S, T>(api: S, client: T) -> Result<Self>
+    where
+        S: Into<String>,
+        T: HttpClient + 'static + Send + Sync,
+    {
+        Self::new(crate::api::ApiClient::set_rapid_api(api, client))
+    }
+
+    /// Get async client used by blocking client
+    #[must_use]
+    pub fn api_client(&self) -> &crate::api::ApiClient {
+        &self.api_client
+    }
+
+    /// Block on future until it completes, which can be used for calling
+    /// async method of [`api_client`](Self::api_client) which returns output
+    /// directly instead of builder
+    pub fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        self.runtime.block_on(future)
+    }
+
+    fn blocking<B>(&self, builder: B) -> Blocking<'_, B> {
+        Blocking {
+            builder,
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::crypto`]
+    #[must_use]
+    pub fn crypto(
+        &self,
+        function: CryptoFunction,
+        symbol: impl Into<CurrencyCode>,
+        market: impl Into<CurrencyCode>,
+    ) -> Blocking<'_, CryptoBuilder<'_>> {
+        self.blocking(self.api_client.crypto(function, symbol, market))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::custom`]
+    #[must_use]
+    pub fn custom<'a>(&'a self, function: &'a str) -> Blocking<'a, CustomBuilder<'a>> {
+        self.blocking(self.api_client.custom(function))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::earnings_calendar`]
+    #[must_use]
+    pub fn earnings_calendar(&self) -> Blocking<'_, EarningsCalendarBuilder<'_>> {
+        self.blocking(self.api_client.earnings_calendar())
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::ipo_calendar`]
+    #[must_use]
+    pub fn ipo_calendar(&self) -> Blocking<'_, IpoCalendarBuilder<'_>> {
+        self.blocking(self.api_client.ipo_calendar())
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::earning`]
+    #[must_use]
+    pub fn earning<'a>(&'a self, symbol: &'a str) -> Blocking<'a, EarningBuilder<'a>> {
+        self.blocking(self.api_client.earning(symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::economic_indicator`]
+    #[must_use]
+    pub fn economic_indicator(
+        &self,
+        function: impl Into<EconomicIndicatorFunction>,
+    ) -> Blocking<'_, EconomicIndicatorBuilder<'_>> {
+        self.blocking(self.api_client.economic_indicator(function))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::exchange`]
+    #[must_use]
+    pub fn exchange(
+        &self,
+        from_currency: impl Into<CurrencyCode>,
+        to_currency: impl Into<CurrencyCode>,
+    ) -> Blocking<'_, ExchangeBuilder<'_>> {
+        self.blocking(self.api_client.exchange(from_currency, to_currency))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::forex`]
+    #[must_use]
+    pub fn forex(
+        &self,
+        function: ForexFunction,
+        from_symbol: impl Into<CurrencyCode>,
+        to_symbol: impl Into<CurrencyCode>,
+    ) -> Blocking<'_, ForexBuilder<'_>> {
+        self.blocking(self.api_client.forex(function, from_symbol, to_symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::quote`]
+    #[must_use]
+    pub fn quote<'a>(&'a self, symbol: &'a str) -> Blocking<'a, QuoteBuilder<'a>> {
+        self.blocking(self.api_client.quote(symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::search`]
+    #[must_use]
+    pub fn search<'a>(&'a self, keywords: &'a str) -> Blocking<'a, SearchBuilder<'a>> {
+        self.blocking(self.api_client.search(keywords))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::stock_time`]
+    #[must_use]
+    pub fn stock_time<'a>(
+        &'a self,
+        function: StockFunction,
+        symbol: &'a str,
+    ) -> Blocking<'a, TimeSeriesBuilder<'a>> {
+        self.blocking(self.api_client.stock_time(function, symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::technical_indicator`]
+    #[must_use]
+    pub fn technical_indicator<'a>(
+        &'a self,
+        function: impl Into<TechnicalIndicatorFunction>,
+        symbol: &'a str,
+        interval: TechnicalIndicatorInterval,
+    ) -> Blocking<'a, TechnicalIndicatorBuilder<'a>> {
+        self.blocking(
+            self.api_client
+                .technical_indicator(function, symbol, interval),
+        )
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::price`]
+    ///
+    /// ```no_run
+    /// use alpha_vantage::blocking::ApiClient;
+    ///
+    /// let api = ApiClient::set_api("demo", reqwest::Client::new()).unwrap();
+    /// println!("{}", api.price("MSFT").unwrap());
+    /// println!("{}", api.rate("BTC", "EUR").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn price(&self, symbol: &str) -> Result<f64> {
+        self.block_on(self.api_client.price(symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::rate`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn rate(
+        &self,
+        from_currency: impl Into<CurrencyCode>,
+        to_currency: impl Into<CurrencyCode>,
+    ) -> Result<f64> {
+        self.block_on(self.api_client.rate(from_currency, to_currency))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::validate_key`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn validate_key(&self) -> Result<KeyStatus> {
+        self.block_on(self.api_client.validate_key())
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::exchange_on`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn exchange_on(&self, from_currency: &str, to_currency: &str, date: &str) -> Result<f64> {
+        self.block_on(
+            self.api_client
+                .exchange_on(from_currency, to_currency, date),
+        )
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::exchange_on_as`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn exchange_on_as(
+        &self,
+        from_currency: &str,
+        to_currency: &str,
+        date: &str,
+        kind: CurrencyKind,
+    ) -> Result<f64> {
+        self.block_on(
+            self.api_client
+                .exchange_on_as(from_currency, to_currency, date, kind),
+        )
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::cross_exchange`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn cross_exchange(&self, from_currency: &str, to_currency: &str) -> Result<CrossRate> {
+        self.block_on(self.api_client.cross_exchange(from_currency, to_currency))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::currency_list`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn currency_list(&self, kind: CurrencyKind) -> Result<Vec<Currency>> {
+        self.block_on(self.api_client.currency_list(kind))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::resolve_symbol`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn resolve_symbol(&self, keywords: &str, prefs: ResolvePrefs) -> Result<Match> {
+        self.block_on(self.api_client.resolve_symbol(keywords, prefs))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::upcoming_report_date`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    #[cfg(feature = "chrono")]
+    pub fn upcoming_report_date(&self, symbol: &str) -> Result<Option<CalendarEntry>> {
+        self.block_on(self.api_client.upcoming_report_date(symbol))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::economic_indicators`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn economic_indicators(
+        &self,
+        specs: &[EconomicIndicatorSpec],
+    ) -> Result<AlignedIndicators> {
+        self.block_on(self.api_client.economic_indicators(specs))
+    }
+
+    /// Blocking version of [`crate::api::ApiClient::technical_indicators`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn technical_indicators(
+        &self,
+        symbol: &str,
+        interval: TechnicalIndicatorInterval,
+        specs: &[IndicatorSpec],
+    ) -> Result<HashMap<String, IndicatorResult>> {
+        self.block_on(
+            self.api_client
+                .technical_indicators(symbol, interval, specs),
+        )
+    }
+}
+
+/// Wrapper of builder whose output is returned by blocking until request
+/// completes. Builder can be configured with [`with`](Self::with) or
+/// [`builder_mut`](Self::builder_mut) before output is requested
+pub struct Blocking<'a, B> {
+    builder: B,
+    runtime: &'a Runtime,
+}
+
+impl<B> Blocking<'_, B> {
+    /// Configure builder using method of builder which takes builder by
+    /// value
+    #[must_use]
+    pub fn with(mut self, configure: impl FnOnce(B) -> B) -> Self {
+        self.builder = configure(self.builder);
+        self
+    }
+
+    /// Get builder which is wrapped
+    #[must_use]
+    pub fn builder(&self) -> &B {
+        &self.builder
+    }
+
+    /// Get mutable builder which is wrapped, which can be used for method of
+    /// builder which takes mutable reference of builder
+    pub fn builder_mut(&mut self) -> &mut B {
+        &mut self.builder
+    }
+}
+
+/// Create blocking version of async output method of builder
+macro_rules! blocking_output {
+    ($builder:ident { $($method:ident -> $output:ty),+ $(,)? }) => {
+        impl<'a> Blocking<'a, $builder<'a>> {
+            $(
+                #[doc = concat!(
+                    "Blocking version of [`",
+                    stringify!($builder),
+                    "::",
+                    stringify!($method),
+                    "`]"
+                )]
+                ///
+                /// # Errors
+                /// Raise error which is raised by async version of method
+                pub fn $method(&self) -> Result<$output> {
+                    self.runtime.block_on(self.builder.$method())
+                }
+            )+
+        }
+    };
+}
+
+blocking_output!(CryptoBuilder { json -> Crypto });
+blocking_output!(EarningBuilder { json -> Earning });
+blocking_output!(EarningsCalendarBuilder { csv -> Vec<CalendarEntry> });
+blocking_output!(EconomicIndicatorBuilder { json -> EconomicIndicator });
+blocking_output!(ExchangeBuilder { json -> Exchange });
+blocking_output!(ForexBuilder { json -> Forex });
+blocking_output!(IpoCalendarBuilder { csv -> Vec<Ipo> });
+blocking_output!(QuoteBuilder { json -> Quote, csv -> Quote });
+blocking_output!(SearchBuilder { json -> Search });
+blocking_output!(TimeSeriesBuilder { json -> TimeSeries });
+
+impl Blocking<'_, TechnicalIndicatorBuilder<'_>> {
+    /// Blocking version of [`TechnicalIndicatorBuilder::fetch_months`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn fetch_months(
+        &self,
+        range: std::ops::RangeInclusive<&str>,
+    ) -> Result<TechnicalIndicator> {
+        self.runtime.block_on(self.builder.fetch_months(range))
+    }
+}
+blocking_output!(TechnicalIndicatorBuilder {
+    json -> TechnicalIndicator,
+    json_single -> SingleValueIndicator,
+    json_macd -> MacdIndicator,
+    json_bbands -> BbandsIndicator,
+    json_stoch -> StochIndicator,
+    json_aroon -> AroonIndicator,
+});
+
+impl Blocking<'_, CustomBuilder<'_>> {
+    /// Blocking version of [`CustomBuilder::json`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn json<T>(&self) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.builder.json())
+    }
+
+    /// Blocking version of [`CustomBuilder::text`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn text(&self) -> Result<String> {
+        self.runtime.block_on(self.builder.text())
+    }
+
+    /// Blocking version of [`CustomBuilder::records`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn records<T>(&self) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.builder.records())
+    }
+
+    /// Blocking version of [`CustomBuilder::bytes`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        self.runtime.block_on(self.builder.bytes())
+    }
+
+    /// Blocking version of [`CustomBuilder::json_with_text`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    pub fn json_with_text<T>(&self) -> Result<(T, String)>
+    where
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.builder.json_with_text())
+    }
+
+    /// Blocking version of [`CustomBuilder::paged`]
+    ///
+    /// # Errors
+    /// Raise error which is raised by async version of method
+    #[allow(clippy::result_large_err)]
+    pub fn paged<T>(
+        &self,
+        param: &str,
+        values: impl IntoIterator<Item = String>,
+    ) -> std::result::Result<Vec<T>, PagedError<T>>
+    where
+        T: DeserializeOwned,
+    {
+        self.runtime.block_on(self.builder.paged(param, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiClient;
+    use crate::exchange::CurrencyKind;
+    use crate::test_utils::FixtureClient;
+
+    #[test]
+    fn convenience_methods() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route("function=CURRENCY_EXCHANGE_RATE", "exchange/btc_eur.json")
+            .route(
+                "digital_currency_list",
+                "exchange/physical_currency_list.csv",
+            );
+        let api = ApiClient::set_api("demo", client).unwrap();
+        assert_eq!(api.price("MSFT").unwrap().to_string(), "406.32");
+        assert_eq!(api.rate("BTC", "EUR").unwrap().to_string(), "48155.23");
+        assert!(!api.currency_list(CurrencyKind::Digital).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+//! Module for caching response of API used by `ApiClient`
+//!
+//! Response is cached with key created from url of request without API key,
+//! and cached response is used only if it is not older than time to live of
+//! builder which sends request
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+/// Trait which can be implemented for storing response of API. Cache is
+/// shared by all builders created from `ApiClient` so it needs to be safe
+/// under concurrent use
+pub trait Cache: Send + Sync {
+    /// Get response stored for key along with time when it was stored
+    fn get(&self, key: &str) -> Option<(String, Instant)>;
+
+    /// Store response for key
+    fn put(&self, key: &str, body: String);
+}
+
+/// Cache which stores response in memory
+///
+/// ```
+/// use alpha_vantage::cache::{Cache, InMemoryCache};
+///
+/// let cache = InMemoryCache::default();
+/// assert!(cache.get("query?function=GLOBAL_QUOTE&symbol=IBM").is_none());
+/// cache.put("query?function=GLOBAL_QUOTE&symbol=IBM", "{}".to_string());
+/// let (body, _) = cache.get("query?function=GLOBAL_QUOTE&symbol=IBM").unwrap();
+/// assert_eq!(body, "{}");
+/// assert_eq!(cache.len(), 1);
+/// cache.clear();
+/// assert!(cache.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl InMemoryCache {
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, (String, Instant)>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Number of stored response
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Check whether cache does not store any response
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Remove all stored response
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+}
+
+impl Cache for InMemoryCache {
+    fn get(&self, key: &str) -> Option<(String, Instant)> {
+        self.entries().get(key).cloned()
+    }
+
+    fn put(&self, key: &str, body: String) {
+        self.entries()
+            .insert(key.to_string(), (body, Instant::now()));
+    }
+}
@@ -0,0 +1,452 @@
+//! Module for earnings and IPO calendar
+//!
+//! These APIs return a list of company earnings expected in the next 3, 6, or
+//! 12 months and a list of IPOs expected in the next 3 months. Data is only
+//! returned in CSV format
+//!
+//! You can read about [Earnings Calendar][earnings_calendar] and [IPO
+//! Calendar][ipo_calendar] API and what it returns on alphavantage
+//! documentation
+//!
+//! [earnings_calendar]: https://www.alphavantage.co/documentation/#earnings-calendar
+//! [ipo_calendar]: https://www.alphavantage.co/documentation/#ipo-calendar
+
+use std::fmt::Write;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::api::{encode_query_value, ApiClient, Provider};
+#[cfg(feature = "chrono")]
+use crate::error::Error;
+use crate::error::Result;
+
+/// Struct to store information of scheduled earning report
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CalendarEntry {
+    symbol: String,
+    name: String,
+    #[serde(rename = "reportDate")]
+    report_date: String,
+    #[serde(rename = "fiscalDateEnding")]
+    fiscal_date_ending: String,
+    estimate: Option<f64>,
+    currency: String,
+    #[serde(rename = "timeOfTheDay", default)]
+    time_of_the_day: Option<String>,
+}
+
+impl CalendarEntry {
+    /// Return symbol of company
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of company
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return date on which earning is expected to be reported
+    #[must_use]
+    pub fn report_date(&self) -> &str {
+        &self.report_date
+    }
+
+    /// Return date on which earning is expected to be reported parsed as date
+    ///
+    /// # Errors
+    /// Raise error if report date is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn report_date_parsed(&self) -> Result<NaiveDate> {
+        NaiveDate::parse_from_str(&self.report_date, "%Y-%m-%d")
+            .map_err(|_| Error::ParseDateTime(self.report_date.clone()))
+    }
+
+    /// Return fiscal date ending of earning to be reported
+    #[must_use]
+    pub fn fiscal_date_ending(&self) -> &str {
+        &self.fiscal_date_ending
+    }
+
+    /// Return estimated EPS. Return None if there is no estimate
+    #[must_use]
+    pub fn estimate(&self) -> Option<f64> {
+        self.estimate
+    }
+
+    /// Return currency of estimate
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Return time of day when earning is expected to be reported. Return
+    /// `ReportTime::Unknown` if calendar does not provide report time
+    ///
+    /// ```no_run
+    /// use alpha_vantage::calendar::ReportTime;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let calendar = api.earnings_calendar().csv().await.unwrap();
+    ///     let pre_market = calendar
+    ///         .iter()
+    ///         .filter(|entry| entry.report_time() == ReportTime::PreMarket)
+    ///         .count();
+    ///     println!("{pre_market} earnings are reported before market opens");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn report_time(&self) -> ReportTime {
+        match self.time_of_the_day.as_deref().map(str::trim) {
+            Some(time) if time.eq_ignore_ascii_case("pre-market") => ReportTime::PreMarket,
+            Some(time) if time.eq_ignore_ascii_case("post-market") => ReportTime::PostMarket,
+            _ => ReportTime::Unknown,
+        }
+    }
+}
+
+/// Builder to help create list of `CalendarEntry`
+pub struct EarningsCalendarBuilder<'a> {
+    api_client: &'a ApiClient,
+    provider: Option<Provider>,
+    symbol: Option<&'a str>,
+    horizon: Option<Horizon>,
+}
+
+impl<'a> EarningsCalendarBuilder<'a> {
+    /// Create new `EarningsCalendarBuilder` from `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self {
+            api_client,
+            provider: None,
+            symbol: None,
+            horizon: None,
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Only return scheduled earning of symbol. By default all scheduled
+    /// earnings are returned
+    #[must_use]
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    /// Define horizon of calendar. By default API returns three month horizon
+    #[must_use]
+    pub fn horizon(mut self, horizon: Horizon) -> Self {
+        self.horizon = Some(horizon);
+        self
+    }
+
+    /// Returns scheduled earnings parsed from CSV
+    ///
+    /// ```no_run
+    /// use alpha_vantage::calendar::Horizon;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let calendar = api
+    ///         .earnings_calendar()
+    ///         .symbol("IBM")
+    ///         .horizon(Horizon::TwelveMonth)
+    ///         .csv()
+    ///         .await
+    ///         .unwrap();
+    ///     for entry in calendar {
+    ///         println!("{} reports on {}", entry.symbol(), entry.report_date());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors
+    pub async fn csv(&self) -> Result<Vec<CalendarEntry>> {
+        let url = self.create_url();
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_csv_rows(&url)
+            .await
+    }
+
+    fn create_url(&self) -> String {
+        let mut url = String::from("query?function=EARNINGS_CALENDAR");
+        if let Some(horizon) = &self.horizon {
+            let horizon = match horizon {
+                Horizon::ThreeMonth => "3month",
+                Horizon::SixMonth => "6month",
+                Horizon::TwelveMonth => "12month",
+            };
+            write!(url, "&horizon={horizon}").unwrap();
+        }
+        if let Some(symbol) = self.symbol {
+            write!(url, "&symbol={}", encode_query_value(symbol)).unwrap();
+        }
+        url
+    }
+}
+
+/// Struct to store information of upcoming IPO
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Ipo {
+    symbol: String,
+    name: String,
+    #[serde(rename = "ipoDate")]
+    date: String,
+    #[serde(rename = "priceRangeLow")]
+    price_range_low: Option<f64>,
+    #[serde(rename = "priceRangeHigh")]
+    price_range_high: Option<f64>,
+    currency: String,
+    exchange: String,
+}
+
+impl Ipo {
+    /// Return symbol of company
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of company
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return expected date of IPO
+    #[must_use]
+    pub fn ipo_date(&self) -> &str {
+        &self.date
+    }
+
+    /// Return expected date of IPO parsed as date
+    ///
+    /// # Errors
+    /// Raise error if IPO date is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn ipo_date_parsed(&self) -> Result<NaiveDate> {
+        NaiveDate::parse_from_str(&self.date, "%Y-%m-%d")
+            .map_err(|_| Error::ParseDateTime(self.date.clone()))
+    }
+
+    /// Return low end of offering price range. Return None if price range is
+    /// not announced yet
+    #[must_use]
+    pub fn price_range_low(&self) -> Option<f64> {
+        self.price_range_low
+    }
+
+    /// Return high end of offering price range. Return None if price range is
+    /// not announced yet
+    #[must_use]
+    pub fn price_range_high(&self) -> Option<f64> {
+        self.price_range_high
+    }
+
+    /// Return currency of offering price
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Return exchange where company is going to be listed
+    #[must_use]
+    pub fn exchange(&self) -> &str {
+        &self.exchange
+    }
+}
+
+/// Builder to help create list of `Ipo`
+pub struct IpoCalendarBuilder<'a> {
+    api_client: &'a ApiClient,
+    provider: Option<Provider>,
+}
+
+impl<'a> IpoCalendarBuilder<'a> {
+    /// Create new `IpoCalendarBuilder` from `APIClient`
+    #[must_use]
+    pub fn new(api_client: &'a ApiClient) -> Self {
+        Self {
+            api_client,
+            provider: None,
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Returns upcoming IPOs parsed from CSV
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     for ipo in api.ipo_calendar().csv().await.unwrap() {
+    ///         println!("{} lists on {}", ipo.symbol(), ipo.ipo_date());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors
+    pub async fn csv(&self) -> Result<Vec<Ipo>> {
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_csv_rows("query?function=IPO_CALENDAR")
+            .await
+    }
+}
+
+/// Enum for time of day when earning is reported
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportTime {
+    /// reported before market opens
+    PreMarket,
+    /// reported after market closes
+    PostMarket,
+    /// report time is not known
+    Unknown,
+}
+
+/// Enum for declaring horizon of calendar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Horizon {
+    /// three month horizon
+    ThreeMonth,
+    /// six month horizon
+    SixMonth,
+    /// twelve month horizon
+    TwelveMonth,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Horizon, ReportTime};
+    use crate::error::Error;
+    use crate::test_utils::FixtureClient;
+
+    #[tokio::test]
+    async fn report_time_of_entry() {
+        let api = crate::set_api("demo", FixtureClient::new("calendar/earnings_time.csv"));
+        let calendar = api.earnings_calendar().csv().await.unwrap();
+        assert_eq!(calendar[0].report_time(), ReportTime::PostMarket);
+        assert_eq!(calendar[1].report_time(), ReportTime::PreMarket);
+        assert_eq!(calendar[2].report_time(), ReportTime::Unknown);
+
+        // calendar without time of the day column
+        let api = crate::set_api("demo", FixtureClient::new("calendar/earnings.csv"));
+        let calendar = api.earnings_calendar().csv().await.unwrap();
+        assert!(
+            calendar
+                .iter()
+                .all(|entry| entry.report_time() == ReportTime::Unknown)
+        );
+    }
+
+    #[tokio::test]
+    async fn earnings_calendar_csv() {
+        let client = FixtureClient::new("error/rate_limit.json")
+            .route("horizon=6month&", "calendar/earnings.csv");
+        let api = crate::set_api("demo", client.clone());
+        let calendar = api
+            .earnings_calendar()
+            .horizon(Horizon::SixMonth)
+            .csv()
+            .await
+            .unwrap();
+        assert_eq!(calendar.len(), 4);
+
+        let berkshire = &calendar[2];
+        assert_eq!(berkshire.symbol(), "BRK-B");
+        assert_eq!(berkshire.name(), "Berkshire Hathaway Inc, Class B");
+        assert_eq!(berkshire.report_date(), "2024-05-04");
+        assert_eq!(berkshire.fiscal_date_ending(), "2024-03-31");
+        assert_eq!(
+            berkshire
+                .estimate()
+                .map(|estimate| estimate.to_string())
+                .as_deref(),
+            Some("4.92")
+        );
+        assert_eq!(berkshire.currency(), "USD");
+
+        let tesco = &calendar[3];
+        assert!(tesco.estimate().is_none());
+        assert_eq!(tesco.currency(), "GBX");
+
+        // error is returned as json even though output is csv
+        let error = api
+            .earnings_calendar()
+            .horizon(Horizon::TwelveMonth)
+            .csv()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageInformation(_)));
+
+        for url in client.urls() {
+            assert!(url.contains("function=EARNINGS_CALENDAR&horizon="));
+            assert!(!url.contains("datatype="));
+        }
+    }
+
+    #[tokio::test]
+    async fn ipo_calendar_csv() {
+        let client = FixtureClient::new("calendar/ipo.csv");
+        let ipos = crate::set_rapid_api("demo", client.clone())
+            .ipo_calendar()
+            .csv()
+            .await
+            .unwrap();
+        assert!(client.urls()[0].ends_with("query?function=IPO_CALENDAR"));
+        assert_eq!(ipos.len(), 3);
+
+        let price = |price: Option<f64>| price.map(|price| price.to_string());
+        let reddit = &ipos[1];
+        assert_eq!(reddit.symbol(), "RDDT");
+        assert_eq!(reddit.ipo_date(), "2024-03-21");
+        assert_eq!(price(reddit.price_range_low()).as_deref(), Some("31"));
+        assert_eq!(price(reddit.price_range_high()).as_deref(), Some("34"));
+        assert_eq!(reddit.currency(), "USD");
+        assert_eq!(reddit.exchange(), "NYSE");
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            reddit.ipo_date_parsed().unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 21).unwrap()
+        );
+
+        assert_eq!(ipos[0].name(), "Acri Capital Merger Corp II, Units");
+
+        let sage = &ipos[2];
+        assert!(sage.price_range_low().is_none());
+        assert!(sage.price_range_high().is_none());
+        assert_eq!(sage.exchange(), "LSE");
+    }
+}
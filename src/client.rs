@@ -1,48 +1,1157 @@
 use async_trait::async_trait;
 
-use crate::error::{Error, Result};
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "isahc-client",
+    feature = "reqwest-blocking-client",
+    feature = "reqwest-client",
+    feature = "ureq-client"
+))]
+use crate::error::Error;
+use crate::error::Result;
+
+/// Response of HTTP request along with its status code. Body is text by
+/// default and raw bytes for [`get_bytes`](HttpClient::get_bytes)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse<B = String> {
+    /// HTTP status code of response
+    pub status: u16,
+    /// body of response
+    pub body: B,
+}
+
+impl<B> HttpResponse<B> {
+    /// Check whether status code of response is 2xx
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Host of `RapidAPI` provider which is sent in `x-rapidapi-host` header
+const RAPID_API_HOST: &str = "alpha-vantage.p.rapidapi.com";
+
+/// Name of headers which are set for `RapidAPI` provider
+pub(crate) const RAPID_API_HEADERS: [&str; 2] = ["x-rapidapi-host", "x-rapidapi-key"];
+
+/// Extend extra headers with headers required by `RapidAPI` provider
+pub(crate) fn rapid_api_headers<'a>(
+    api_key: &'a str,
+    headers: &[(&'a str, &'a str)],
+) -> Vec<(&'a str, &'a str)> {
+    let mut headers = headers.to_vec();
+    headers.push((RAPID_API_HEADERS[0], RAPID_API_HOST));
+    headers.push((RAPID_API_HEADERS[1], api_key));
+    headers
+}
 
 #[async_trait]
 /// Trait which can be implemented for all common library client for getting
 /// output from server
-/// reqwest is client which is supported with feature flag. If
-/// you prefer alternate http client you can add support by implementing
-/// `HttpClient` trait for client.
-/// Some example of other client which can be used are surf and isahc client
+/// `reqwest`, `hyper`, `ureq` and `isahc` are clients which are supported with
+/// feature flag. If you prefer alternate http client you can add support by
+/// implementing `HttpClient` trait for client.
+/// Some example of other client which can be used is `surf` client
+///
+/// Client needs to implement [`get`](Self::get) which sends GET request along
+/// with headers and returns status code along with body of response, so error
+/// page returned by server can be told apart from valid body. Headers required
+/// by provider are built by [`ApiClient`](crate::api::ApiClient), so client
+/// does not need to know about provider
+///
+/// # Migration
+/// Methods of provider which return only body of response are deprecated and
+/// are no longer called by crate. To migrate, move body of
+/// `get_alpha_vantage_provider_output` into [`get`](Self::get), send headers
+/// passed to it and return [`HttpResponse`] with status code of response
+///
+/// ```
+/// use alpha_vantage::client::{HttpClient, HttpResponse};
+/// use alpha_vantage::error::Result;
+///
+/// // client which answers every request with maintenance page
+/// struct Maintenance;
+///
+/// #[async_trait::async_trait]
+/// impl HttpClient for Maintenance {
+///     async fn get(&self, _: &str, _: &[(&str, &str)]) -> Result<HttpResponse> {
+///         Ok(HttpResponse {
+///             status: 503,
+///             body: "under maintenance".to_string(),
+///         })
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", Maintenance);
+///     let error = api.quote("IBM").json().await.unwrap_err();
+///     assert!(error.is_retryable());
+/// }
+/// ```
 pub trait HttpClient {
-    /// AlphaVantage provider output function which provides one field path
-    /// where get GET request needs to be performed
-    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String>;
+    /// Send GET request to url along with headers and return status code
+    /// along with body of response
+    ///
+    /// ```no_run
+    /// use alpha_vantage::client::HttpClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = reqwest::Client::new();
+    ///     let response = HttpClient::get(
+    ///         &client,
+    ///         "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&apikey=demo",
+    ///         &[("x-trace-id", "42")],
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///     println!("{} {}", response.status, response.body);
+    /// }
+    /// ```
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse>;
 
-    /// RapidAPI provider function which provides two field path and api_key.
-    /// Path needs to be set along with header x-rapidapi-host as
-    /// alpha-vantage.p.rapidapi.com and header x-rapidapi-key same as
-    /// api_key field
-    async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String>;
+    /// Send GET request to url along with headers and return status code
+    /// along with raw bytes of body without decoding it as text. By default
+    /// it calls [`get`](Self::get), so client needs to implement it for
+    /// downloading binary output which is not valid UTF-8
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let response = self.get(url, headers).await?;
+        Ok(HttpResponse {
+            status: response.status,
+            body: response.body.into_bytes(),
+        })
+    }
+
+    /// `AlphaVantage` provider output function which provides one field path
+    /// where get GET request needs to be performed. By default it calls
+    /// [`get`](Self::get) and discards status code
+    #[deprecated(note = "implement `get` instead")]
+    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
+        Ok(self.get(path, &[]).await?.body)
+    }
+
+    /// `RapidAPI` provider function which provides two field path and
+    /// `api_key`. Path needs to be set along with header `x-rapidapi-host` as
+    /// `alpha-vantage.p.rapidapi.com` and header `x-rapidapi-key` same as
+    /// `api_key` field. By default it calls [`get`](Self::get) with
+    /// `RapidAPI` headers and discards status code
+    #[deprecated(note = "implement `get` instead")]
+    async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
+        Ok(self.get(path, &rapid_api_headers(api_key, &[])).await?.body)
+    }
 }
 
+/// `reqwest` client which is used by default. Timeout and other options of
+/// request are taken from configuration of client. Failure of request is
+/// reported as connection, TLS, timeout or transport error
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::error::Error;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     drop(listener);
+///     let client = reqwest::Client::builder().no_proxy().build().unwrap();
+///     let api = ApiClient::set_api("demo", client)
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Connect(_)));
+///
+///     // server accepts connection but never responds
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     let client = reqwest::Client::builder()
+///         .no_proxy()
+///         .timeout(Duration::from_millis(100))
+///         .build()
+///         .unwrap();
+///     let api = ApiClient::set_api("demo", client)
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Timeout));
+///     drop(listener);
+/// }
+/// ```
 #[cfg(feature = "reqwest-client")]
 #[async_trait]
 impl HttpClient for reqwest::Client {
-    async fn get_alpha_vantage_provider_output(&self, path: &str) -> Result<String> {
-        self.get(path)
-            .send()
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        let response = send_reqwest(self, url, headers).await?;
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(reqwest_error)?;
+        Ok(HttpResponse { status, body })
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let response = send_reqwest(self, url, headers).await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await.map_err(reqwest_error)?;
+        Ok(HttpResponse {
+            status,
+            body: body.to_vec(),
+        })
+    }
+}
+
+// Send GET request with headers using `reqwest` client
+#[cfg(feature = "reqwest-client")]
+async fn send_reqwest(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &[(&str, &str)],
+) -> Result<reqwest::Response> {
+    let mut request = client.get(url);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    request.send().await.map_err(reqwest_error)
+}
+
+/// Default limit of size of body collected by `hyper`, `isahc`, `ureq` and
+/// blocking `reqwest` client
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "isahc-client",
+    feature = "reqwest-blocking-client",
+    feature = "ureq-client"
+))]
+const DEFAULT_BODY_LIMIT: usize = 32 * 1024 * 1024;
+
+// Decode raw body of response as text, replacing invalid UTF-8 sequence
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "isahc-client",
+    feature = "reqwest-blocking-client",
+    feature = "ureq-client"
+))]
+fn text_response(response: HttpResponse<Vec<u8>>) -> HttpResponse {
+    let body = String::from_utf8(response.body)
+        .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned());
+    HttpResponse {
+        status: response.status,
+        body,
+    }
+}
+
+#[cfg(feature = "hyper-client")]
+type HyperConnector =
+    hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>;
+
+#[cfg(feature = "hyper-client")]
+type HyperBody = http_body_util::Empty<hyper::body::Bytes>;
+
+/// Client which sends request using `hyper` for application which already
+/// depends on `hyper` and does not want to depend on `reqwest`. TLS is
+/// provided by `rustls` with webpki roots and redirects are never followed,
+/// so 3xx response is returned as it is. Client needs to be used inside
+/// `tokio` runtime
+///
+/// ```
+/// # fn serve(expected: &'static [&'static str]) -> String {
+/// #     use std::io::{Read, Write};
+/// #     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+/// #     let address = listener.local_addr().unwrap();
+/// #     std::thread::spawn(move || {
+/// #         let (mut stream, _) = listener.accept().unwrap();
+/// #         let mut request = [0; 4096];
+/// #         let read = stream.read(&mut request).unwrap();
+/// #         let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+/// #         for line in expected {
+/// #             assert!(request.contains(line), "{request}");
+/// #         }
+/// #         let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/");
+/// #         let body = std::fs::read_to_string(format!("{dir}quote/global_quote.json")).unwrap();
+/// #         write!(
+/// #             stream,
+/// #             "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+/// #             body.len()
+/// #         )
+/// #         .unwrap();
+/// #     });
+/// #     format!("http://{address}/")
+/// # }
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::client::HyperClient;
+/// use alpha_vantage::error::Error;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = HyperClient::new()
+///         .with_header("user-agent", "alpha-vantage-test")
+///         .unwrap();
+///
+///     let base_url = serve(&[
+///         "get /query?function=global_quote&symbol=msft&apikey=demo ",
+///         "user-agent: alpha-vantage-test",
+///     ]);
+///     let api = ApiClient::set_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let base_url = serve(&[
+///         "get /query?function=global_quote&symbol=msft ",
+///         "x-rapidapi-host: alpha-vantage.p.rapidapi.com",
+///         "x-rapidapi-key: demo",
+///         "user-agent: alpha-vantage-test",
+///     ]);
+///     let api = ApiClient::set_rapid_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     drop(listener);
+///     let api = ApiClient::set_api("demo", client)
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Connect(_)));
+///     assert!(error.is_retryable());
+/// }
+/// ```
+#[cfg(feature = "hyper-client")]
+#[derive(Clone, Debug)]
+pub struct HyperClient {
+    client: hyper_util::client::legacy::Client<HyperConnector, HyperBody>,
+    headers: hyper::HeaderMap,
+    body_limit: usize,
+    timeout: Option<std::time::Duration>,
+}
+
+#[cfg(feature = "hyper-client")]
+impl Default for HyperClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "hyper-client")]
+impl HyperClient {
+    /// Create new client which collects body up to 32 MiB and does not time
+    /// out request
+    #[must_use]
+    pub fn new() -> Self {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+        let client =
+            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build(connector);
+        Self {
+            client,
+            headers: hyper::HeaderMap::new(),
+            body_limit: DEFAULT_BODY_LIMIT,
+            timeout: None,
+        }
+    }
+
+    /// Add default header which is sent along with every request
+    ///
+    /// # Errors
+    /// Raise error if name or value is not valid header
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self> {
+        let invalid = || Error::InvalidParameter {
+            parameter: "header".to_string(),
+            value: format!("{name}: {value}"),
+        };
+        let name = hyper::header::HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?;
+        let value = hyper::header::HeaderValue::from_str(value).map_err(|_| invalid())?;
+        self.headers.append(name, value);
+        Ok(self)
+    }
+
+    /// Set maximum size of body in bytes which is collected from response
+    #[must_use]
+    pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+
+    /// Set timeout of whole request including collection of body
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // Send GET request with default and extra headers and collect status code
+    // along with body of response
+    async fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<(u16, Vec<u8>)> {
+        let output = self.send(url, headers);
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, output)
+                .await
+                .map_err(|_| Error::Timeout)?,
+            None => output.await,
+        }
+    }
+
+    async fn send(&self, url: &str, headers: &[(&str, &str)]) -> Result<(u16, Vec<u8>)> {
+        use http_body_util::BodyExt;
+
+        let mut request = hyper::Request::get(url);
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let request = request
+            .body(HyperBody::new())
+            .map_err(|error| Error::Transport(error.to_string()))?;
+        let response = self
+            .client
+            .request(request)
             .await
-            .map_err(|_| Error::GetRequestFailed)?
-            .text()
+            .map_err(|error| hyper_error(&error))?;
+        let status = response.status().as_u16();
+        let body = http_body_util::Limited::new(response.into_body(), self.body_limit)
+            .collect()
             .await
-            .map_err(|_| Error::GetRequestFailed)
+            .map_err(|error| {
+                if error.is::<http_body_util::LengthLimitError>() {
+                    Error::ResponseTooLarge(self.body_limit)
+                } else {
+                    Error::Transport(error_chain(error.as_ref()))
+                }
+            })?;
+        Ok((status, body.to_bytes().to_vec()))
     }
+}
 
-    async fn get_rapid_api_provider_output(&self, path: &str, api_key: &str) -> Result<String> {
-        self.get(path)
-            .header("x-rapidapi-host", "alpha-vantage.p.rapidapi.com")
-            .header("x-rapidapi-key", api_key)
-            .send()
-            .await
-            .map_err(|_| Error::GetRequestFailed)?
-            .text()
+#[cfg(feature = "hyper-client")]
+#[async_trait]
+impl HttpClient for HyperClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        Ok(text_response(self.get_bytes(url, headers).await?))
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let (status, body) = self.fetch(url, headers).await?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// `ureq` client which sends blocking request. Client is intended to be used
+/// with [blocking client](crate::blocking) so that async runtime is not
+/// needed by application. If client is used inside `tokio` runtime request is
+/// sent on blocking thread of runtime so executor is not blocked. Timeout of
+/// request is taken from configuration of agent
+///
+/// ```
+/// # fn serve(status: &'static str, expected: &'static [&'static str]) -> String {
+/// #     use std::io::{Read, Write};
+/// #     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+/// #     let address = listener.local_addr().unwrap();
+/// #     std::thread::spawn(move || {
+/// #         let (mut stream, _) = listener.accept().unwrap();
+/// #         let mut request = [0; 4096];
+/// #         let read = stream.read(&mut request).unwrap();
+/// #         let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+/// #         for line in expected {
+/// #             assert!(request.contains(line), "{request}");
+/// #         }
+/// #         let body = if status.starts_with("200") {
+/// #             let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/");
+/// #             std::fs::read_to_string(format!("{dir}quote/global_quote.json")).unwrap()
+/// #         } else {
+/// #             "<html>Service Unavailable</html>".to_string()
+/// #         };
+/// #         write!(
+/// #             stream,
+/// #             "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+/// #             body.len()
+/// #         )
+/// #         .unwrap();
+/// #     });
+/// #     format!("http://{address}/")
+/// # }
+/// use std::time::Duration;
+///
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::error::Error;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let agent = ureq::AgentBuilder::new()
+///         .timeout(Duration::from_secs(5))
+///         .build();
+///
+///     let base_url = serve(
+///         "200 OK",
+///         &["get /query?function=global_quote&symbol=msft&apikey=demo "],
+///     );
+///     let api = ApiClient::set_api("demo", agent.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let base_url = serve(
+///         "200 OK",
+///         &[
+///             "get /query?function=global_quote&symbol=msft ",
+///             "x-rapidapi-host: alpha-vantage.p.rapidapi.com",
+///             "x-rapidapi-key: demo",
+///         ],
+///     );
+///     let api = ApiClient::set_rapid_api("demo", agent.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let base_url = serve("503 Service Unavailable", &["x-rapidapi-key: demo"]);
+///     let api = ApiClient::set_rapid_api("demo", agent)
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::HttpStatus { code: 503, .. }));
+/// }
+/// ```
+#[cfg(feature = "ureq-client")]
+#[async_trait]
+impl HttpClient for ureq::Agent {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        Ok(text_response(self.get_bytes(url, headers).await?))
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let (status, body) = run_blocking(self, url, headers, fetch_ureq).await?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+// Function which sends blocking GET request with headers using client
+#[cfg(any(feature = "reqwest-blocking-client", feature = "ureq-client"))]
+type BlockingRequest<C, T> = fn(&C, &str, &[(&str, &str)]) -> Result<T>;
+
+// Send blocking request using client. Request is sent on blocking thread if
+// it is called inside `tokio` runtime otherwise it is sent on current thread
+#[cfg(any(feature = "reqwest-blocking-client", feature = "ureq-client"))]
+async fn run_blocking<C, T>(
+    client: &C,
+    url: &str,
+    headers: &[(&str, &str)],
+    request: BlockingRequest<C, T>,
+) -> Result<T>
+where
+    C: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        return request(client, url, headers);
+    };
+    let client = client.clone();
+    let url = url.to_string();
+    let headers = headers
+        .iter()
+        .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+        .collect::<Vec<_>>();
+    handle
+        .spawn_blocking(move || {
+            let headers = headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect::<Vec<_>>();
+            request(&client, &url, &headers)
+        })
+        .await
+        .map_err(|error| Error::Transport(error.to_string()))?
+}
+
+// Send blocking GET request and collect status code along with body of
+// response. Response with non 2xx status is returned as it is
+#[cfg(feature = "ureq-client")]
+fn fetch_ureq(agent: &ureq::Agent, url: &str, headers: &[(&str, &str)]) -> Result<(u16, Vec<u8>)> {
+    use std::io::Read;
+
+    let mut request = agent.get(url);
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+    let response = match request.call() {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(transport)) => return Err(ureq_error(&transport)),
+    };
+    let status = response.status();
+    let mut body = Vec::new();
+    response
+        .into_reader()
+        .take(DEFAULT_BODY_LIMIT as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|error| {
+            if error.kind() == std::io::ErrorKind::TimedOut {
+                Error::Timeout
+            } else {
+                Error::Transport(error.to_string())
+            }
+        })?;
+    if body.len() > DEFAULT_BODY_LIMIT {
+        return Err(Error::ResponseTooLarge(DEFAULT_BODY_LIMIT));
+    }
+    Ok((status, body))
+}
+
+// Map transport error of `ureq` to transport error of crate. Message is built
+// without URL of request since it contains API key
+#[cfg(feature = "ureq-client")]
+fn ureq_error(error: &ureq::Transport) -> Error {
+    let timed_out =
+        std::iter::successors(std::error::Error::source(error), |current| current.source())
+            .filter_map(|current| current.downcast_ref::<std::io::Error>())
+            .any(|error| error.kind() == std::io::ErrorKind::TimedOut);
+    if timed_out {
+        return Error::Timeout;
+    }
+    if let Some(tls) = tls_error(error) {
+        return tls;
+    }
+    match error.kind() {
+        ureq::ErrorKind::InvalidUrl | ureq::ErrorKind::UnknownScheme => Error::CreateUrl,
+        ureq::ErrorKind::Dns | ureq::ErrorKind::ConnectionFailed => {
+            Error::Connect(ureq_message(error))
+        }
+        _ => Error::Transport(ureq_message(error)),
+    }
+}
+
+// Message of `ureq` transport error along with its sources, without URL
+#[cfg(feature = "ureq-client")]
+fn ureq_message(error: &ureq::Transport) -> String {
+    let mut message = error.kind().to_string();
+    if let Some(detail) = error.message() {
+        message.push_str(": ");
+        message.push_str(detail);
+    }
+    if let Some(source) = std::error::Error::source(error) {
+        message.push_str(": ");
+        message.push_str(&error_chain(source));
+    }
+    message
+}
+
+/// Adapter which sends request using blocking `reqwest` client. Adapter is
+/// intended to be used with [blocking client](crate::blocking) so that async
+/// runtime is not needed by application. If adapter is used inside `tokio`
+/// runtime request is sent on blocking thread of runtime so executor is not
+/// blocked. Timeout of request is taken from configuration of client
+///
+/// ```
+/// # fn serve(expected: &'static [&'static str]) -> String {
+/// #     use std::io::{Read, Write};
+/// #     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+/// #     let address = listener.local_addr().unwrap();
+/// #     std::thread::spawn(move || {
+/// #         let (mut stream, _) = listener.accept().unwrap();
+/// #         let mut request = [0; 4096];
+/// #         let read = stream.read(&mut request).unwrap();
+/// #         let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+/// #         for line in expected {
+/// #             assert!(request.contains(line), "{request}");
+/// #         }
+/// #         let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/");
+/// #         let body = std::fs::read_to_string(format!("{dir}quote/global_quote.json")).unwrap();
+/// #         write!(
+/// #             stream,
+/// #             "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+/// #             body.len()
+/// #         )
+/// #         .unwrap();
+/// #     });
+/// #     format!("http://{address}/")
+/// # }
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::client::BlockingReqwest;
+/// use alpha_vantage::error::Error;
+///
+/// let client = BlockingReqwest(reqwest::blocking::Client::builder().no_proxy().build().unwrap());
+///
+/// // through blocking client
+/// let base_url = serve(&["get /query?function=global_quote&symbol=msft&apikey=demo "]);
+/// let api = ApiClient::set_api("demo", client.clone())
+///     .with_base_url(base_url)
+///     .unwrap();
+/// let api = alpha_vantage::blocking::ApiClient::new(api).unwrap();
+/// assert_eq!(api.quote("MSFT").json().unwrap().symbol(), "MSFT");
+///
+/// // from async context
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// runtime.block_on(async {
+///     let base_url = serve(&[
+///         "get /query?function=global_quote&symbol=msft ",
+///         "x-rapidapi-host: alpha-vantage.p.rapidapi.com",
+///         "x-rapidapi-key: demo",
+///     ]);
+///     let api = ApiClient::set_rapid_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     drop(listener);
+///     let api = ApiClient::set_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Connect(_)));
+/// });
+/// ```
+#[cfg(feature = "reqwest-blocking-client")]
+#[derive(Clone, Debug, Default)]
+pub struct BlockingReqwest(pub reqwest::blocking::Client);
+
+#[cfg(feature = "reqwest-blocking-client")]
+#[async_trait]
+impl HttpClient for BlockingReqwest {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        Ok(text_response(self.get_bytes(url, headers).await?))
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let (status, body) = run_blocking(&self.0, url, headers, fetch_blocking_reqwest).await?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+// Send blocking GET request using `reqwest` and collect status code along with
+// body of response
+#[cfg(feature = "reqwest-blocking-client")]
+fn fetch_blocking_reqwest(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    headers: &[(&str, &str)],
+) -> Result<(u16, Vec<u8>)> {
+    use std::io::Read;
+
+    let mut request = client.get(url);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let response = request.send().map_err(reqwest_error)?;
+    let status = response.status().as_u16();
+    let mut body = Vec::new();
+    response
+        .take(DEFAULT_BODY_LIMIT as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|error| {
+            if error.kind() == std::io::ErrorKind::TimedOut {
+                Error::Timeout
+            } else {
+                Error::Transport(error.to_string())
+            }
+        })?;
+    if body.len() > DEFAULT_BODY_LIMIT {
+        return Err(Error::ResponseTooLarge(DEFAULT_BODY_LIMIT));
+    }
+    Ok((status, body))
+}
+
+// Map error of `reqwest` to transport error of crate. URL of request is
+// stripped since it contains API key
+#[cfg(any(feature = "reqwest-blocking-client", feature = "reqwest-client"))]
+fn reqwest_error(error: reqwest::Error) -> Error {
+    let error = error.without_url();
+    if error.is_timeout() {
+        return Error::Timeout;
+    }
+    if let Some(tls) = tls_error(&error) {
+        return tls;
+    }
+    if error.is_builder() {
+        Error::CreateUrl
+    } else if error.is_connect() {
+        Error::Connect(error_chain(&error))
+    } else {
+        Error::Transport(error_chain(&error))
+    }
+}
+
+/// `isahc` client which sends request using `curl`. Timeout and other options
+/// of request are taken from configuration of client
+///
+/// ```
+/// # fn serve(expected: &'static [&'static str]) -> String {
+/// #     use std::io::{Read, Write};
+/// #     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+/// #     let address = listener.local_addr().unwrap();
+/// #     std::thread::spawn(move || {
+/// #         let (mut stream, _) = listener.accept().unwrap();
+/// #         let mut request = [0; 4096];
+/// #         let read = stream.read(&mut request).unwrap();
+/// #         let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+/// #         for line in expected {
+/// #             assert!(request.contains(line), "{request}");
+/// #         }
+/// #         let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/");
+/// #         let body = std::fs::read_to_string(format!("{dir}quote/global_quote.json")).unwrap();
+/// #         write!(
+/// #             stream,
+/// #             "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+/// #             body.len()
+/// #         )
+/// #         .unwrap();
+/// #     });
+/// #     format!("http://{address}/")
+/// # }
+/// use std::time::Duration;
+///
+/// use alpha_vantage::api::ApiClient;
+/// use alpha_vantage::error::Error;
+/// use isahc::config::Configurable;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = isahc::HttpClient::builder()
+///         .timeout(Duration::from_millis(500))
+///         .build()
+///         .unwrap();
+///
+///     let base_url = serve(&["get /query?function=global_quote&symbol=msft&apikey=demo "]);
+///     let api = ApiClient::set_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     let base_url = serve(&[
+///         "get /query?function=global_quote&symbol=msft ",
+///         "x-rapidapi-host: alpha-vantage.p.rapidapi.com",
+///         "x-rapidapi-key: demo",
+///     ]);
+///     let api = ApiClient::set_rapid_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     assert_eq!(api.quote("MSFT").json().await.unwrap().symbol(), "MSFT");
+///
+///     // server which accepts connection but never responds
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     let api = ApiClient::set_api("demo", client.clone())
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Timeout));
+///     drop(listener);
+///
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let base_url = format!("http://{}/", listener.local_addr().unwrap());
+///     drop(listener);
+///     let api = ApiClient::set_api("demo", client)
+///         .with_base_url(base_url)
+///         .unwrap();
+///     let error = api.quote("MSFT").json().await.unwrap_err();
+///     assert!(matches!(error.inner(), Error::Connect(_)));
+/// }
+/// ```
+#[cfg(feature = "isahc-client")]
+#[async_trait]
+impl HttpClient for isahc::HttpClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        Ok(text_response(self.get_bytes(url, headers).await?))
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        let (status, body) = send_isahc(self, url, headers).await?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+// Send GET request using `isahc` client and collect status code along with
+// body of response
+#[cfg(feature = "isahc-client")]
+async fn send_isahc(
+    client: &isahc::HttpClient,
+    url: &str,
+    headers: &[(&str, &str)],
+) -> Result<(u16, Vec<u8>)> {
+    use futures::AsyncReadExt;
+
+    let mut request = isahc::Request::get(url);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = request
+        .body(())
+        .map_err(|error| Error::Transport(error.to_string()))?;
+    let response = client
+        .send_async(request)
+        .await
+        .map_err(|error| isahc_error(&error))?;
+    let status = response.status().as_u16();
+    let mut body = Vec::new();
+    response
+        .into_body()
+        .take(DEFAULT_BODY_LIMIT as u64 + 1)
+        .read_to_end(&mut body)
+        .await
+        .map_err(|error| {
+            if error.kind() == std::io::ErrorKind::TimedOut {
+                Error::Timeout
+            } else {
+                Error::Transport(error.to_string())
+            }
+        })?;
+    if body.len() > DEFAULT_BODY_LIMIT {
+        return Err(Error::ResponseTooLarge(DEFAULT_BODY_LIMIT));
+    }
+    Ok((status, body))
+}
+
+// Map kind of `isahc` error to transport error of crate
+#[cfg(feature = "isahc-client")]
+fn isahc_error(error: &isahc::Error) -> Error {
+    use isahc::error::ErrorKind;
+
+    match error.kind() {
+        ErrorKind::Timeout => Error::Timeout,
+        ErrorKind::BadClientCertificate
+        | ErrorKind::BadServerCertificate
+        | ErrorKind::TlsEngine => Error::Tls(error.to_string()),
+        ErrorKind::ConnectionFailed | ErrorKind::NameResolution => {
+            Error::Connect(error.to_string())
+        }
+        _ => Error::Transport(error.to_string()),
+    }
+}
+
+// Map error of hyper client to transport error
+#[cfg(feature = "hyper-client")]
+fn hyper_error(error: &hyper_util::client::legacy::Error) -> Error {
+    if let Some(tls) = tls_error(error) {
+        tls
+    } else if error.is_connect() {
+        Error::Connect(error_chain(error))
+    } else {
+        Error::Transport(error_chain(error))
+    }
+}
+
+// Find TLS error in sources of error. TLS error is reported as io error by
+// connector so inner error of io error is also checked
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "reqwest-blocking-client",
+    feature = "reqwest-client",
+    feature = "ureq-client"
+))]
+fn tls_error(error: &(dyn std::error::Error + 'static)) -> Option<Error> {
+    let mut source = Some(error);
+    while let Some(current) = source {
+        let tls = current.downcast_ref::<rustls::Error>().or_else(|| {
+            current
+                .downcast_ref::<std::io::Error>()
+                .and_then(std::io::Error::get_ref)
+                .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+        });
+        if let Some(tls) = tls {
+            return Some(Error::Tls(tls.to_string()));
+        }
+        source = current.source();
+    }
+    None
+}
+
+// Join message of error along with all of its sources
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "reqwest-blocking-client",
+    feature = "reqwest-client",
+    feature = "ureq-client"
+))]
+fn error_chain(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(current) = source {
+        message.push_str(": ");
+        message.push_str(&current.to_string());
+        source = current.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HttpClient, HttpResponse};
+    #[cfg(any(feature = "reqwest-client", feature = "ureq-client"))]
+    use crate::api::ApiClient;
+    use crate::error::{Error, Result};
+    use crate::test_utils::FixtureClient;
+
+    // Client which implements only required method of trait
+    struct Text(&'static str);
+
+    #[async_trait::async_trait]
+    impl HttpClient for Text {
+        async fn get(&self, _: &str, _: &[(&str, &str)]) -> Result<HttpResponse> {
+            Ok(HttpResponse {
+                status: 200,
+                body: self.0.to_string(),
+            })
+        }
+    }
+
+    // Base URL of local server which checks that request contains every
+    // expected line and responds with 202 status
+    #[cfg(feature = "reqwest-client")]
+    fn serve(expected: &'static [&'static str]) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let read = stream.read(&mut request).unwrap();
+            let request = String::from_utf8_lossy(&request[..read]).to_lowercase();
+            for line in expected {
+                assert!(request.contains(line), "{request}");
+            }
+            write!(
+                stream,
+                "HTTP/1.1 202 Accepted\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{{}}"
+            )
+            .unwrap();
+        });
+        format!("http://{address}/")
+    }
+
+    // Base URL of local port which refuses connection
+    #[cfg(any(feature = "reqwest-client", feature = "ureq-client"))]
+    fn refused_url() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        drop(listener);
+        base_url
+    }
+
+    #[cfg(feature = "reqwest-client")]
+    #[tokio::test]
+    async fn reqwest_connect_error_hides_api_key() {
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let api = ApiClient::set_api("secretkey", client)
+            .with_base_url(refused_url())
+            .unwrap();
+        let error = api.quote("MSFT").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::Connect(_)));
+        assert!(!format!("{error:?}").contains("secretkey"));
+        assert!(!error.to_string().contains("secretkey"));
+    }
+
+    #[cfg(feature = "ureq-client")]
+    #[tokio::test]
+    async fn ureq_connect_error_hides_api_key() {
+        let agent = ureq::AgentBuilder::new().build();
+        let api = ApiClient::set_api("secretkey", agent)
+            .with_base_url(refused_url())
+            .unwrap();
+        let error = api.quote("MSFT").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::Connect(_)));
+        assert!(!format!("{error:?}").contains("secretkey"));
+        assert!(!error.to_string().contains("secretkey"));
+    }
+
+    #[tokio::test]
+    async fn status_decides_error() {
+        let error_json = r#"{"Error Message": "Invalid API call."}"#;
+        let api = crate::set_api("demo", FixtureClient::status(200, error_json));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::AlphaVantageErrorMessage(message) if message == "Invalid API call."
+        ));
+
+        let api = crate::set_api("demo", FixtureClient::status(429, error_json));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::HttpStatus { code: 429, .. }));
+
+        let html = "<html><body>503 Service Unavailable</body></html>";
+        let api = crate::set_api("demo", FixtureClient::status(503, html));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::HttpStatus { code: 503, body_snippet } if body_snippet == html
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_bytes_defaults_to_get() {
+        let response = Text("{}")
+            .get_bytes("https://www.alphavantage.co/query", &[])
             .await
-            .map_err(|_| Error::GetRequestFailed)
+            .unwrap();
+        assert!(response.is_success());
+        assert_eq!(response.body, b"{}");
+    }
+
+    #[tokio::test]
+    async fn rapid_api_headers_are_sent() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_rapid_api("demo", client.clone());
+        api.quote("MSFT").json().await.unwrap();
+        assert_eq!(
+            client.requests()[0].headers,
+            [
+                (
+                    "x-rapidapi-host".to_string(),
+                    "alpha-vantage.p.rapidapi.com".to_string()
+                ),
+                ("x-rapidapi-key".to_string(), "demo".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "reqwest-client")]
+    #[tokio::test]
+    async fn reqwest_sends_headers() {
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let base_url = serve(&[
+            "get /query?function=global_quote ",
+            "x-custom: first",
+            "x-trace-id: 42",
+        ]);
+        let response = HttpClient::get(
+            &client,
+            &format!("{base_url}query?function=GLOBAL_QUOTE"),
+            &[("x-custom", "first"), ("x-trace-id", "42")],
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.body, "{}");
     }
 }
@@ -13,9 +13,10 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::api::ApiClient;
+use crate::api::{encode_query_value, ApiClient, Provider};
 use crate::deserialize::from_str;
 use crate::error::{detect_common_helper_error, Error, Result};
+use crate::exchange::CurrencyCode;
 use crate::vec_trait::FindData;
 
 /// Store Meta Data Information
@@ -274,7 +275,7 @@ impl CryptoHelper {
                     .expect("failed to get value from crypto hashmap");
 
                 let data = Data {
-                    time: key.to_string(),
+                    time: key.clone(),
                     open: data_helper.open,
                     high: data_helper.high,
                     low: data_helper.low,
@@ -329,30 +330,47 @@ impl FindData for Vec<Data> {
 /// Builder to help create `Crypto`
 pub struct CryptoBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     function: CryptoFunction,
-    symbol: &'a str,
-    market: &'a str,
+    symbol: CurrencyCode,
+    market: CurrencyCode,
 }
 
 impl<'a> CryptoBuilder<'a> {
-    crate::json_data_struct!(Crypto, CryptoHelper);
+    crate::json_data_struct!(Crypto, CryptoHelper, validate = validate);
 
     /// Create new `CryptoBuilder` with help of `APIClient`
     #[must_use]
     pub fn new(
         api_client: &'a ApiClient,
         function: CryptoFunction,
-        symbol: &'a str,
-        market: &'a str,
+        symbol: impl Into<CurrencyCode>,
+        market: impl Into<CurrencyCode>,
     ) -> Self {
         Self {
             api_client,
+            provider: None,
             function,
-            symbol,
-            market,
+            symbol: symbol.into(),
+            market: market.into(),
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.symbol.validate()?;
+        self.market.validate()
+    }
+
     fn create_url(&self) -> String {
         let function_name = match self.function {
             CryptoFunction::Daily => "DIGITAL_CURRENCY_DAILY",
@@ -362,7 +380,8 @@ impl<'a> CryptoBuilder<'a> {
 
         format!(
             "query?function={function_name}&symbol={}&market={}",
-            &self.symbol, &self.market
+            encode_query_value(self.symbol.as_str()),
+            encode_query_value(self.market.as_str())
         )
     }
 }
@@ -1,12 +1,15 @@
 use std::collections::HashMap;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 use serde::de::value::MapDeserializer;
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_json::Value;
 
-use crate::api::ApiClient;
-use crate::error::{detect_common_helper_error, Error, Result};
+use crate::api::{encode_query_value, format_float, parse_csv_rows, ApiClient, Provider};
+use crate::error::{body_snippet, detect_common_helper_error, Error, Result};
+
 /// struct used for helping creation of custom url
 #[derive(Debug, Deserialize)]
 pub(crate) struct CustomHelper {
@@ -21,22 +24,158 @@ pub(crate) struct CustomHelper {
 }
 
 impl CustomHelper {
-    fn convert<T>(self) -> Result<T>
+    fn convert<T>(self, body: &str, api_key: &str) -> Result<T>
     where
         T: DeserializeOwned,
     {
         detect_common_helper_error(self.information, self.error_message, self.note)?;
         let data = self.extras;
-        T::deserialize(MapDeserializer::new(data.into_iter()))
-            .map_err(|_| Error::DecodeJsonToStruct)
+        let deserializer = MapDeserializer::<_, serde_json::Error>::new(data.into_iter());
+        serde_path_to_error::deserialize(deserializer).map_err(|error| {
+            let path = error.path().to_string();
+            decode_error(path, error.into_inner(), body, api_key)
+        })
+    }
+}
+
+// Create decode error with snippet of body whose API key is redacted
+fn decode_error(path: String, source: serde_json::Error, body: &str, api_key: &str) -> Error {
+    let snippet = body_snippet(body, api_key);
+    Error::DecodeJson {
+        path,
+        line: source.line(),
+        column: source.column(),
+        snippet,
+        source,
+    }
+}
+
+/// Enum for declaring value of parameter used in custom url
+///
+/// ```
+/// use alpha_vantage::custom::ParamValue;
+///
+/// assert_eq!(ParamValue::from("IBM").to_string(), "IBM");
+/// assert_eq!(ParamValue::from(14).to_string(), "14");
+/// assert_eq!(ParamValue::from(0.1 + 0.2).to_string(), "0.3");
+/// assert_eq!(ParamValue::from(2.0).to_string(), "2");
+/// assert_eq!(ParamValue::from(true).to_string(), "true");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    /// string value
+    Str(String),
+    /// integer value
+    Int(i64),
+    /// float value which is formatted with at most 10 decimal places
+    Float(f64),
+    /// boolean value which is formatted as `true` or `false`
+    Bool(bool),
+}
+
+impl std::fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Str(value) => f.write_str(value),
+            ParamValue::Int(value) => write!(f, "{value}"),
+            ParamValue::Float(value) => f.write_str(&format_float(*value)),
+            ParamValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Str(value.to_string())
+    }
+}
+
+impl From<&String> for ParamValue {
+    fn from(value: &String) -> Self {
+        ParamValue::Str(value.clone())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::Str(value)
+    }
+}
+
+impl From<i32> for ParamValue {
+    fn from(value: i32) -> Self {
+        ParamValue::Int(value.into())
+    }
+}
+
+impl From<i64> for ParamValue {
+    fn from(value: i64) -> Self {
+        ParamValue::Int(value)
+    }
+}
+
+impl From<u32> for ParamValue {
+    fn from(value: u32) -> Self {
+        ParamValue::Int(value.into())
+    }
+}
+
+impl From<f64> for ParamValue {
+    fn from(value: f64) -> Self {
+        ParamValue::Float(value)
+    }
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+/// Enum for declaring data type of output returned by custom function
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataType {
+    /// JSON output
+    Json,
+    /// CSV output
+    Csv,
+}
+
+impl DataType {
+    fn as_str(self) -> &'static str {
+        match self {
+            DataType::Json => "json",
+            DataType::Csv => "csv",
+        }
     }
 }
 
+/// Error returned by [`CustomBuilder::paged`] which contains data of pages
+/// fetched before request of page failed
+#[derive(Debug, thiserror::Error)]
+#[error("page {value} failed: {source}")]
+pub struct PagedError<T> {
+    /// value of page parameter whose request failed
+    pub value: String,
+    /// data of pages fetched before failure in order of values
+    pub fetched: Vec<T>,
+    /// error raised by request of page
+    #[source]
+    pub source: Error,
+}
+
 /// Builder to create new Custom Struct
 pub struct CustomBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     function: &'a str,
-    extras: Vec<(&'a str, &'a str)>,
+    extras: Vec<(&'a str, ParamValue)>,
+    datatype: Option<DataType>,
+    headers: Vec<(&'a str, String)>,
+    #[cfg(feature = "tokio")]
+    page_delay: Option<Duration>,
+    strict: bool,
+    duplicate: Option<&'a str>,
 }
 
 impl<'a> CustomBuilder<'a> {
@@ -45,37 +184,885 @@ impl<'a> CustomBuilder<'a> {
     pub fn new(api_client: &'a ApiClient, function: &'a str) -> Self {
         Self {
             api_client,
+            provider: None,
             function,
             extras: vec![],
+            datatype: None,
+            headers: vec![],
+            #[cfg(feature = "tokio")]
+            page_delay: None,
+            strict: false,
+            duplicate: None,
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    pub fn provider(&mut self, provider: Provider) -> &mut Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Create new `CustomBuilder` from query string or full url. Values of
+    /// parameters are kept as written in query and `apikey` parameter is
+    /// dropped
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Quote {
+    ///     #[serde(rename = "Global Quote")]
+    ///     global_quote: std::collections::HashMap<String, String>,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let url = "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&apikey=demo";
+    ///     let mut builder = api.custom_from_query(url).unwrap();
+    ///     builder.extra_params("symbol", "MSFT");
+    ///     let quote: Quote = builder.json().await.unwrap();
+    ///     println!("{:?}", quote.global_quote);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if query contains fragment which is not `key=value` pair
+    /// with non empty key or does not contain `function` parameter
+    pub fn from_query(api_client: &'a ApiClient, query: &'a str) -> Result<Self> {
+        let query = query.split_once('?').map_or(query, |(_, query)| query);
+        let mut function = None;
+        let mut extras = vec![];
+        for fragment in query.split('&').filter(|fragment| !fragment.is_empty()) {
+            let Some((key, value)) = fragment.split_once('=') else {
+                return Err(Error::MalformedQuery(fragment.to_string()));
+            };
+            if key.is_empty() {
+                return Err(Error::MalformedQuery(fragment.to_string()));
+            }
+            match key {
+                "function" => function = Some(value),
+                "apikey" => {}
+                _ => extras.push((key, value)),
+            }
+        }
+        let function = function.ok_or_else(|| Error::MissingParameter {
+            parameter: "function".to_string(),
+            function: "custom query".to_string(),
+        })?;
+        let mut builder = Self::new(api_client, function);
+        for (key, value) in extras {
+            builder.extra_params(key, value);
         }
+        Ok(builder)
+    }
+
+    /// Add extra parameter to url. Parameters are added to url in order of
+    /// insertion and value of parameter which is already added is
+    /// overwritten in place unless builder is in strict mode
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct News {
+    ///     items: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let mut builder = api.custom("NEWS_SENTIMENT");
+    ///     builder
+    ///         .extra_params("tickers", "AAPL")
+    ///         .extra_params("limit", 50)
+    ///         .extra_params("sort", false);
+    ///     let news: News = builder.json().await.unwrap();
+    ///     println!("{}", news.items);
+    /// }
+    /// ```
+    pub fn extra_params(&mut self, key: &'a str, value: impl Into<ParamValue>) -> &mut Self {
+        let value = value.into();
+        if let Some((_, existing)) = self.extras.iter_mut().find(|(name, _)| *name == key) {
+            if self.strict {
+                self.duplicate.get_or_insert(key);
+            } else {
+                log::debug!("parameter {key} is overwritten from {existing} to {value}");
+                *existing = value;
+            }
+        } else {
+            self.extras.push((key, value));
+        }
+        self
     }
 
-    /// Add extra parameter to url
-    pub fn extra_params(&mut self, key: &'a str, value: &'a str) -> &mut Self {
-        self.extras.push((key, value));
+    /// Set strict mode. In strict mode parameter which is set more than once
+    /// raise error when request is sent instead of overwriting previous value
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
         self
     }
 
+    /// Define data type of output which is appended to url as `datatype`
+    /// parameter. If data type is not set, default data type of client is
+    /// used. `datatype` set through [`extra_params`](Self::extra_params)
+    /// takes precedence and is sent only once
+    pub fn datatype(&mut self, datatype: DataType) -> &mut Self {
+        self.datatype = Some(datatype);
+        self
+    }
+
+    /// Add extra header which is sent along with request. Headers are
+    /// accumulated so header added more than once is sent multiple times.
+    /// Headers used by `RapidAPI` provider are never overwritten
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Quote {
+    ///     #[serde(rename = "Global Quote")]
+    ///     global_quote: std::collections::HashMap<String, String>,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_rapid_api("rapid_key", reqwest::Client::new());
+    ///     let quote: Quote = api
+    ///         .custom("GLOBAL_QUOTE")
+    ///         .extra_params("symbol", "MSFT")
+    ///         .header("x-gateway-token", "token")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", quote.global_quote);
+    /// }
+    /// ```
+    pub fn header(&mut self, name: &'a str, value: impl Into<String>) -> &mut Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Set delay between requests of pages sent by [`paged`](Self::paged) to
+    /// stay under rate limit of API. Delay is available when `tokio` is
+    /// enabled directly or by feature such as `stream` or `rate-limit`
+    #[cfg(feature = "tokio")]
+    pub fn page_delay(&mut self, delay: Duration) -> &mut Self {
+        self.page_delay = Some(delay);
+        self
+    }
+
+    /// Return parameters in order of insertion
+    #[must_use]
+    pub fn params(&self) -> &[(&'a str, ParamValue)] {
+        &self.extras
+    }
+
+    // Data type of builder, or default data type of client if neither data
+    // type nor `datatype` parameter is set
+    // Data type of output. `datatype` set as extra param takes precedence
+    // over data type of builder and client
+    fn effective_datatype(&self) -> Option<DataType> {
+        if let Some((_, value)) = self.extras.iter().find(|(key, _)| *key == "datatype") {
+            let value = value.to_string();
+            return [DataType::Json, DataType::Csv]
+                .into_iter()
+                .find(|datatype| value.trim().eq_ignore_ascii_case(datatype.as_str()));
+        }
+        self.datatype.or(self.api_client.defaults().datatype)
+    }
+
+    fn header_pairs(&self) -> Vec<(&str, &str)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect()
+    }
+
     fn create_url(&self) -> String {
-        let mut path = format!("query?function={}", self.function);
+        self.create_page_url(None)
+    }
+
+    // Create url where value of page parameter replaces value of parameter
+    // with same key or is appended if parameter is not present
+    fn create_page_url(&self, page: Option<(&str, &str)>) -> String {
+        let mut path = format!("query?function={}", encode_query_value(self.function));
         for (key, value) in &self.extras {
-            path.push_str(format!("&{key}={value}").as_str());
+            let value = match page {
+                Some((page_key, page_value)) if page_key == *key => page_value.to_string(),
+                _ => value.to_string(),
+            };
+            path.push_str(format!("&{key}={}", encode_query_value(&value)).as_str());
+        }
+        if let Some((page_key, page_value)) = page {
+            if !self.extras.iter().any(|(key, _)| *key == page_key) {
+                path.push_str(format!("&{page_key}={}", encode_query_value(page_value)).as_str());
+            }
+        }
+        if !self.extras.iter().any(|(key, _)| *key == "datatype") {
+            if let Some(datatype) = self.effective_datatype() {
+                path.push_str(format!("&datatype={}", datatype.as_str()).as_str());
+            }
         }
 
         path
     }
 
-    /// Returns JSON data struct
+    /// Returns raw body of response without parsing it, which can be used for
+    /// function returning CSV or plain text. Error returned by API as JSON
+    /// is still converted to error
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let csv = api
+    ///         .custom("LISTING_STATUS")
+    ///         .extra_params("date", "2024-02-16")
+    ///         .text()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", csv.lines().count());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if request fails or API returns any 4 possible known
+    /// errors. Also raise error without request if parameter is set more than
+    /// once in strict mode
+    pub async fn text(&self) -> Result<String> {
+        if let Some(key) = self.duplicate {
+            return Err(Error::DuplicateParameter(key.to_string()));
+        }
+        let url = self.create_url();
+        let api_client = self
+            .api_client
+            .with_provider_override(self.provider.as_ref())?;
+        api_client
+            .in_request(&url, async {
+                let text = api_client
+                    .get_text_with_headers(&url, &self.header_pairs())
+                    .await?;
+                detect_envelope_error(&text)?;
+                Ok(text)
+            })
+            .await
+    }
+
+    /// Returns raw bytes of response without decoding it as text, which can
+    /// be used for function returning compressed or binary output. Client
+    /// needs to implement bytes output of [`HttpClient`] otherwise body
+    /// decoded as text by client is returned. Error returned by API as JSON
+    /// is still converted to error
+    ///
+    /// [`HttpClient`]: crate::client::HttpClient
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let bytes = api.custom("LISTING_STATUS").bytes().await.unwrap();
+    ///     std::fs::write("listing_status.csv", bytes).unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if request fails, server responds with non 2xx status code
+    /// or API returns any 4 possible known errors. Also raise error without
+    /// request if parameter is set more than once in strict mode
+    pub async fn bytes(&self) -> Result<Vec<u8>> {
+        if let Some(key) = self.duplicate {
+            return Err(Error::DuplicateParameter(key.to_string()));
+        }
+        let url = self.create_url();
+        let api_client = self
+            .api_client
+            .with_provider_override(self.provider.as_ref())?;
+        api_client
+            .in_request(&url, async {
+                let bytes = api_client.get_bytes(&url, &self.header_pairs()).await?;
+                if let Ok(text) = std::str::from_utf8(&bytes) {
+                    detect_envelope_error(text)?;
+                }
+                Ok(bytes)
+            })
+            .await
+    }
+
+    /// Returns JSON data struct. If data cannot be converted to struct,
+    /// returned error contains path of failed field, position and snippet of
+    /// body
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Quote {
+    ///     #[serde(rename = "Global Quote")]
+    ///     global_quote: Price,
+    /// }
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Price {
+    ///     #[serde(rename = "05. price")]
+    ///     price: f64,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let result = api
+    ///         .custom("GLOBAL_QUOTE")
+    ///         .extra_params("symbol", "MSFT")
+    ///         .json::<Quote>()
+    ///         .await;
+    ///     match result {
+    ///         Ok(quote) => println!("{}", quote.global_quote.price),
+    ///         // error contains path of field such as `Global Quote.05. price`
+    ///         Err(error) => println!("{error}"),
+    ///     }
+    /// }
+    /// ```
     ///
     /// # Errors
     /// Raise error if data obtained cannot be properly converted to struct or
-    /// API returns any 4 possible known errors
+    /// API returns any 4 possible known errors. Also raise error without
+    /// request if parameter is set more than once in strict mode
     pub async fn json<T>(&self) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let url = self.create_url();
-        let custom_helper: CustomHelper = self.api_client.get_json(&url).await?;
-        custom_helper.convert()
+        self.json_with_text().await.map(|(data, _)| data)
+    }
+
+    /// Returns JSON data struct along with raw body from which struct is
+    /// decoded. Body is returned exactly as received from client without any
+    /// normalization, so it can be saved and decoded again later
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Quote {
+    ///     #[serde(rename = "Global Quote")]
+    ///     global_quote: std::collections::HashMap<String, String>,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let (quote, text) = api
+    ///         .custom("GLOBAL_QUOTE")
+    ///         .extra_params("symbol", "MSFT")
+    ///         .json_with_text::<Quote>()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", quote.global_quote);
+    ///     std::fs::write("global_quote.json", text).unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors. Also raise error without
+    /// request if parameter is set more than once in strict mode
+    pub async fn json_with_text<T>(&self) -> Result<(T, String)>
+    where
+        T: DeserializeOwned,
+    {
+        self.fetch_json(&self.create_url()).await
+    }
+
+    /// Returns JSON data struct of each page where one request is sent per
+    /// value of page parameter sequentially. Value of page parameter replaces
+    /// value set with [`extra_params`](Self::extra_params) for same key. If
+    /// request of any page fails, returned error contains data of pages
+    /// fetched before failure
+    ///
+    /// ```no_run
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Quote {
+    ///     #[serde(rename = "Global Quote")]
+    ///     global_quote: std::collections::HashMap<String, String>,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let symbols = ["MSFT", "IBM", "AAPL"].map(String::from);
+    ///     match api.custom("GLOBAL_QUOTE").paged::<Quote>("symbol", symbols).await {
+    ///         Ok(quotes) => println!("{}", quotes.len()),
+    ///         Err(error) => println!("{} failed after {}", error.value, error.fetched.len()),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error containing value of failed page and data of pages fetched
+    /// before it if request of page fails, data of page cannot be converted
+    /// to struct or API returns any 4 possible known errors
+    pub async fn paged<T>(
+        &self,
+        param: &str,
+        values: impl IntoIterator<Item = String>,
+    ) -> std::result::Result<Vec<T>, PagedError<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut fetched = vec![];
+        for value in values {
+            #[cfg(feature = "tokio")]
+            if let Some(delay) = self.page_delay.filter(|_| !fetched.is_empty()) {
+                tokio::time::sleep(delay).await;
+            }
+            let url = self.create_page_url(Some((param, &value)));
+            match self.fetch_json(&url).await {
+                Ok((data, _)) => fetched.push(data),
+                Err(source) => {
+                    return Err(PagedError {
+                        value,
+                        fetched,
+                        source,
+                    });
+                }
+            }
+        }
+        Ok(fetched)
+    }
+
+    async fn fetch_json<T>(&self, url: &str) -> Result<(T, String)>
+    where
+        T: DeserializeOwned,
+    {
+        if let Some(key) = self.duplicate {
+            return Err(Error::DuplicateParameter(key.to_string()));
+        }
+        let api_client = self
+            .api_client
+            .with_provider_override(self.provider.as_ref())?;
+        api_client
+            .in_request(url, async {
+                let text = api_client
+                    .get_text_with_headers(url, &self.header_pairs())
+                    .await?;
+                let api_key = self.api_client.get_api_key();
+                let custom_helper: CustomHelper = serde_json::from_str(&text)
+                    .map_err(|error| decode_error(String::from("."), error, &text, api_key))?;
+                let data = custom_helper.convert(&text, api_key)?;
+                Ok((data, text))
+            })
+            .await
+    }
+
+    /// Returns records of output according to data type. CSV output is
+    /// deserialized row by row using header of CSV, while JSON output is
+    /// decoded same as [`json`](Self::json) into single record, so `T` is
+    /// type of whole JSON body and returned `Vec` always holds one record
+    ///
+    /// ```no_run
+    /// use alpha_vantage::custom::DataType;
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Listing {
+    ///     symbol: String,
+    ///     name: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let listings: Vec<Listing> = api
+    ///         .custom("LISTING_STATUS")
+    ///         .datatype(DataType::Csv)
+    ///         .records()
+    ///         .await
+    ///         .unwrap();
+    ///     for listing in listings {
+    ///         println!("{} {}", listing.symbol, listing.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors. Also raise error without
+    /// request if parameter is set more than once in strict mode
+    pub async fn records<T>(&self) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match self.effective_datatype() {
+            Some(DataType::Csv) => {
+                if let Some(key) = self.duplicate {
+                    return Err(Error::DuplicateParameter(key.to_string()));
+                }
+                let url = self.create_url();
+                let api_client = self
+                    .api_client
+                    .with_provider_override(self.provider.as_ref())?;
+                api_client
+                    .in_request(&url, async {
+                        let text = api_client
+                            .get_csv_with_headers(&url, &self.header_pairs())
+                            .await?;
+                        parse_csv_rows(&text)
+                    })
+                    .await
+            }
+            Some(DataType::Json) | None => Ok(vec![self.json().await?]),
+        }
+    }
+}
+
+// Detect error returned by API as JSON in output which is not parsed as JSON
+fn detect_envelope_error(text: &str) -> Result<()> {
+    if text.trim_start().starts_with('{') {
+        if let Ok(helper) = serde_json::from_str::<CustomHelper>(text) {
+            detect_common_helper_error(helper.information, helper.error_message, helper.note)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{DataType, ParamValue};
+    use crate::error::Error;
+    use crate::test_utils::{FixtureClient, fixture};
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Quote {
+        #[serde(rename = "Global Quote")]
+        global_quote: HashMap<String, String>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Listing {
+        symbol: String,
+        name: String,
+        exchange: String,
+        #[serde(rename = "assetType")]
+        asset_type: String,
+    }
+
+    #[tokio::test]
+    async fn from_query() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("my_key", client.clone());
+        let url = "https://www.alphavantage.co/query?symbol=IBM&apikey=demo&function=GLOBAL_QUOTE";
+        let mut builder = api.custom_from_query(url).unwrap();
+        let mut params = builder.params().to_vec();
+        params.sort_by_key(|(key, _)| *key);
+        assert_eq!(params, [("symbol", ParamValue::from("IBM"))]);
+
+        builder
+            .extra_params("symbol", "MSFT")
+            .extra_params("datatype", "json");
+        let quote: Quote = builder.json().await.unwrap();
+        assert_eq!(quote.global_quote["01. symbol"], "MSFT");
+        // api key of query is replaced by api key of client
+        let url = &client.urls()[0];
+        assert!(url.contains("function=GLOBAL_QUOTE&"));
+        assert!(url.contains("&symbol=MSFT&"));
+        assert!(url.contains("&datatype=json&"));
+        assert_eq!(url.matches("apikey=").count(), 1);
+        assert!(url.ends_with("&apikey=my_key"));
+
+        let error = api
+            .custom_from_query("function=GLOBAL_QUOTE&symbol")
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::MalformedQuery(fragment) if fragment == "symbol"));
+
+        let error = api.custom_from_query("?symbol=IBM").err().unwrap();
+        assert!(matches!(error, Error::MissingParameter { .. }));
+    }
+
+    #[tokio::test]
+    async fn extra_params_replace_value() {
+        #[derive(serde::Deserialize)]
+        struct News {
+            items: String,
+        }
+
+        let client = FixtureClient::status(200, r#"{"items": "50"}"#);
+        let api = crate::set_api("demo", client.clone());
+        let mut builder = api.custom("NEWS_SENTIMENT");
+        builder
+            .extra_params("tickers", "AAPL")
+            .extra_params("limit", 10)
+            .extra_params("relevance", 0.1 + 0.25)
+            .extra_params("sort", false)
+            .extra_params("limit", 50);
+        assert_eq!(
+            builder.params(),
+            [
+                ("tickers", ParamValue::Str("AAPL".to_string())),
+                ("limit", ParamValue::Int(50)),
+                ("relevance", ParamValue::Float(0.1 + 0.25)),
+                ("sort", ParamValue::Bool(false)),
+            ]
+        );
+        let news: News = builder.json().await.unwrap();
+        assert_eq!(news.items, "50");
+        assert!(client.urls()[0].contains(
+            "query?function=NEWS_SENTIMENT&tickers=AAPL&limit=50&relevance=0.35&sort=false&apikey="
+        ));
+
+        // duplicated parameter is rejected before request in strict mode
+        let error = api
+            .custom("NEWS_SENTIMENT")
+            .strict(true)
+            .extra_params("limit", 10)
+            .extra_params("limit", 50)
+            .json::<News>()
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error, Error::DuplicateParameter(key) if key == "limit"));
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn header_is_merged_with_provider_headers() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_rapid_api("rapid_key", client.clone());
+        let quote: Quote = api
+            .custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "MSFT")
+            .header("x-gateway-token", "token")
+            .header("X-RapidAPI-Key", "other_key")
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(quote.global_quote["01. symbol"], "MSFT");
+
+        let request = &client.requests()[0];
+        assert!(
+            request
+                .url
+                .contains("query?function=GLOBAL_QUOTE&symbol=MSFT")
+        );
+        // provider header is not overridden by custom header
+        assert_eq!(
+            request.headers,
+            [
+                ("x-gateway-token", "token"),
+                ("x-rapidapi-host", "alpha-vantage.p.rapidapi.com"),
+                ("x-rapidapi-key", "rapid_key"),
+            ]
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn text_output() {
+        let client = FixtureClient::new("error/invalid_call.json")
+            .route(
+                "function=LISTING_STATUS&date=2024-02-16&apikey=",
+                "custom/listing_status.csv",
+            )
+            .route("function=LISTING_STATUS&", "error/rate_limit.json");
+        let api = crate::set_api("demo", client);
+        let csv = api
+            .custom("LISTING_STATUS")
+            .extra_params("date", "2024-02-16")
+            .text()
+            .await
+            .unwrap();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("symbol,name,exchange"));
+        assert_eq!(lines.count(), 3);
+
+        let error = api
+            .custom("LISTING_STATUS")
+            .extra_params("date", "2024-02-17")
+            .text()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageInformation(_)));
+
+        let error = api.custom("LISTING").text().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageErrorMessage(_)));
+    }
+
+    #[tokio::test]
+    async fn bytes_output() {
+        let client = FixtureClient::new("error/invalid_call.json")
+            .route("function=LISTING_STATUS&", "custom/listing_status.csv.gz");
+        let api = crate::set_api("demo", client.clone());
+        let bytes = api
+            .custom("LISTING_STATUS")
+            .header("x-gateway-token", "token")
+            .bytes()
+            .await
+            .unwrap();
+        assert_eq!(bytes, fixture("custom/listing_status.csv.gz"));
+        assert_eq!(bytes[..2], [0x1f, 0x8b]);
+        assert_eq!(
+            client.requests()[0].headers,
+            [("x-gateway-token".to_string(), "token".to_string())]
+        );
+
+        let error = api.custom("LISTING").bytes().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageErrorMessage(_)));
+
+        let api = crate::set_api("demo", FixtureClient::status(503, "Service Unavailable"));
+        let error = api.custom("LISTING_STATUS").bytes().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::HttpStatus { code: 503, .. }));
+    }
+
+    #[tokio::test]
+    async fn decode_error_has_path_and_redacted_snippet() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Quote {
+            #[serde(rename = "Global Quote")]
+            _global_quote: Price,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Price {
+            #[serde(rename = "05. price")]
+            _price: f64,
+        }
+
+        let client = FixtureClient::status(200, r#"{"url": "query?apikey=secret_key",}"#)
+            .route("function=GLOBAL_QUOTE&", "quote/global_quote.json");
+        let api = crate::set_api("secret_key", client);
+        let error = api
+            .custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "MSFT")
+            .json::<Quote>()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::DecodeJson { path, .. } if path == "Global Quote.05. price"
+        ));
+        assert!(error.to_string().contains("Global Quote.05. price"));
+
+        let error = api.custom("BROKEN").json::<Quote>().await.unwrap_err();
+        let Error::DecodeJson { line, snippet, .. } = error.inner() else {
+            panic!("unexpected error {error}");
+        };
+        assert_eq!(*line, 1);
+        assert!(snippet.contains("[REDACTED]"));
+        assert!(!error.to_string().contains("secret_key"));
+    }
+
+    #[tokio::test]
+    async fn json_with_text() {
+        let client = FixtureClient::new("error/invalid_call.json")
+            .route("function=GLOBAL_QUOTE&", "quote/global_quote.json");
+        let api = crate::set_api("demo", client);
+        let (quote, text) = api
+            .custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "MSFT")
+            .json_with_text::<Quote>()
+            .await
+            .unwrap();
+        assert_eq!(quote.global_quote["01. symbol"], "MSFT");
+        assert_eq!(text.as_bytes(), fixture("quote/global_quote.json"));
+        // saved text can be parsed again later
+        let parsed: Quote = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, quote);
+
+        let error = api
+            .custom("LISTING")
+            .json_with_text::<Quote>()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageErrorMessage(_)));
+    }
+
+    #[tokio::test]
+    async fn paged_error_keeps_fetched_pages() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route("symbol=INVALID&", "error/invalid_call.json");
+        let api = crate::set_api("demo", client.clone());
+        let symbols = ["MSFT", "IBM", "AAPL"].map(String::from);
+        let quotes = api
+            .custom("GLOBAL_QUOTE")
+            .paged::<Quote>("symbol", symbols)
+            .await
+            .unwrap();
+        assert_eq!(quotes.len(), 3);
+        assert_eq!(client.calls(), 3);
+
+        let symbols = ["MSFT", "INVALID", "AAPL"].map(String::from);
+        let error = api
+            .custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "IBM")
+            .paged::<Quote>("symbol", symbols)
+            .await
+            .unwrap_err();
+        assert_eq!(error.value, "INVALID");
+        assert_eq!(error.fetched.len(), 1);
+        assert_eq!(error.fetched[0].global_quote["01. symbol"], "MSFT");
+        assert!(matches!(
+            error.source.inner(),
+            Error::AlphaVantageErrorMessage(_)
+        ));
+        // page after failure is not requested
+        assert_eq!(client.calls(), 5);
+        assert!(
+            client
+                .urls()
+                .iter()
+                .all(|url| url.contains("function=GLOBAL_QUOTE&symbol="))
+        );
+    }
+
+    #[tokio::test]
+    async fn records_by_datatype() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route(
+                "function=LISTING_STATUS&datatype=csv&apikey=",
+                "custom/listing_status.csv",
+            )
+            .route("function=LISTING_STATUS&", "error/rate_limit.json");
+        let api = crate::set_api("demo", client);
+        let listings: Vec<Listing> = api
+            .custom("LISTING_STATUS")
+            .datatype(DataType::Csv)
+            .records()
+            .await
+            .unwrap();
+        assert_eq!(listings.len(), 3);
+        assert_eq!(listings[0].symbol, "A");
+        assert_eq!(listings[0].name, "Agilent Technologies Inc");
+        assert_eq!(listings[0].exchange, "NYSE");
+        assert_eq!(listings[0].asset_type, "Stock");
+
+        let error = api
+            .custom("LISTING_STATUS")
+            .extra_params("date", "2024-02-17")
+            .datatype(DataType::Csv)
+            .records::<Listing>()
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.inner(), Error::AlphaVantageInformation(_)));
+
+        let quotes: Vec<Quote> = api
+            .custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "MSFT")
+            .datatype(DataType::Json)
+            .records()
+            .await
+            .unwrap();
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[0].global_quote["01. symbol"], "MSFT");
+    }
+
+    #[tokio::test]
+    async fn extra_datatype_is_sent_once() {
+        let client = FixtureClient::new("custom/listing_status.csv");
+        let listings = crate::set_api("demo", client.clone())
+            .custom("LISTING_STATUS")
+            .datatype(DataType::Json)
+            .extra_params("datatype", "csv")
+            .records::<Listing>()
+            .await
+            .unwrap();
+        assert_eq!(listings[0].symbol, "A");
+        let url = &client.urls()[0];
+        assert_eq!(url.matches("datatype=").count(), 1);
+        assert!(url.contains("&datatype=csv"));
     }
 }
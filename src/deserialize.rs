@@ -12,6 +12,7 @@ where
     T::from_str(&s).map_err(Error::custom)
 }
 
+// Deserialize string to Option where "None", "-", "." and empty string is None
 pub(crate) fn from_none_str<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     T: FromStr,
@@ -19,10 +20,11 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?.to_lowercase();
-    if &s == "none" {
+    let s = s.trim();
+    if s.is_empty() || s == "none" || s == "-" || s == "." {
         Ok(None)
     } else {
-        match T::from_str(&s) {
+        match T::from_str(s) {
             Ok(data) => Ok(Some(data)),
             Err(msg) => Err(msg),
         }
@@ -30,11 +32,15 @@ where
     }
 }
 
-pub(crate) fn percent_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+pub(crate) fn percent_none_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let mut s = String::deserialize(deserializer)?;
-    s.pop();
-    f64::from_str(&s).map_err(Error::custom)
+    let s = String::deserialize(deserializer)?;
+    let s = s.trim().trim_end_matches('%');
+    if s.eq_ignore_ascii_case("none") {
+        Ok(None)
+    } else {
+        f64::from_str(s).map(Some).map_err(Error::custom)
+    }
 }
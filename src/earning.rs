@@ -4,9 +4,11 @@
 //! interest. Quarterly data also includes analyst estimates and surprise
 //! metrics.
 
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
 use serde::Deserialize;
 
-use crate::api::ApiClient;
+use crate::api::{encode_query_value, ApiClient, Provider};
 use crate::deserialize::{from_none_str, from_str};
 use crate::error::{detect_common_helper_error, Error, Result};
 
@@ -26,6 +28,15 @@ impl Annual {
         &self.fiscal_date_ending
     }
 
+    /// Return annual earning fiscal date ending parsed as date
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn fiscal_date_ending_parsed(&self) -> Result<NaiveDate> {
+        parse_date(&self.fiscal_date_ending)
+    }
+
     /// Return reported eps for annual earning
     #[must_use]
     pub fn reported_eps(&self) -> f64 {
@@ -38,15 +49,19 @@ impl Annual {
 pub struct Quarterly {
     #[serde(rename = "fiscalDateEnding")]
     fiscal_date_ending: String,
-    #[serde(rename = "reportedDate")]
+    #[serde(rename = "reportedDate", default)]
     reported_date: String,
-    #[serde(rename = "reportedEPS", deserialize_with = "from_none_str")]
+    #[serde(rename = "reportedEPS", deserialize_with = "from_none_str", default)]
     reported_eps: Option<f64>,
-    #[serde(rename = "estimatedEPS", deserialize_with = "from_str")]
-    estimated_eps: f64,
-    #[serde(rename = "surprise", deserialize_with = "from_none_str")]
+    #[serde(rename = "estimatedEPS", deserialize_with = "from_none_str", default)]
+    estimated_eps: Option<f64>,
+    #[serde(rename = "surprise", deserialize_with = "from_none_str", default)]
     surprise: Option<f64>,
-    #[serde(rename = "surprisePercentage", deserialize_with = "from_none_str")]
+    #[serde(
+        rename = "surprisePercentage",
+        deserialize_with = "from_none_str",
+        default
+    )]
     surprise_percentage: Option<f64>,
 }
 
@@ -57,22 +72,73 @@ impl Quarterly {
         &self.fiscal_date_ending
     }
 
-    /// Return reported date for quarterly earning
+    /// Return reported date for quarterly earning. Return empty string if
+    /// reported date is not present
     #[must_use]
     pub fn reported_date(&self) -> &str {
         &self.reported_date
     }
 
+    /// Return fiscal date ending of quarterly earning parsed as date
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn fiscal_date_ending_parsed(&self) -> Result<NaiveDate> {
+        parse_date(&self.fiscal_date_ending)
+    }
+
+    /// Return reported date of quarterly earning parsed as date
+    ///
+    /// # Errors
+    /// Raise error if reported date is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn reported_date_parsed(&self) -> Result<NaiveDate> {
+        parse_date(&self.reported_date)
+    }
+
+    /// Return number of days between fiscal date ending and reported date.
+    /// Return None if either date is missing or cannot be parsed
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     for quarter in earning.quarterly_earning() {
+    ///         println!("{:?}", quarter.reporting_delay_days());
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn reporting_delay_days(&self) -> Option<i64> {
+        let fiscal_date_ending = self.fiscal_date_ending_parsed().ok()?;
+        let reported_date = self.reported_date_parsed().ok()?;
+        Some((reported_date - fiscal_date_ending).num_days())
+    }
+
     /// Return reported eps of symbol for quarter. Return None if api return
     /// none
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     let reported = earning.quarterly_earning()[0].reported_eps();
+    ///     assert!(reported.is_some());
+    /// }
+    /// ```
     #[must_use]
     pub fn reported_eps(&self) -> Option<f64> {
         self.reported_eps
     }
 
-    /// Return Estimated eps of symbol for quarter
+    /// Return Estimated eps of symbol for quarter. Return None if api return
+    /// none
     #[must_use]
-    pub fn estimated_eps(&self) -> f64 {
+    pub fn estimated_eps(&self) -> Option<f64> {
         self.estimated_eps
     }
 
@@ -94,8 +160,8 @@ impl Quarterly {
 #[derive(Debug, Default)]
 pub struct Earning {
     symbol: String,
-    annual_earning: Vec<Annual>,
-    quarterly_earning: Vec<Quarterly>,
+    annual: Vec<Annual>,
+    quarterly: Vec<Quarterly>,
 }
 
 impl Earning {
@@ -119,14 +185,252 @@ impl Earning {
     /// Return Annual earning list for symbol
     #[must_use]
     pub fn annual_earning(&self) -> &Vec<Annual> {
-        &self.annual_earning
+        &self.annual
     }
 
     /// Return quarterly earning for symbol
     #[must_use]
     pub fn quarterly_earning(&self) -> &Vec<Quarterly> {
-        &self.quarterly_earning
+        &self.quarterly
+    }
+
+    /// Return annual earning sorted by fiscal date ending in descending order
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any annual earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn sorted_annual_earning(&self) -> Result<Vec<&Annual>> {
+        sort_by_date_desc(&self.annual, Annual::fiscal_date_ending_parsed)
+    }
+
+    /// Return quarterly earning sorted by fiscal date ending in descending
+    /// order
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     for quarter in earning.sorted_quarterly_earning().unwrap() {
+    ///         println!("{} {:?}", quarter.fiscal_date_ending(), quarter.reported_eps());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any quarterly earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn sorted_quarterly_earning(&self) -> Result<Vec<&Quarterly>> {
+        sort_by_date_desc(&self.quarterly, Quarterly::fiscal_date_ending_parsed)
+    }
+
+    /// Return quarterly earning with latest fiscal date ending. Return None if
+    /// there is no quarterly earning
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any quarterly earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn latest_quarterly_earning(&self) -> Result<Option<&Quarterly>> {
+        Ok(self.sorted_quarterly_earning()?.into_iter().next())
+    }
+
+    /// Return quarterly earning whose fiscal date ending falls in `year`
+    /// sorted by fiscal date ending in descending order. Year is matched on
+    /// year component of fiscal date ending, so for company whose fiscal year
+    /// does not end in December quarters of one fiscal year are split between
+    /// two calendar years
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     let quarters = earning.quarterly_for_year(2023).unwrap();
+    ///     assert!(quarters.len() <= 4);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any quarterly earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn quarterly_for_year(&self, year: i32) -> Result<Vec<&Quarterly>> {
+        let mut quarters = Vec::new();
+        for quarter in self.sorted_quarterly_earning()? {
+            if quarter.fiscal_date_ending_parsed()?.year() == year {
+                quarters.push(quarter);
+            }
+        }
+        Ok(quarters)
+    }
+
+    /// Return `n` quarterly earning with latest fiscal date ending sorted in
+    /// descending order
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any quarterly earning cannot be
+    /// parsed or there are less than `n` quarterly earning
+    #[cfg(feature = "chrono")]
+    pub fn latest_quarters(&self, n: usize) -> Result<Vec<&Quarterly>> {
+        let mut quarters = self.sorted_quarterly_earning()?;
+        if n > quarters.len() {
+            return Err(Error::DesiredNumberOfDataNotPresent(quarters.len()));
+        }
+        quarters.truncate(n);
+        Ok(quarters)
+    }
+
+    /// Return year over year growth in percentage of annual reported EPS for
+    /// each fiscal year sorted by fiscal date ending in descending order.
+    /// Growth is compared with fiscal year ending one year earlier and is
+    /// calculated relative to absolute value of previous EPS. Growth is None
+    /// if there is no previous fiscal year or previous EPS is zero
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     for (date, growth) in earning.annual_eps_growth().unwrap() {
+    ///         println!("{date} {growth:?}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any annual earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn annual_eps_growth(&self) -> Result<Vec<(String, Option<f64>)>> {
+        let annual = self
+            .sorted_annual_earning()?
+            .into_iter()
+            .map(|data| Ok((data.fiscal_date_ending_parsed()?, Some(data.reported_eps))))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(year_over_year_growth(&annual, |date, previous| {
+            date.year() - 1 == previous.year()
+        }))
+    }
+
+    /// Return year over year growth in percentage of quarterly reported EPS
+    /// compared with same quarter a year earlier matched by fiscal month.
+    /// Result is sorted by fiscal date ending in descending order. Growth is
+    /// None if there is no comparable quarter, either EPS is missing or
+    /// previous EPS is zero
+    ///
+    /// # Errors
+    /// Raise error if fiscal date ending of any quarterly earning cannot be
+    /// parsed
+    #[cfg(feature = "chrono")]
+    pub fn quarterly_eps_growth_yoy(&self) -> Result<Vec<(String, Option<f64>)>> {
+        let quarterly = self
+            .sorted_quarterly_earning()?
+            .into_iter()
+            .map(|data| Ok((data.fiscal_date_ending_parsed()?, data.reported_eps)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(year_over_year_growth(&quarterly, |date, previous| {
+            date.year() - 1 == previous.year() && date.month() == previous.month()
+        }))
     }
+
+    /// Return trailing twelve month EPS calculated as sum of reported EPS of
+    /// four quarters with latest fiscal date ending. Return None if there are
+    /// less than four quarters, any of them lacks reported EPS or fiscal date
+    /// ending cannot be parsed
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let earning = api.earning("IBM").json().await.unwrap();
+    ///     println!("{:?}", earning.ttm_eps());
+    /// }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn ttm_eps(&self) -> Option<f64> {
+        ttm_eps(&self.sorted_quarterly_earning().ok()?)
+    }
+
+    /// Return trailing twelve month EPS as it was known on `date`. Only
+    /// quarters reported on or before `date` are used so that result does not
+    /// depend on data published later and quarters without reported date are
+    /// skipped. Return None in same case as [`Earning::ttm_eps`] or if
+    /// reported date cannot be parsed
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn ttm_eps_as_of(&self, date: NaiveDate) -> Option<f64> {
+        let mut reported = Vec::new();
+        for quarter in self.sorted_quarterly_earning().ok()? {
+            // quarter without reported date is not reported yet
+            if quarter.reported_date.trim().is_empty() {
+                continue;
+            }
+            if quarter.reported_date_parsed().ok()? <= date {
+                reported.push(quarter);
+            }
+        }
+        ttm_eps(&reported)
+    }
+}
+
+/// Calculate growth of EPS against EPS of comparable date in percentage
+#[cfg(feature = "chrono")]
+fn year_over_year_growth<F>(
+    data: &[(NaiveDate, Option<f64>)],
+    comparable: F,
+) -> Vec<(String, Option<f64>)>
+where
+    F: Fn(NaiveDate, NaiveDate) -> bool,
+{
+    data.iter()
+        .map(|(date, eps)| {
+            let previous = data
+                .iter()
+                .find(|(previous, _)| comparable(*date, *previous))
+                .and_then(|(_, previous)| *previous)
+                .filter(|previous| *previous != 0.0);
+            let growth = eps
+                .zip(previous)
+                .map(|(eps, previous)| (eps - previous) / previous.abs() * 100.0);
+            (date.format("%Y-%m-%d").to_string(), growth)
+        })
+        .collect()
+}
+
+/// Sum reported EPS of first four quarters
+#[cfg(feature = "chrono")]
+fn ttm_eps(quarters: &[&Quarterly]) -> Option<f64> {
+    if quarters.len() < 4 {
+        return None;
+    }
+    quarters[..4]
+        .iter()
+        .map(|quarter| quarter.reported_eps)
+        .sum()
+}
+
+/// Parse date in `YYYY-MM-DD` format
+#[cfg(feature = "chrono")]
+fn parse_date(date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| Error::ParseDateTime(date.to_string()))
+}
+
+/// Sort data by parsed date in descending order
+#[cfg(feature = "chrono")]
+fn sort_by_date_desc<T, F>(data: &[T], parse: F) -> Result<Vec<&T>>
+where
+    F: Fn(&T) -> Result<NaiveDate>,
+{
+    let mut dated = data
+        .iter()
+        .map(|value| Ok((parse(value)?, value)))
+        .collect::<Result<Vec<_>>>()?;
+    dated.sort_by_key(|(parsed, _)| std::cmp::Reverse(*parsed));
+    Ok(dated.into_iter().map(|(_, value)| value).collect())
 }
 
 /// Struct used for creating earning
@@ -158,8 +462,8 @@ impl EarningHelper {
             return Err(Error::EmptyResponse);
         }
         earning.symbol = self.symbol.unwrap();
-        earning.annual_earning = self.annual_earning.unwrap();
-        earning.quarterly_earning = self.quarterly_earning.unwrap();
+        earning.annual = self.annual_earning.unwrap();
+        earning.quarterly = self.quarterly_earning.unwrap();
         Ok(earning)
     }
 }
@@ -167,6 +471,7 @@ impl EarningHelper {
 /// Builder to help create Earning
 pub struct EarningBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     symbol: &'a str,
 }
 
@@ -176,10 +481,249 @@ impl<'a> EarningBuilder<'a> {
     /// Create new Earning Builder with help of `APIClient`
     #[must_use]
     pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
-        Self { api_client, symbol }
+        Self {
+            api_client,
+            provider: None,
+            symbol,
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
     }
 
     fn create_url(&self) -> String {
-        format!("query?function=EARNINGS&symbol={}", self.symbol)
+        format!(
+            "query?function=EARNINGS&symbol={}",
+            encode_query_value(self.symbol)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::earning::Earning;
+    use crate::test_utils::FixtureClient;
+
+    async fn fetch(path: &str) -> Earning {
+        crate::set_api("demo", FixtureClient::new(path))
+            .earning("IBM")
+            .json()
+            .await
+            .unwrap()
+    }
+
+    #[cfg(feature = "chrono")]
+    fn assert_growth(actual: &[(String, Option<f64>)], expected: &[(&str, Option<f64>)]) {
+        assert_eq!(actual.len(), expected.len());
+        for ((date, growth), (expected_date, expected_growth)) in actual.iter().zip(expected) {
+            assert_eq!(date, expected_date);
+            match (growth, expected_growth) {
+                (Some(growth), Some(expected)) => assert!((growth - expected).abs() < 1e-9),
+                (growth, expected) => assert_eq!(growth.is_some(), expected.is_some()),
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn reporting_delay_days() {
+        let earning = fetch("earning/report_delay.json").await;
+        let quarters = earning.quarterly_earning();
+        // reported on same day as fiscal date ending
+        assert_eq!(quarters[0].reporting_delay_days(), Some(0));
+        // 2023-09-30 reported on 2023-11-07
+        assert_eq!(quarters[1].reporting_delay_days(), Some(38));
+        // reported date is missing
+        assert_eq!(quarters[2].reported_date(), "");
+        assert_eq!(quarters[2].reporting_delay_days(), None);
+    }
+
+    #[tokio::test]
+    async fn missing_eps_values() {
+        let earning = fetch("earning/pre_ipo.json").await;
+        let quarters = earning.quarterly_earning();
+        let values = |index: usize| {
+            let quarter = &quarters[index];
+            [
+                quarter.reported_eps(),
+                quarter.estimated_eps(),
+                quarter.surprise(),
+                quarter.surprise_percentage(),
+            ]
+            .map(|value| value.map(|value| value.to_string()))
+        };
+
+        // reported quarter with zero surprise
+        assert_eq!(
+            values(0),
+            [Some("0.36"), Some("0.36"), Some("0"), Some("0")].map(|value| value.map(String::from))
+        );
+        // pre ipo quarter with "None", "-" and empty values
+        for index in 1..quarters.len() {
+            assert_eq!(values(index), [None, None, None, None]);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn sorted_quarterly_earning() {
+        let earning = fetch("earning/ibm.json").await;
+        let dates = earning
+            .sorted_quarterly_earning()
+            .unwrap()
+            .into_iter()
+            .map(|quarter| quarter.fiscal_date_ending().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dates,
+            [
+                "2023-12-31",
+                "2023-09-30",
+                "2023-06-30",
+                "2023-03-31",
+                "2022-12-31",
+                "2022-09-30"
+            ]
+        );
+        let latest = earning.latest_quarterly_earning().unwrap().unwrap();
+        assert_eq!(
+            latest.reported_date_parsed().unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 24).unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn malformed_date_is_error() {
+        // 2023-09-31 is not valid date
+        let earning = fetch("earning/malformed_date.json").await;
+        assert!(matches!(
+            earning.sorted_quarterly_earning(),
+            Err(crate::error::Error::ParseDateTime(date)) if date == "2023-09-31"
+        ));
+        assert!(earning.latest_quarterly_earning().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn quarters_by_calendar_year() {
+        use crate::earning::Quarterly;
+        use crate::error::Error;
+
+        fn dates(quarters: Vec<&Quarterly>) -> Vec<&str> {
+            quarters
+                .into_iter()
+                .map(Quarterly::fiscal_date_ending)
+                .collect()
+        }
+
+        // fiscal year of company ends in september
+        let earning = fetch("earning/aapl.json").await;
+        assert_eq!(
+            dates(earning.quarterly_for_year(2022).unwrap()),
+            ["2022-12-31", "2022-09-30", "2022-06-30", "2022-03-31"]
+        );
+        assert_eq!(
+            dates(earning.quarterly_for_year(2023).unwrap()),
+            ["2023-03-31"]
+        );
+        assert!(earning.quarterly_for_year(2020).unwrap().is_empty());
+
+        assert_eq!(
+            dates(earning.latest_quarters(2).unwrap()),
+            ["2023-03-31", "2022-12-31"]
+        );
+        assert!(matches!(
+            earning.latest_quarters(8),
+            Err(Error::DesiredNumberOfDataNotPresent(6))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn eps_growth() {
+        let earning = fetch("earning/ibm.json").await;
+        assert_growth(
+            &earning.annual_eps_growth().unwrap(),
+            &[
+                // (9.61 - 9.12) / 9.12
+                ("2023-12-31", Some(0.49 / 9.12 * 100.0)),
+                // (9.12 - 7.93) / 7.93
+                ("2022-12-31", Some(1.19 / 7.93 * 100.0)),
+                ("2021-12-31", None),
+            ],
+        );
+        assert_growth(
+            &earning.quarterly_eps_growth_yoy().unwrap(),
+            &[
+                // (3.87 - 3.6) / 3.6
+                ("2023-12-31", Some(7.5)),
+                // (2.2 - 1.81) / 1.81
+                ("2023-09-30", Some(0.39 / 1.81 * 100.0)),
+                ("2023-06-30", None),
+                ("2023-03-31", None),
+                ("2022-12-31", None),
+                ("2022-09-30", None),
+            ],
+        );
+
+        let earning = fetch("earning/zero_eps.json").await;
+        assert_growth(
+            &earning.annual_eps_growth().unwrap(),
+            &[
+                // previous EPS is zero
+                ("2023-12-31", None),
+                // (0 - -0.2) / 0.2
+                ("2022-12-31", Some(100.0)),
+                ("2021-12-31", None),
+            ],
+        );
+        assert_growth(
+            &earning.quarterly_eps_growth_yoy().unwrap(),
+            &[
+                // current EPS is missing
+                ("2023-06-30", None),
+                // previous EPS is zero
+                ("2023-03-31", None),
+                ("2022-06-30", None),
+                ("2022-03-31", None),
+            ],
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn ttm_eps() {
+        use chrono::NaiveDate;
+
+        let earning = fetch("earning/ibm.json").await;
+        // 3.87 + 2.2 + 2.18 + 1.36
+        assert!((earning.ttm_eps().unwrap() - 9.61).abs() < 1e-9);
+
+        // quarter ending 2023-09-30 was reported on 2023-10-25
+        let date = NaiveDate::from_ymd_opt(2023, 10, 24).unwrap();
+        // 2.18 + 1.36 + 3.6 + 1.81
+        assert!((earning.ttm_eps_as_of(date).unwrap() - 8.95).abs() < 1e-9);
+
+        // only three quarters were reported before 2023-07-19
+        let date = NaiveDate::from_ymd_opt(2023, 7, 18).unwrap();
+        assert!(earning.ttm_eps_as_of(date).is_none());
+
+        // latest four quarter contains pre ipo quarter without EPS
+        let earning = fetch("earning/pre_ipo.json").await;
+        assert!(earning.ttm_eps().is_none());
+
+        // latest quarter without reported date is skipped
+        let earning = fetch("earning/undated.json").await;
+        let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        // 1.0 + 0.9 + 0.8 + 0.7
+        assert!((earning.ttm_eps_as_of(date).unwrap() - 3.4).abs() < 1e-9);
     }
 }
@@ -9,11 +9,14 @@
 //! [economic_indicator]: https://www.alphavantage.co/documentation/#economic-indicators
 
 use std::cmp;
+use std::collections::HashMap;
 
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
 use serde::Deserialize;
 
-use crate::api::ApiClient;
-use crate::deserialize::from_str;
+use crate::api::{ApiClient, Provider};
+use crate::deserialize::from_none_str;
 use crate::error::{detect_common_helper_error, Error, Result};
 use crate::vec_trait::FindData;
 
@@ -21,8 +24,8 @@ use crate::vec_trait::FindData;
 #[derive(Default, Debug, Deserialize, Clone)]
 pub struct Data {
     date: String,
-    #[serde(deserialize_with = "from_str")]
-    value: f64,
+    #[serde(deserialize_with = "from_none_str")]
+    value: Option<f64>,
 }
 
 impl Data {
@@ -32,20 +35,51 @@ impl Data {
         &self.date
     }
 
-    /// Return value for Data
+    /// Return date parsed as date. Date is in `YYYY-MM-DD` format for all
+    /// intervals, quarterly data is dated on first day of quarter and annual
+    /// data is dated on first day of year
+    ///
+    /// # Errors
+    /// Raise error if date is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn date_parsed(&self) -> Result<NaiveDate> {
+        NaiveDate::parse_from_str(&self.date, "%Y-%m-%d")
+            .map_err(|_| Error::ParseDateTime(self.date.clone()))
+    }
+
+    /// Return value for Data. Returns None if there is no observation for
+    /// date which API reports as `.`
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::{
+    ///     EconomicIndicatorFunction, EconomicIndicatorInterval,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let treasury_yield = api
+    ///         .economic_indicator(EconomicIndicatorFunction::TreasuryYield)
+    ///         .interval(EconomicIndicatorInterval::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     for data in treasury_yield.data() {
+    ///         println!("{} {:?}", data.date(), data.value());
+    ///     }
+    /// }
+    /// ```
     #[must_use]
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> Option<f64> {
         self.value
     }
 }
 
 impl FindData for Vec<Data> {
-    #[must_use]
     fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
         self.iter().find(|&data| data.date == time)
     }
 
-    #[must_use]
     fn latest(&self) -> <Self as IntoIterator>::Item {
         let mut latest = &Data::default();
         for data in self {
@@ -107,6 +141,671 @@ impl EconomicIndicator {
     pub fn data(&self) -> &Vec<Data> {
         &self.data
     }
+
+    /// Return data dated between start and end date (both inclusive) in
+    /// ascending order of date. As quarterly and annual data are dated on
+    /// first day of period, period is included only if its first day lies in
+    /// range
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    /// use chrono::NaiveDate;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let real_gdp = api
+    ///         .economic_indicator(EconomicIndicatorFunction::RealGdp)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+    ///     let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+    ///     let data = real_gdp.between(start, end).unwrap();
+    ///     println!("{}", data.len());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if date of any data cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn between(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<&Data>> {
+        let mut dated = Vec::new();
+        for data in &self.data {
+            let date = data.date_parsed()?;
+            if start <= date && date <= end {
+                dated.push((date, data));
+            }
+        }
+        dated.sort_by_key(|(date, _)| *date);
+        Ok(dated.into_iter().map(|(_, data)| data).collect())
+    }
+
+    /// Return date and value of most recent observation on or before date in
+    /// `YYYY-MM-DD` format. Data without observation is skipped and None is
+    /// returned if date is before first observation
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let real_gdp = api
+    ///         .economic_indicator(EconomicIndicatorFunction::RealGdp)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     if let Some((date, value)) = real_gdp.value_on("2022-05-15") {
+    ///         println!("{date} {value}");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn value_on(&self, date: &str) -> Option<(String, f64)> {
+        let date = date.trim();
+        self.observations()
+            .filter(|(observed, _)| *observed <= date)
+            .max_by(|a, b| a.0.cmp(b.0))
+            .map(|(observed, value)| (observed.to_string(), value))
+    }
+
+    /// Return date and value of newest observation. Data without observation
+    /// is skipped
+    #[must_use]
+    pub fn latest(&self) -> Option<(String, f64)> {
+        self.observations()
+            .max_by(|a, b| a.0.cmp(b.0))
+            .map(|(observed, value)| (observed.to_string(), value))
+    }
+
+    // Iterate over date and value of data which has observation
+    fn observations(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.data
+            .iter()
+            .filter_map(|data| Some((data.date.as_str(), data.value?)))
+    }
+
+    // Return observations in ascending order of date so repeated lookups can
+    // use `observation_on`
+    fn sorted_observations(&self) -> Vec<(&str, f64)> {
+        let mut observations = self.observations().collect::<Vec<_>>();
+        observations.sort_by_key(|(date, _)| *date);
+        observations
+    }
+
+    /// Return values deflated by CPI relative to CPI on base date in
+    /// ascending order of date. CPI of each date is joined using most recent
+    /// CPI observation on or before date so series of different frequency can
+    /// be deflated
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let cpi = api
+    ///         .economic_indicator(EconomicIndicatorFunction::Cpi)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let retail_sales = api
+    ///         .economic_indicator(EconomicIndicatorFunction::RetailSales)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     for (date, value) in retail_sales.deflate(&cpi, "2023-01-01").unwrap() {
+    ///         println!("{date} {value}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if CPI has no observation on or before base date or date
+    /// of any value, if base date or date of any value is past period of last
+    /// CPI observation, or if CPI is not positive
+    pub fn deflate(&self, cpi: &EconomicIndicator, base_date: &str) -> Result<Vec<(String, f64)>> {
+        let cpi_observations = cpi.sorted_observations();
+        let covered_until = cpi_observations
+            .last()
+            .map(|(latest, _)| cpi.covered_until(latest));
+        let cpi_on = |date: &str| {
+            let date = date.trim();
+            if covered_until.as_ref().is_some_and(|end| !end.covers(date)) {
+                return Err(Error::NoDataForDate(date.to_string()));
+            }
+            let (_, value) = observation_on(&cpi_observations, date)
+                .ok_or_else(|| Error::NoDataForDate(date.to_string()))?;
+            if value > 0.0 {
+                Ok(value)
+            } else {
+                Err(Error::AlphaVantageInvalidData)
+            }
+        };
+        let base_cpi = cpi_on(base_date)?;
+        self.sorted_observations()
+            .into_iter()
+            .map(|(date, value)| Ok((date.to_string(), value * base_cpi / cpi_on(date)?)))
+            .collect()
+    }
+
+    // Return end of period covered by observation on date. Period length is
+    // taken from interval, observation of unknown interval only covers its date
+    fn covered_until(&self, date: &str) -> CoveredUntil {
+        let months_per_period = match self.interval.trim().to_lowercase().as_str() {
+            "monthly" => 1,
+            "quarterly" => 3,
+            "semiannual" => 6,
+            "annual" | "annually" => 12,
+            _ => return CoveredUntil::Inclusive(date.to_string()),
+        };
+        match month_index(date) {
+            Ok(month) => {
+                let next = month + months_per_period;
+                CoveredUntil::Exclusive(format!(
+                    "{:04}-{:02}-01",
+                    next.div_euclid(12),
+                    next.rem_euclid(12) + 1
+                ))
+            }
+            Err(_) => CoveredUntil::Inclusive(date.to_string()),
+        }
+    }
+
+    /// Return trailing simple moving average of values over window in
+    /// ascending order of date. First `window - 1` points and every point when
+    /// window is zero are None. If any value in window is missing average is
+    /// None, use [`EconomicIndicator::smoothed_with`] with
+    /// [`MissingValues::Skip`] to average available values instead
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let retail_sales = api
+    ///         .economic_indicator(EconomicIndicatorFunction::RetailSales)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     for (date, value) in retail_sales.smoothed(3) {
+    ///         println!("{date} {value:?}");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn smoothed(&self, window: usize) -> Vec<(String, Option<f64>)> {
+        self.smoothed_with(window, MissingValues::Propagate)
+    }
+
+    /// Return trailing simple moving average of values over window handling
+    /// missing values as per policy. See [`EconomicIndicator::smoothed`]
+    #[must_use]
+    pub fn smoothed_with(
+        &self,
+        window: usize,
+        missing_values: MissingValues,
+    ) -> Vec<(String, Option<f64>)> {
+        let mut data = self.data.iter().collect::<Vec<_>>();
+        data.sort_by(|a, b| a.date.cmp(&b.date));
+        (0..data.len())
+            .map(|index| {
+                let average = (window > 0 && index + 1 >= window)
+                    .then(|| average(&data[index + 1 - window..=index], missing_values))
+                    .flatten();
+                (data[index].date.clone(), average)
+            })
+            .collect()
+    }
+
+    /// Return percent change of value versus previous observation in
+    /// ascending order of date. Change is None for first observation, if
+    /// either value is missing or previous value is zero
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let cpi = api
+    ///         .economic_indicator(EconomicIndicatorFunction::Cpi)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     // CPI levels into inflation rate
+    ///     for (date, inflation) in cpi.pct_change_yoy() {
+    ///         println!("{date} {inflation:?}");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn pct_change(&self) -> Vec<(String, Option<f64>)> {
+        let data = self.sorted_data();
+        data.iter()
+            .enumerate()
+            .map(|(index, current)| {
+                let change = index
+                    .checked_sub(1)
+                    .and_then(|previous| percent_change(data[previous].value, current.value));
+                (current.date.clone(), change)
+            })
+            .collect()
+    }
+
+    /// Return percent change of value versus observation one year earlier in
+    /// ascending order of date. Observation dated on same month of previous
+    /// year is used for monthly, quarterly, semiannual or annual interval so
+    /// gap in series does not shift comparison. Change is None for all
+    /// observations of other interval, if comparable observation is not
+    /// present, either value is missing or comparable value is zero. See
+    /// [`EconomicIndicator::pct_change`] for example
+    #[must_use]
+    pub fn pct_change_yoy(&self) -> Vec<(String, Option<f64>)> {
+        let yearly = matches!(
+            self.interval.trim().to_lowercase().as_str(),
+            "monthly" | "quarterly" | "semiannual" | "annual" | "annually"
+        );
+        let by_month = self
+            .data
+            .iter()
+            .filter_map(|data| Some((month_index(&data.date).ok()?, data.value)))
+            .collect::<HashMap<_, _>>();
+        self.sorted_data()
+            .into_iter()
+            .map(|current| {
+                let change = month_index(&current.date)
+                    .ok()
+                    .filter(|_| yearly)
+                    .and_then(|month| by_month.get(&(month - 12)).copied())
+                    .and_then(|previous| percent_change(previous, current.value));
+                (current.date.clone(), change)
+            })
+            .collect()
+    }
+
+    // Data in ascending order of date
+    fn sorted_data(&self) -> Vec<&Data> {
+        let mut data = self.data.iter().collect::<Vec<_>>();
+        data.sort_by(|a, b| a.date.cmp(&b.date));
+        data
+    }
+
+    /// Return quarterly or annual values interpolated to monthly frequency in
+    /// ascending order of date. Monthly values are dated on first day of
+    /// month and months up to end of period of final observation are
+    /// included
+    ///
+    /// `Step` repeats value of observation for each month of period and
+    /// `Linear` interpolates linearly between observations, months after
+    /// final observation are None for `Linear`
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::{
+    ///     EconomicIndicatorFunction, InterpolationMethod,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let real_gdp = api
+    ///         .economic_indicator(EconomicIndicatorFunction::RealGdp)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let monthly = real_gdp.to_monthly(InterpolationMethod::Linear).unwrap();
+    ///     for (date, value) in monthly {
+    ///         println!("{date} {value:?}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if interval is not quarterly or annual or date of any data
+    /// cannot be parsed
+    pub fn to_monthly(&self, method: InterpolationMethod) -> Result<Vec<(String, Option<f64>)>> {
+        let months_per_period = match self.interval.trim().to_lowercase().as_str() {
+            "quarterly" => 3,
+            "annual" | "annually" => 12,
+            _ => return Err(Error::UnsupportedInterval(self.interval.clone())),
+        };
+        let mut data = self
+            .data
+            .iter()
+            .map(|data| Ok((month_index(&data.date)?, data.value)))
+            .collect::<Result<Vec<_>>>()?;
+        data.sort_by_key(|(month, _)| *month);
+
+        let mut monthly = Vec::new();
+        for (index, &(month, value)) in data.iter().enumerate() {
+            let next = data.get(index + 1);
+            let months = next.map_or(months_per_period, |(next_month, _)| next_month - month);
+            for offset in 0..months {
+                let interpolated = match method {
+                    InterpolationMethod::Step => value,
+                    InterpolationMethod::Linear if offset == 0 => value,
+                    InterpolationMethod::Linear => next.and_then(|(_, next_value)| {
+                        let (value, next_value) = (value?, (*next_value)?);
+                        Some(value + (next_value - value) * f64::from(offset) / f64::from(months))
+                    }),
+                };
+                let month = month + offset;
+                monthly.push((
+                    format!(
+                        "{:04}-{:02}-01",
+                        month.div_euclid(12),
+                        month.rem_euclid(12) + 1
+                    ),
+                    interpolated,
+                ));
+            }
+        }
+        Ok(monthly)
+    }
+
+    /// Return unit of economic indicator parsed as `Unit`
+    #[must_use]
+    pub fn unit_enum(&self) -> Unit {
+        Unit::from(self.unit.as_str())
+    }
+
+    /// Return data with values scaled into base unit such as dollars,
+    /// persons or fraction instead of percent. If unit is not recognized
+    /// values are returned as it is and returned values are flagged as not
+    /// normalized
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let treasury_yield = api
+    ///         .economic_indicator(EconomicIndicatorFunction::TreasuryYield)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let normalized = treasury_yield.normalized_values();
+    ///     for data in normalized.data() {
+    ///         println!("{} {:?}", data.date(), data.value());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn normalized_values(&self) -> NormalizedValues {
+        let factor = self.unit_enum().factor();
+        let data = self
+            .data
+            .iter()
+            .map(|data| Data {
+                date: data.date.clone(),
+                value: data.value.map(|value| value * factor.unwrap_or(1.0)),
+            })
+            .collect();
+        NormalizedValues {
+            data,
+            normalized: factor.is_some(),
+        }
+    }
+
+    /// Return maturity of treasury yield parsed from name of economic
+    /// indicator. Returns None if name does not contain known maturity
+    #[must_use]
+    pub fn maturity(&self) -> Option<EconomicIndicatorMaturity> {
+        let (period, _) = self.name.split_once(' ')?;
+        EconomicIndicatorMaturity::ALL
+            .into_iter()
+            .find(|maturity| maturity.name_prefix().eq_ignore_ascii_case(period))
+    }
+}
+
+// End of period covered by CPI observation
+enum CoveredUntil {
+    Inclusive(String),
+    Exclusive(String),
+}
+
+impl CoveredUntil {
+    fn covers(&self, date: &str) -> bool {
+        match self {
+            Self::Inclusive(end) => date <= end.as_str(),
+            Self::Exclusive(end) => date < end.as_str(),
+        }
+    }
+}
+
+/// Enum for declaring method used for interpolating data to higher frequency
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    /// repeat value of observation until next observation
+    Step,
+    /// interpolate linearly between observations
+    Linear,
+}
+
+// Percent change from previous to current value, None if either value is
+// missing or previous value is zero
+fn percent_change(previous: Option<f64>, current: Option<f64>) -> Option<f64> {
+    let (previous, current) = (previous?, current?);
+    (previous != 0.0).then(|| (current - previous) / previous.abs() * 100.0)
+}
+
+// Return most recent observation on or before date from observations sorted
+// in ascending order of date
+fn observation_on<'a>(observations: &[(&'a str, f64)], date: &str) -> Option<(&'a str, f64)> {
+    let index = observations.partition_point(|(observed, _)| *observed <= date);
+    observations.get(index.checked_sub(1)?).copied()
+}
+
+// Number of months since year zero for date in `YYYY-MM-DD` format
+fn month_index(date: &str) -> Result<i32> {
+    let error = || Error::ParseDateTime(date.to_string());
+    let year = date
+        .get(0..4)
+        .ok_or_else(error)?
+        .parse::<i32>()
+        .map_err(|_| error())?;
+    let month = date
+        .get(5..7)
+        .ok_or_else(error)?
+        .parse::<i32>()
+        .map_err(|_| error())?;
+    if date.get(4..5) != Some("-") || !(1..=12).contains(&month) {
+        return Err(error());
+    }
+    Ok(year * 12 + month - 1)
+}
+
+/// Enum for declaring how missing values are handled while aggregating values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingValues {
+    /// aggregate is None if any value is missing
+    Propagate,
+    /// missing values are skipped and aggregate is None only if all values
+    /// are missing
+    Skip,
+}
+
+// Average values of data as per missing value policy
+#[allow(clippy::cast_precision_loss)]
+fn average(data: &[&Data], missing_values: MissingValues) -> Option<f64> {
+    let values = data.iter().map(|data| data.value);
+    let values = match missing_values {
+        MissingValues::Propagate => values.collect::<Option<Vec<_>>>()?,
+        MissingValues::Skip => values.flatten().collect(),
+    };
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Struct for storing data of economic indicator scaled into base unit
+#[derive(Default, Debug, Clone)]
+pub struct NormalizedValues {
+    data: Vec<Data>,
+    normalized: bool,
+}
+
+impl NormalizedValues {
+    /// Return data with scaled value
+    #[must_use]
+    pub fn data(&self) -> &[Data] {
+        &self.data
+    }
+
+    /// Return whether unit was recognized and values are scaled into base
+    /// unit. If false values are same as values returned by API
+    #[must_use]
+    pub fn is_normalized(&self) -> bool {
+        self.normalized
+    }
+}
+
+/// Enum for unit of economic indicator. Unit not known by library is stored
+/// as `Raw`
+///
+/// ```
+/// use alpha_vantage::economic_indicator::{Unit, UnitScale};
+///
+/// assert_eq!(Unit::from("percent"), Unit::Percent);
+/// assert_eq!(
+///     Unit::from("millions of dollars"),
+///     Unit::Dollars {
+///         scale: UnitScale::Millions,
+///         chained_year: None
+///     }
+/// );
+/// assert_eq!(
+///     Unit::from("chained 2012 dollars"),
+///     Unit::Dollars {
+///         scale: UnitScale::One,
+///         chained_year: Some(2012)
+///     }
+/// );
+/// assert_eq!(
+///     Unit::from("thousands of persons"),
+///     Unit::Persons {
+///         scale: UnitScale::Thousands
+///     }
+/// );
+/// assert_eq!(
+///     Unit::from("index 1982-1984=100"),
+///     Unit::Raw("index 1982-1984=100".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// percent
+    Percent,
+    /// dollars optionally chained to dollar of base year
+    Dollars {
+        /// scale of value
+        scale: UnitScale,
+        /// base year of chained dollar
+        chained_year: Option<u16>,
+    },
+    /// persons
+    Persons {
+        /// scale of value
+        scale: UnitScale,
+    },
+    /// unit not known by library
+    Raw(String),
+}
+
+impl Unit {
+    /// Return factor used for converting value into base unit. Returns None
+    /// for raw unit
+    #[must_use]
+    pub fn factor(&self) -> Option<f64> {
+        match self {
+            Unit::Percent => Some(0.01),
+            Unit::Dollars { scale, .. } | Unit::Persons { scale } => Some(scale.factor()),
+            Unit::Raw(_) => None,
+        }
+    }
+}
+
+impl From<&str> for Unit {
+    fn from(value: &str) -> Self {
+        let unit = value.trim().to_lowercase();
+        if unit == "percent" {
+            return Unit::Percent;
+        }
+        let (scale, rest) = [
+            ("thousands of ", UnitScale::Thousands),
+            ("millions of ", UnitScale::Millions),
+            ("billions of ", UnitScale::Billions),
+        ]
+        .into_iter()
+        .find_map(|(prefix, scale)| Some((scale, unit.strip_prefix(prefix)?)))
+        .unwrap_or((UnitScale::One, unit.as_str()));
+        match rest {
+            "dollars" => Unit::Dollars {
+                scale,
+                chained_year: None,
+            },
+            "persons" => Unit::Persons { scale },
+            _ => rest
+                .strip_prefix("chained ")
+                .and_then(|rest| rest.strip_suffix(" dollars"))
+                .and_then(|year| year.parse().ok())
+                .map_or_else(
+                    || Unit::Raw(value.to_string()),
+                    |year| Unit::Dollars {
+                        scale,
+                        chained_year: Some(year),
+                    },
+                ),
+        }
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (scale, base) = match self {
+            Unit::Percent => return f.write_str("percent"),
+            Unit::Raw(raw) => return f.write_str(raw),
+            Unit::Dollars {
+                scale,
+                chained_year: Some(year),
+            } => (scale, format!("chained {year} dollars")),
+            Unit::Dollars { scale, .. } => (scale, "dollars".to_string()),
+            Unit::Persons { scale } => (scale, "persons".to_string()),
+        };
+        match scale {
+            UnitScale::One => f.write_str(&base),
+            UnitScale::Thousands => write!(f, "thousands of {base}"),
+            UnitScale::Millions => write!(f, "millions of {base}"),
+            UnitScale::Billions => write!(f, "billions of {base}"),
+        }
+    }
+}
+
+/// Enum for scale of unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnitScale {
+    /// value is in base unit
+    One,
+    /// value is in thousands
+    Thousands,
+    /// value is in millions
+    Millions,
+    /// value is in billions
+    Billions,
+}
+
+impl UnitScale {
+    /// Return factor used for converting value into base unit
+    #[must_use]
+    pub fn factor(self) -> f64 {
+        match self {
+            UnitScale::One => 1.0,
+            UnitScale::Thousands => 1e3,
+            UnitScale::Millions => 1e6,
+            UnitScale::Billions => 1e9,
+        }
+    }
 }
 
 /// Struct for helping indicator struct
@@ -146,39 +845,92 @@ impl EconomicIndicatorHelper {
 /// Builder to help create `EconomicIndicator`
 pub struct EconomicIndicatorBuilder<'a> {
     api_client: &'a ApiClient,
-    function: &'a str,
+    provider: Option<Provider>,
+    function: EconomicIndicatorFunction,
     interval: Option<EconomicIndicatorInterval>,
     maturity: Option<EconomicIndicatorMaturity>,
 }
 
 impl<'a> EconomicIndicatorBuilder<'a> {
-    crate::json_data_struct!(EconomicIndicator, EconomicIndicatorHelper);
-
     /// Create new `EconomicIndicatorBuilder` form `APIClient`
     #[must_use]
-    pub fn new(api_client: &'a ApiClient, function: &'a str) -> Self {
+    pub fn new(api_client: &'a ApiClient, function: impl Into<EconomicIndicatorFunction>) -> Self {
         Self {
             api_client,
-            function,
+            provider: None,
+            function: function.into(),
             interval: None,
             maturity: None,
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    pub fn provider(&mut self, provider: Provider) -> &mut Self {
+        self.provider = Some(provider);
+        self
+    }
+
     /// Set interval for API
     pub fn interval(&mut self, interval: EconomicIndicatorInterval) -> &mut Self {
         self.interval = Some(interval);
         self
     }
 
-    /// Set maturity for API
+    /// Set maturity for API. Maturity is only supported by `TREASURY_YIELD`
+    /// and custom function
+    ///
+    /// ```no_run
+    /// use alpha_vantage::economic_indicator::{
+    ///     EconomicIndicatorFunction, EconomicIndicatorMaturity,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let treasury_yield = api
+    ///         .economic_indicator(EconomicIndicatorFunction::TreasuryYield)
+    ///         .maturity(EconomicIndicatorMaturity::TenYear)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", treasury_yield.data()[0].value());
+    /// }
+    /// ```
     pub fn maturity(&mut self, maturity: EconomicIndicatorMaturity) -> &mut Self {
         self.maturity = Some(maturity);
         self
     }
 
+    /// Returns JSON data
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors. Also raise error if maturity
+    /// is set for function which does not support maturity
+    pub async fn json(&self) -> Result<EconomicIndicator> {
+        if self.maturity.is_some()
+            && !matches!(
+                self.function,
+                EconomicIndicatorFunction::TreasuryYield | EconomicIndicatorFunction::Custom(_)
+            )
+        {
+            return Err(Error::UnsupportedParameter {
+                parameter: "maturity".to_string(),
+                function: self.function.to_string(),
+            });
+        }
+        let url = self.create_url();
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_json_with(&url, None, EconomicIndicatorHelper::convert)
+            .await
+    }
+
     fn create_url(&self) -> String {
-        let mut created_link = format!("query?function={}", &self.function);
+        let mut created_link = format!("query?function={}", self.function);
 
         if let Some(interval) = &self.interval {
             match interval {
@@ -195,7 +947,9 @@ impl<'a> EconomicIndicatorBuilder<'a> {
         if let Some(maturity) = &self.maturity {
             match maturity {
                 EconomicIndicatorMaturity::ThreeMonth => created_link.push_str("&maturity=3month"),
+                EconomicIndicatorMaturity::TwoYear => created_link.push_str("&maturity=2year"),
                 EconomicIndicatorMaturity::FiveYear => created_link.push_str("&maturity=5year"),
+                EconomicIndicatorMaturity::SevenYear => created_link.push_str("&maturity=7year"),
                 EconomicIndicatorMaturity::TenYear => created_link.push_str("&maturity=10year"),
                 EconomicIndicatorMaturity::ThirtyYear => created_link.push_str("&maturity=30year"),
             }
@@ -205,8 +959,285 @@ impl<'a> EconomicIndicatorBuilder<'a> {
     }
 }
 
+/// Struct for declaring economic indicator requested by
+/// [`ApiClient::economic_indicators`]
+#[derive(Clone, Debug)]
+pub struct EconomicIndicatorSpec {
+    function: EconomicIndicatorFunction,
+    interval: Option<EconomicIndicatorInterval>,
+    maturity: Option<EconomicIndicatorMaturity>,
+}
+
+impl EconomicIndicatorSpec {
+    /// Create new `EconomicIndicatorSpec` for function
+    #[must_use]
+    pub fn new(function: impl Into<EconomicIndicatorFunction>) -> Self {
+        Self {
+            function: function.into(),
+            interval: None,
+            maturity: None,
+        }
+    }
+
+    /// Set interval for economic indicator
+    #[must_use]
+    pub fn interval(mut self, interval: EconomicIndicatorInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Set maturity for economic indicator
+    #[must_use]
+    pub fn maturity(mut self, maturity: EconomicIndicatorMaturity) -> Self {
+        self.maturity = Some(maturity);
+        self
+    }
+
+    /// Return function of economic indicator
+    #[must_use]
+    pub fn function(&self) -> &EconomicIndicatorFunction {
+        &self.function
+    }
+}
+
+/// Struct for storing economic indicators aligned by date
+#[derive(Debug)]
+pub struct AlignedIndicators {
+    specs: Vec<EconomicIndicatorSpec>,
+    indicators: Vec<Result<EconomicIndicator>>,
+    rows: Vec<AlignedRow>,
+}
+
+impl AlignedIndicators {
+    /// Return specs of economic indicators in requested order. Values of row
+    /// are in same order
+    #[must_use]
+    pub fn specs(&self) -> &[EconomicIndicatorSpec] {
+        &self.specs
+    }
+
+    /// Return rows in ascending order of date
+    #[must_use]
+    pub fn rows(&self) -> &[AlignedRow] {
+        &self.rows
+    }
+
+    /// Return economic indicator of spec at index. Returns None if index is
+    /// out of range or request of spec failed
+    #[must_use]
+    pub fn indicator(&self, index: usize) -> Option<&EconomicIndicator> {
+        self.indicators.get(index)?.as_ref().ok()
+    }
+
+    /// Return index of spec and error for every failed request
+    #[must_use]
+    pub fn errors(&self) -> Vec<(usize, &Error)> {
+        self.indicators
+            .iter()
+            .enumerate()
+            .filter_map(|(index, indicator)| Some((index, indicator.as_ref().err()?)))
+            .collect()
+    }
+}
+
+/// Struct for storing row of aligned economic indicators
+#[derive(Debug, Clone)]
+pub struct AlignedRow {
+    date: String,
+    values: Vec<Option<f64>>,
+}
+
+impl AlignedRow {
+    /// Return date of row
+    #[must_use]
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    /// Return most recent value on or before date of every economic indicator
+    /// in order of specs. Value is None if request failed or there is no
+    /// observation on or before date
+    #[must_use]
+    pub fn values(&self) -> &[Option<f64>] {
+        &self.values
+    }
+}
+
+/// Fetch economic indicators concurrently and align them by date
+pub(crate) async fn economic_indicators(
+    api_client: &ApiClient,
+    specs: &[EconomicIndicatorSpec],
+) -> Result<AlignedIndicators> {
+    let mut indicators = futures::future::join_all(specs.iter().map(|spec| async move {
+        let mut builder = api_client.economic_indicator(spec.function.clone());
+        if let Some(interval) = spec.interval {
+            builder.interval(interval);
+        }
+        if let Some(maturity) = spec.maturity {
+            builder.maturity(maturity);
+        }
+        builder.json().await
+    }))
+    .await;
+    if !indicators.is_empty() && indicators.iter().all(Result::is_err) {
+        return Err(indicators.swap_remove(0).unwrap_err());
+    }
+
+    let mut dates = indicators
+        .iter()
+        .flatten()
+        .flat_map(|indicator| indicator.observations().map(|(date, _)| date))
+        .collect::<Vec<_>>();
+    dates.sort_unstable();
+    dates.dedup();
+    let observations = indicators
+        .iter()
+        .map(|indicator| {
+            indicator
+                .as_ref()
+                .map(EconomicIndicator::sorted_observations)
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let rows = dates
+        .into_iter()
+        .map(|date| AlignedRow {
+            date: date.to_string(),
+            values: observations
+                .iter()
+                .map(|observations| Some(observation_on(observations, date)?.1))
+                .collect(),
+        })
+        .collect();
+    Ok(AlignedIndicators {
+        specs: specs.to_vec(),
+        indicators,
+        rows,
+    })
+}
+
+/// Enum for declaring function of economic indicator. Function is parsed case
+/// insensitively and function not known by library is stored as `Custom`
+///
+/// ```
+/// use alpha_vantage::economic_indicator::EconomicIndicatorFunction;
+///
+/// for (raw, function) in [
+///     ("REAL_GDP", EconomicIndicatorFunction::RealGdp),
+///     ("REAL_GDP_PER_CAPITA", EconomicIndicatorFunction::RealGdpPerCapita),
+///     ("TREASURY_YIELD", EconomicIndicatorFunction::TreasuryYield),
+///     ("FEDERAL_FUNDS_RATE", EconomicIndicatorFunction::FederalFundsRate),
+///     ("CPI", EconomicIndicatorFunction::Cpi),
+///     ("INFLATION", EconomicIndicatorFunction::Inflation),
+///     ("RETAIL_SALES", EconomicIndicatorFunction::RetailSales),
+///     ("DURABLES", EconomicIndicatorFunction::Durables),
+///     ("UNEMPLOYMENT", EconomicIndicatorFunction::Unemployment),
+///     ("NONFARM_PAYROLL", EconomicIndicatorFunction::NonfarmPayroll),
+/// ] {
+///     assert_eq!(raw.parse::<EconomicIndicatorFunction>().unwrap(), function);
+///     assert_eq!(function.to_string(), raw);
+/// }
+/// assert_eq!(
+///     " cpi ".parse::<EconomicIndicatorFunction>().unwrap(),
+///     EconomicIndicatorFunction::Cpi
+/// );
+/// let custom = "WTI".parse::<EconomicIndicatorFunction>().unwrap();
+/// assert_eq!(custom, EconomicIndicatorFunction::Custom("WTI".to_string()));
+/// assert_eq!(custom.to_string(), "WTI");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EconomicIndicatorFunction {
+    /// real gross domestic product
+    RealGdp,
+    /// real gross domestic product per capita
+    RealGdpPerCapita,
+    /// treasury yield
+    TreasuryYield,
+    /// federal funds rate
+    FederalFundsRate,
+    /// consumer price index
+    Cpi,
+    /// inflation
+    Inflation,
+    /// retail sales
+    RetailSales,
+    /// durable goods orders
+    Durables,
+    /// unemployment rate
+    Unemployment,
+    /// total nonfarm payroll
+    NonfarmPayroll,
+    /// function not known by library
+    Custom(String),
+}
+
+impl EconomicIndicatorFunction {
+    fn as_str(&self) -> &str {
+        match self {
+            EconomicIndicatorFunction::RealGdp => "REAL_GDP",
+            EconomicIndicatorFunction::RealGdpPerCapita => "REAL_GDP_PER_CAPITA",
+            EconomicIndicatorFunction::TreasuryYield => "TREASURY_YIELD",
+            EconomicIndicatorFunction::FederalFundsRate => "FEDERAL_FUNDS_RATE",
+            EconomicIndicatorFunction::Cpi => "CPI",
+            EconomicIndicatorFunction::Inflation => "INFLATION",
+            EconomicIndicatorFunction::RetailSales => "RETAIL_SALES",
+            EconomicIndicatorFunction::Durables => "DURABLES",
+            EconomicIndicatorFunction::Unemployment => "UNEMPLOYMENT",
+            EconomicIndicatorFunction::NonfarmPayroll => "NONFARM_PAYROLL",
+            EconomicIndicatorFunction::Custom(custom) => custom,
+        }
+    }
+}
+
+impl From<&str> for EconomicIndicatorFunction {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+        [
+            EconomicIndicatorFunction::RealGdp,
+            EconomicIndicatorFunction::RealGdpPerCapita,
+            EconomicIndicatorFunction::TreasuryYield,
+            EconomicIndicatorFunction::FederalFundsRate,
+            EconomicIndicatorFunction::Cpi,
+            EconomicIndicatorFunction::Inflation,
+            EconomicIndicatorFunction::RetailSales,
+            EconomicIndicatorFunction::Durables,
+            EconomicIndicatorFunction::Unemployment,
+            EconomicIndicatorFunction::NonfarmPayroll,
+        ]
+        .into_iter()
+        .find(|function| function.as_str().eq_ignore_ascii_case(value))
+        .unwrap_or_else(|| EconomicIndicatorFunction::Custom(value.to_string()))
+    }
+}
+
+impl From<&String> for EconomicIndicatorFunction {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<String> for EconomicIndicatorFunction {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::str::FromStr for EconomicIndicatorFunction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl std::fmt::Display for EconomicIndicatorFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Enum for declaring interval for economic indicator
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EconomicIndicatorInterval {
     /// daily interval
     Daily,
@@ -221,14 +1252,439 @@ pub enum EconomicIndicatorInterval {
 }
 
 /// Enum for declaring maturity for economic indicator
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EconomicIndicatorMaturity {
     /// 3 month maturity
     ThreeMonth,
+    /// 2 year maturity
+    TwoYear,
     /// 5 year maturity
     FiveYear,
+    /// 7 year maturity
+    SevenYear,
     /// 10 year maturity
     TenYear,
     /// 30 year maturity
     ThirtyYear,
 }
+
+impl EconomicIndicatorMaturity {
+    const ALL: [EconomicIndicatorMaturity; 6] = [
+        EconomicIndicatorMaturity::ThreeMonth,
+        EconomicIndicatorMaturity::TwoYear,
+        EconomicIndicatorMaturity::FiveYear,
+        EconomicIndicatorMaturity::SevenYear,
+        EconomicIndicatorMaturity::TenYear,
+        EconomicIndicatorMaturity::ThirtyYear,
+    ];
+
+    // Prefix used by API in name of treasury yield such as `10-Year`
+    fn name_prefix(self) -> &'static str {
+        match self {
+            EconomicIndicatorMaturity::ThreeMonth => "3-Month",
+            EconomicIndicatorMaturity::TwoYear => "2-Year",
+            EconomicIndicatorMaturity::FiveYear => "5-Year",
+            EconomicIndicatorMaturity::SevenYear => "7-Year",
+            EconomicIndicatorMaturity::TenYear => "10-Year",
+            EconomicIndicatorMaturity::ThirtyYear => "30-Year",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    use chrono::NaiveDate;
+
+    use crate::economic_indicator::{
+        Data, EconomicIndicator, EconomicIndicatorFunction, EconomicIndicatorInterval,
+        EconomicIndicatorMaturity, InterpolationMethod, MissingValues, Unit, UnitScale,
+    };
+    use crate::error::Error;
+    use crate::test_utils::FixtureClient;
+
+    async fn fetch(
+        function: impl Into<EconomicIndicatorFunction>,
+        path: &str,
+    ) -> EconomicIndicator {
+        crate::set_api("demo", FixtureClient::new(path))
+            .economic_indicator(function)
+            .json()
+            .await
+            .unwrap()
+    }
+
+    fn values(data: Vec<(String, Option<f64>)>) -> Vec<Option<f64>> {
+        data.into_iter().map(|(_, value)| value).collect()
+    }
+
+    #[tokio::test]
+    async fn missing_value() {
+        let treasury_yield = crate::set_api(
+            "demo",
+            FixtureClient::new("economic_indicator/treasury_yield_daily.json"),
+        )
+        .economic_indicator(EconomicIndicatorFunction::TreasuryYield)
+        .interval(EconomicIndicatorInterval::Daily)
+        .json()
+        .await
+        .unwrap();
+        let data = treasury_yield.data();
+        assert_eq!(data.len(), 4);
+        assert_eq!(data[1].value(), Some(3.95));
+        assert_eq!(data[2].date(), "2024-01-01");
+        assert_eq!(data[2].value(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn between_dates() {
+        let real_gdp = fetch(
+            EconomicIndicatorFunction::RealGdp,
+            "economic_indicator/real_gdp_quarterly.json",
+        )
+        .await;
+        let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let data = real_gdp.between(start, end).unwrap();
+        let dates = data.iter().map(|data| data.date()).collect::<Vec<_>>();
+        assert_eq!(
+            dates,
+            [
+                "2022-01-01",
+                "2022-04-01",
+                "2022-07-01",
+                "2022-10-01",
+                "2023-01-01",
+                "2023-04-01",
+                "2023-07-01",
+                "2023-10-01",
+            ]
+        );
+
+        let end = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+        let data = real_gdp.between(start, end).unwrap();
+        assert_eq!(data.last().unwrap().date(), "2023-10-01");
+    }
+
+    #[tokio::test]
+    async fn value_on_date() {
+        let real_gdp = fetch(
+            EconomicIndicatorFunction::RealGdp,
+            "economic_indicator/real_gdp_quarterly.json",
+        )
+        .await;
+        assert_eq!(
+            real_gdp.value_on("2022-05-15"),
+            Some(("2022-04-01".to_string(), 19681.682))
+        );
+        assert_eq!(
+            real_gdp.value_on("2022-04-01"),
+            Some(("2022-04-01".to_string(), 19681.682))
+        );
+        assert_eq!(real_gdp.value_on("2020-12-31"), None);
+        assert_eq!(
+            real_gdp.latest(),
+            Some(("2024-01-01".to_string(), 21108.421))
+        );
+    }
+
+    #[tokio::test]
+    async fn deflate_by_cpi() {
+        let cpi = fetch(
+            EconomicIndicatorFunction::Cpi,
+            "economic_indicator/cpi_monthly.json",
+        )
+        .await;
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales.json",
+        )
+        .await;
+        assert_eq!(
+            retail_sales.deflate(&cpi, "2023-01-01").unwrap(),
+            [
+                ("2023-01-01".to_string(), 600_000.0),
+                ("2023-02-01".to_string(), 600_000.0),
+                ("2023-03-01".to_string(), 612_000.0),
+            ]
+        );
+
+        // quarterly series is joined with monthly CPI
+        let gdp = fetch("GDP", "economic_indicator/nominal_gdp_quarterly.json").await;
+        assert_eq!(
+            gdp.deflate(&cpi, "2023-01-15").unwrap(),
+            [
+                ("2023-01-01".to_string(), 26_000.0),
+                ("2023-04-01".to_string(), 25_500.0),
+            ]
+        );
+
+        // CPI does not cover start of real GDP series
+        let real_gdp = fetch(
+            EconomicIndicatorFunction::RealGdp,
+            "economic_indicator/real_gdp_quarterly.json",
+        )
+        .await;
+        let error = real_gdp.deflate(&cpi, "2023-01-01").unwrap_err();
+        assert!(matches!(error, Error::NoDataForDate(date) if date == "2021-01-01"));
+
+        // CPI ends before retail sales series, April CPI only covers April
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales_recent.json",
+        )
+        .await;
+        let error = retail_sales.deflate(&cpi, "2023-01-01").unwrap_err();
+        assert!(matches!(error, Error::NoDataForDate(date) if date == "2023-05-01"));
+    }
+
+    #[tokio::test]
+    async fn deflate_rejects_base_date_past_cpi() {
+        let cpi = fetch(
+            EconomicIndicatorFunction::Cpi,
+            "economic_indicator/cpi_monthly.json",
+        )
+        .await;
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales.json",
+        )
+        .await;
+        let error = retail_sales.deflate(&cpi, "2023-06-01").unwrap_err();
+        assert!(matches!(error, Error::NoDataForDate(date) if date == "2023-06-01"));
+        assert!(retail_sales.deflate(&cpi, "2023-04-15").is_ok());
+    }
+
+    #[tokio::test]
+    async fn smoothed_values() {
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales.json",
+        )
+        .await;
+        let smoothed = retail_sales.smoothed(2);
+        assert_eq!(smoothed[0].0, "2023-01-01");
+        assert_eq!(values(smoothed), [None, Some(605_000.0), Some(621_200.0)]);
+        assert_eq!(
+            values(retail_sales.smoothed(3)),
+            [None, None, Some(614_133.333_333_333_4)]
+        );
+        assert_eq!(values(retail_sales.smoothed(4)), [None, None, None]);
+
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales_missing.json",
+        )
+        .await;
+        assert_eq!(
+            values(retail_sales.smoothed(2)),
+            [None, Some(150.0), None, None, Some(450.0)]
+        );
+        assert_eq!(
+            values(retail_sales.smoothed_with(2, MissingValues::Skip)),
+            [None, Some(150.0), Some(200.0), Some(400.0), Some(450.0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn percent_change() {
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales_missing.json",
+        )
+        .await;
+        let change = retail_sales.pct_change();
+        assert_eq!(change[1], ("2023-02-01".to_string(), Some(100.0)));
+        assert_eq!(values(change), [None, Some(100.0), None, None, Some(25.0)]);
+
+        // CPI levels into inflation rate
+        let cpi = fetch(
+            EconomicIndicatorFunction::Cpi,
+            "economic_indicator/cpi_2022.json",
+        )
+        .await;
+        let inflation = cpi.pct_change_yoy();
+        assert!(inflation[..12].iter().all(|(_, value)| value.is_none()));
+        let (date, december) = &inflation[12];
+        assert_eq!(date, "2022-12-01");
+        assert_eq!(format!("{:.1}", december.unwrap()), "6.5");
+        let (date, january) = &inflation[13];
+        assert_eq!(date, "2023-01-01");
+        assert_eq!(format!("{:.1}", january.unwrap()), "6.4");
+    }
+
+    #[tokio::test]
+    async fn quarterly_to_monthly() {
+        let gdp = fetch("GDP", "economic_indicator/nominal_gdp_quarterly.json").await;
+
+        let step = gdp.to_monthly(InterpolationMethod::Step).unwrap();
+        let dates = step
+            .iter()
+            .map(|(date, _)| date.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dates,
+            [
+                "2023-01-01",
+                "2023-02-01",
+                "2023-03-01",
+                "2023-04-01",
+                "2023-05-01",
+                "2023-06-01",
+            ]
+        );
+        assert_eq!(
+            values(step),
+            [
+                Some(26_000.0),
+                Some(26_000.0),
+                Some(26_000.0),
+                Some(26_520.0),
+                Some(26_520.0),
+                Some(26_520.0),
+            ]
+        );
+
+        let linear = gdp.to_monthly(InterpolationMethod::Linear).unwrap();
+        let rounded = values(linear)
+            .into_iter()
+            .map(|value| value.map(|value| (value * 1000.0).round() / 1000.0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rounded,
+            [
+                Some(26_000.0),
+                Some(26_173.333),
+                Some(26_346.667),
+                Some(26_520.0),
+                None,
+                None,
+            ]
+        );
+
+        let retail_sales = fetch(
+            EconomicIndicatorFunction::RetailSales,
+            "economic_indicator/retail_sales.json",
+        )
+        .await;
+        let error = retail_sales
+            .to_monthly(InterpolationMethod::Step)
+            .unwrap_err();
+        assert!(matches!(error, Error::UnsupportedInterval(interval) if interval == "monthly"));
+    }
+
+    #[tokio::test]
+    async fn normalized_by_unit() {
+        let treasury_yield = fetch(
+            EconomicIndicatorFunction::TreasuryYield,
+            "economic_indicator/treasury_yield_10year.json",
+        )
+        .await;
+        assert_eq!(treasury_yield.unit_enum(), Unit::Percent);
+        let normalized = treasury_yield.normalized_values();
+        assert!(normalized.is_normalized());
+        assert_eq!(normalized.data()[0].value(), Some(0.0421));
+        assert_eq!(normalized.data()[2].value(), Some(0.0406));
+
+        let real_gdp = fetch(
+            EconomicIndicatorFunction::RealGdp,
+            "economic_indicator/real_gdp_quarterly.json",
+        )
+        .await;
+        assert_eq!(
+            real_gdp.unit_enum(),
+            Unit::Dollars {
+                scale: UnitScale::Billions,
+                chained_year: Some(2012)
+            }
+        );
+        let normalized = real_gdp.normalized_values();
+        assert!(normalized.is_normalized());
+        assert_eq!(normalized.data()[0].value(), Some(21_108_421_000_000.0));
+    }
+
+    #[tokio::test]
+    async fn maturity_of_treasury_yield() {
+        let client = FixtureClient::new("economic_indicator/treasury_yield_10year.json");
+        let api = crate::set_api("demo", client.clone());
+        let treasury_yield = api
+            .economic_indicator(EconomicIndicatorFunction::TreasuryYield)
+            .maturity(EconomicIndicatorMaturity::TenYear)
+            .json()
+            .await
+            .unwrap();
+        assert!(client.urls()[0].contains("query?function=TREASURY_YIELD&maturity=10year&apikey="));
+        assert_eq!(
+            treasury_yield.maturity(),
+            Some(EconomicIndicatorMaturity::TenYear)
+        );
+        assert_eq!(treasury_yield.data()[0].value(), Some(4.21));
+
+        let error = api
+            .economic_indicator(EconomicIndicatorFunction::Cpi)
+            .maturity(EconomicIndicatorMaturity::TwoYear)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnsupportedParameter { .. }));
+        assert_eq!(error.to_string(), "maturity is not supported by CPI");
+        // unsupported parameter is rejected before request is sent
+        assert_eq!(client.calls(), 1);
+    }
+
+    fn indicator(interval: &str, data: &[(&str, f64)]) -> EconomicIndicator {
+        EconomicIndicator {
+            interval: interval.to_string(),
+            data: data
+                .iter()
+                .map(|(date, value)| Data {
+                    date: (*date).to_string(),
+                    value: Some(*value),
+                })
+                .collect(),
+            ..EconomicIndicator::default()
+        }
+    }
+
+    #[test]
+    fn pct_change_yoy_matches_by_date() {
+        // February 2022 is missing so March 2023 must not compare with
+        // February 2022 by position
+        let cpi = indicator(
+            "monthly",
+            &[
+                ("2022-01-01", 100.0),
+                ("2022-03-01", 200.0),
+                ("2023-01-01", 110.0),
+                ("2023-02-01", 120.0),
+                ("2023-03-01", 220.0),
+            ],
+        );
+        let change = cpi
+            .pct_change_yoy()
+            .into_iter()
+            .map(|(_, value)| value.map(f64::round))
+            .collect::<Vec<_>>();
+        assert_eq!(change, [None, None, Some(10.0), None, Some(10.0)]);
+    }
+
+    #[test]
+    fn pct_change_yoy_supports_semiannual() {
+        let indicator = indicator(
+            "semiannual",
+            &[
+                ("2022-01-01", 100.0),
+                ("2022-07-01", 200.0),
+                ("2023-01-01", 150.0),
+                ("2023-07-01", 100.0),
+            ],
+        );
+        let change = indicator
+            .pct_change_yoy()
+            .into_iter()
+            .map(|(_, value)| value.map(f64::round))
+            .collect::<Vec<_>>();
+        assert_eq!(change, [None, None, Some(50.0), Some(-50.0)]);
+    }
+}
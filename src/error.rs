@@ -1,6 +1,10 @@
 //! Module which contains all types of error for alpha vantage crates
+use std::sync::Arc;
+
 use thiserror::Error;
 
+use crate::technical_indicator::TechnicalIndicator;
+
 /// Result type for alpha vantage crate
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -40,15 +44,390 @@ pub enum Error {
     #[error("failed to get output from sever")]
     GetRequestFailed,
 
+    /// Error which is raised if client fails to connect to server
+    #[error("failed to connect to server: {0}")]
+    Connect(String),
+
+    /// Error which is raised if TLS handshake with server fails
+    #[error("tls handshake failed: {0}")]
+    Tls(String),
+
+    /// Error which is raised if request does not complete within timeout of
+    /// client
+    #[error("request timed out")]
+    Timeout,
+
+    /// Error which is raised if request fails after connection to server is
+    /// established
+    #[error("request failed: {0}")]
+    Transport(String),
+
+    /// Error which is raised if body of response is larger than limit of
+    /// client
+    #[error("response body is larger than {0} bytes")]
+    ResponseTooLarge(usize),
+
     /// Error which is raised if client fails to decode it into struct
     #[error("failed to decode string into struct")]
     DecodeJsonToStruct,
 
+    /// Error which is raised if client fails to decode JSON into struct with
+    /// detail of failure. API key is redacted from snippet of body
+    #[error("failed to decode json at {path}: {source}; body: {snippet}")]
+    DecodeJson {
+        /// path of field which failed to decode, `.` if failure is at root
+        path: String,
+        /// line of body where failure occurred, 0 if it is not known
+        line: usize,
+        /// column of body where failure occurred, 0 if it is not known
+        column: usize,
+        /// truncated snippet of body
+        snippet: String,
+        /// error raised by decoder
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// Error which is raised if server responds with status code other than
+    /// 2xx. API key is redacted from snippet of body
+    #[error("server responded with status {code}: {body_snippet}")]
+    HttpStatus {
+        /// HTTP status code of response
+        code: u16,
+        /// truncated snippet of body
+        body_snippet: String,
+    },
+
+    /// Error which is raised if search does not return any match for keywords
+    #[error("no match found for keywords {keywords}")]
+    NoMatchFound {
+        /// keywords which are used for search
+        keywords: String,
+    },
+
+    /// Error which is raised if date or time returned by API cannot be parsed
+    #[error("failed to parse date time from {0}")]
+    ParseDateTime(String),
+
+    /// Error which is raised if data is not present on or before date
+    #[error("no data found on or before {0}")]
+    NoDataForDate(String),
+
+    /// Error which wraps error raised by request to API function used
+    /// internally by helper method. Use [`Error::inner`] to get wrapped error
+    #[error("{function} request failed: {source}")]
+    Endpoint {
+        /// name of API function which was requested
+        function: String,
+        /// error raised by request
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// Error which is raised if request of month fails after data of previous
+    /// months of backfill is fetched
+    #[error("backfill failed at month {month}: {source}")]
+    PartialBackfill {
+        /// month whose request failed
+        month: String,
+        /// data of months fetched before failure
+        fetched: Box<TechnicalIndicator>,
+        /// error raised by request of month
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// Error which wraps error raised by request or by conversion of its
+    /// output, including error returned by API, with context of request, so
+    /// error of one of many requests can be told apart. Use [`Error::inner`]
+    /// to get wrapped error
+    ///
+    /// Error raised by builder before request is sent, such as
+    /// [`Error::MissingParameter`], [`Error::UnexpectedParameter`],
+    /// [`Error::InvalidParameter`] or [`Error::UnsupportedParameter`], is not
+    /// wrapped since no request is made
+    #[error("{endpoint} request to {url} via {provider} failed: {source}")]
+    Request {
+        /// name of API function which was requested, empty if path of request
+        /// does not contain function
+        endpoint: String,
+        /// url of request where API key is redacted
+        url: String,
+        /// name of provider to which request was sent
+        provider: String,
+        /// error raised by request
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// Error which is raised if exchange rate of one of leg used for cross
+    /// exchange rate cannot be fetched
+    #[error("exchange leg {from_currency}/{to_currency} failed: {source}")]
+    ExchangeLeg {
+        /// currency from which leg exchanges
+        from_currency: String,
+        /// currency to which leg exchanges
+        to_currency: String,
+        /// error raised by leg
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// Error which is raised if time zone returned by API is not supported
+    #[error("unsupported time zone {0}")]
+    UnsupportedTimeZone(String),
+
+    /// Error which is raised if currency code is not valid
+    #[error("invalid currency code {0:?}")]
+    InvalidCurrencyCode(String),
+
+    /// Error which is raised if API reports that one of currency used for
+    /// exchange rate is invalid
+    #[error("invalid {which} {code}")]
+    InvalidCurrency {
+        /// field of request which contains invalid currency
+        which: FromOrTo,
+        /// invalid currency code reported by API
+        code: String,
+    },
+
+    /// Error which is raised if value is missing from data of time
+    #[error("missing {key} value at {time}")]
+    MissingValue {
+        /// time of data
+        time: String,
+        /// key of missing value
+        key: String,
+    },
+
+    /// Error which is raised if parameter required by API function is not set
+    #[error("missing parameter {parameter} for {function}")]
+    MissingParameter {
+        /// name of parameter
+        parameter: String,
+        /// name of API function
+        function: String,
+    },
+
+    /// Error which is raised if parameter set in builder is not accepted by
+    /// API function
+    #[error("unexpected parameter {parameter} for {function}")]
+    UnexpectedParameter {
+        /// name of parameter
+        parameter: String,
+        /// name of API function
+        function: String,
+    },
+
+    /// Error which is raised if parameter is set more than once in strict
+    /// mode
+    #[error("parameter {0} is set more than once")]
+    DuplicateParameter(String),
+
+    /// Error which is raised if fragment of query string cannot be parsed as
+    /// parameter
+    #[error("malformed query fragment {0:?}")]
+    MalformedQuery(String),
+
+    /// Error which is raised if value of parameter is not valid
+    #[error("invalid value {value} for parameter {parameter}")]
+    InvalidParameter {
+        /// name of parameter
+        parameter: String,
+        /// invalid value of parameter
+        value: String,
+    },
+
+    /// Error which is raised if parameter set in builder is not supported by
+    /// API function
+    #[error("{parameter} is not supported by {function}")]
+    UnsupportedParameter {
+        /// name of parameter
+        parameter: String,
+        /// name of API function
+        function: String,
+    },
+
+    /// Error which is raised if operation is not supported for interval of
+    /// data
+    #[error("operation is not supported for {0} interval")]
+    UnsupportedInterval(String),
+
+    /// Error which is raised if coalesced request fails, which shares error
+    /// raised by request with every request waiting for it. Use
+    /// [`Error::inner`] to get shared error
+    #[error("coalesced request failed: {0}")]
+    CoalescedRequest(#[source] Arc<Error>),
+
+    /// Error which is raised if environment variable is missing or empty
+    #[error("environment variable {0} is missing or empty")]
+    MissingEnvironmentVariable(String),
+
+    /// Error which is raised if options set in `ApiClientBuilder` cannot be
+    /// used together
+    #[error("invalid client configuration: {0}")]
+    InvalidConfiguration(String),
+
+    /// Error which is raised if replaying client does not have recorded
+    /// response of request
+    #[error("no recorded response for {url} in {file}")]
+    MissingCassette {
+        /// url of request where API key is redacted
+        url: String,
+        /// file which is expected to contain recorded response
+        file: String,
+    },
+
+    /// Error which is raised if recording client cannot write response to
+    /// file
+    #[error("failed to record response for {url} in {file}")]
+    RecordCassette {
+        /// url of request where API key is redacted
+        url: String,
+        /// file where response is written
+        file: String,
+    },
+
+    /// Error which is raised if global client is used before it is
+    /// initialized
+    #[error("global client is not initialized")]
+    GlobalClientNotInitialized,
+
+    /// Error which is raised if global client is initialized more than once
+    #[error("global client is already initialized")]
+    GlobalClientAlreadyInitialized,
+
+    /// Error which is raised if runtime of blocking client cannot be created
+    #[error("failed to create runtime")]
+    CreateRuntime,
+
     /// Error which is raised if url is failed to get created
     #[error("failed to create url")]
     CreateUrl,
 }
 
+/// Enum for field of exchange request which contains invalid currency
+///
+/// ```no_run
+/// use alpha_vantage::error::{Error, FromOrTo};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     if let Err(error) = api.exchange("EURO", "USD").json().await {
+///         if let Error::InvalidCurrency { which: FromOrTo::From, code } = error.inner() {
+///             println!("unknown currency {code}");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromOrTo {
+    /// `from_currency` field of request
+    From,
+    /// `to_currency` field of request
+    To,
+}
+
+impl std::fmt::Display for FromOrTo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromOrTo::From => f.write_str("from_currency"),
+            FromOrTo::To => f.write_str("to_currency"),
+        }
+    }
+}
+
+impl Error {
+    /// Return error wrapped by [`Error::Request`], [`Error::Endpoint`],
+    /// [`Error::ExchangeLeg`], [`Error::PartialBackfill`] or
+    /// [`Error::CoalescedRequest`], or error itself if it does not carry
+    /// context of request
+    ///
+    /// ```no_run
+    /// use alpha_vantage::error::Error;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     if let Err(error) = api.quote("MSFT").json().await {
+    ///         // wrapped error contains endpoint and redacted url of request
+    ///         println!("{error}");
+    ///         if matches!(error.inner(), Error::GetRequestFailed) {
+    ///             println!("server cannot be reached");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Request { source, .. }
+            | Error::Endpoint { source, .. }
+            | Error::ExchangeLeg { source, .. }
+            | Error::PartialBackfill { source, .. } => source.inner(),
+            Error::CoalescedRequest(source) => source.inner(),
+            _ => self,
+        }
+    }
+
+    /// Check whether error is caused by alpha vantage API rate limit
+    #[must_use]
+    pub fn is_rate_limit(&self) -> bool {
+        match self.inner() {
+            Error::AlphaVantageNote(_) | Error::HttpStatus { code: 429, .. } => true,
+            Error::AlphaVantageInformation(information) => {
+                information.to_lowercase().contains("rate limit")
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether request which raised error can be retried since server
+    /// failed to respond, connection failed or timed out, server responded
+    /// with 5xx status or limited rate with 429 status
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     if let Err(error) = api.quote("IBM").json().await {
+    ///         if error.is_retryable() {
+    ///             println!("request can be sent again: {error}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self.inner() {
+            Error::GetRequestFailed
+            | Error::Connect(_)
+            | Error::Timeout
+            | Error::Transport(_) => true,
+            Error::HttpStatus { code, .. } => *code == 429 || (500..600).contains(code),
+            _ => false,
+        }
+    }
+}
+
+/// maximum number of characters of body kept in error
+const SNIPPET_LENGTH: usize = 200;
+
+// Truncated snippet of body whose API key is redacted
+pub(crate) fn body_snippet(body: &str, api_key: &str) -> String {
+    let body = if api_key.is_empty() {
+        body.to_string()
+    } else {
+        body.replace(api_key, "[REDACTED]")
+    };
+    let mut snippet: String = body.chars().take(SNIPPET_LENGTH).collect();
+    if snippet.len() < body.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
 pub(crate) fn detect_common_helper_error(
     information: Option<String>,
     error_message: Option<String>,
@@ -65,3 +444,148 @@ pub(crate) fn detect_common_helper_error(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+    use std::sync::Arc;
+
+    use super::{Error, FromOrTo};
+    use crate::stock_time::StockFunction;
+    use crate::test_utils::FixtureClient;
+
+    fn exchange_leg(source: Error) -> Error {
+        Error::ExchangeLeg {
+            from_currency: "USD".to_string(),
+            to_currency: "EUR".to_string(),
+            source: Box::new(source),
+        }
+    }
+
+    #[test]
+    fn exchange_leg_exposes_source() {
+        let error = exchange_leg(Error::AlphaVantageNote("limit".to_string()));
+        assert!(matches!(error.inner(), Error::AlphaVantageNote(_)));
+        assert!(error.is_rate_limit());
+
+        let error = exchange_leg(Error::HttpStatus {
+            code: 503,
+            body_snippet: String::new(),
+        });
+        assert!(!error.is_rate_limit());
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn wrappers_expose_rate_limit() {
+        let note = || Box::new(Error::AlphaVantageNote("limit".to_string()));
+        let wrappers = [
+            Error::Request {
+                endpoint: "GLOBAL_QUOTE".to_string(),
+                url: String::new(),
+                provider: "AlphaVantage".to_string(),
+                source: note(),
+            },
+            Error::Endpoint {
+                function: "FX_DAILY".to_string(),
+                source: note(),
+            },
+            exchange_leg(*note()),
+            Error::PartialBackfill {
+                month: "2024-01".to_string(),
+                fetched: Box::default(),
+                source: note(),
+            },
+            Error::CoalescedRequest(Arc::new(*note())),
+        ];
+        for error in wrappers {
+            assert!(error.is_rate_limit(), "{error}");
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_currency() {
+        let client = FixtureClient::new("exchange/invalid_from_currency.json");
+        let api = crate::set_api("demo", client);
+        let error = api.exchange("EURO", "USD").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::InvalidCurrency { which: FromOrTo::From, code } if code == "EURO"
+        ));
+        assert_eq!(error.inner().to_string(), "invalid from_currency EURO");
+
+        let client = FixtureClient::new("exchange/invalid_to_currency.json");
+        let api = crate::set_api("demo", client);
+        let error = api.exchange("EUR", "USDD").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::InvalidCurrency { which: FromOrTo::To, code } if code == "USDD"
+        ));
+
+        // unrelated error message stays generic
+        let api = crate::set_api("demo", FixtureClient::new("error/invalid_call.json"));
+        let error = api.exchange("EUR", "USD").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageErrorMessage(_)));
+    }
+
+    #[tokio::test]
+    async fn request_context() {
+        let client = FixtureClient::status(200, r#"{"Global Quote": ["#).route_error("symbol=FAIL");
+        let api = crate::set_api("secret-key", client);
+
+        // transport failure
+        let error = api.quote("FAIL").json().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Request { ref endpoint, ref provider, .. }
+                if endpoint == "GLOBAL_QUOTE" && provider == "AlphaVantage"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "GLOBAL_QUOTE request to https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=FAIL&apikey=[REDACTED] \
+             via AlphaVantage failed: failed to get output from sever"
+        );
+        assert!(matches!(error.inner(), Error::GetRequestFailed));
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "failed to get output from sever"
+        );
+
+        // decode failure
+        let error = api
+            .stock_time(StockFunction::Daily, "IBM")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("TIME_SERIES_DAILY request to ")
+        );
+        assert!(!error.to_string().contains("secret-key"));
+        assert!(matches!(error.inner(), Error::DecodeJsonToStruct));
+    }
+
+    #[tokio::test]
+    async fn http_status() {
+        let html = "<html><body>503 Service Unavailable</body></html>";
+        let api = crate::set_api("demo", FixtureClient::status(503, html));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::HttpStatus { code: 503, body_snippet } if body_snippet == html
+        ));
+        assert!(error.is_retryable());
+        assert!(!error.is_rate_limit());
+
+        let api = crate::set_api("demo", FixtureClient::status(429, "Too Many Requests"));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::HttpStatus { code: 429, .. }));
+        assert!(error.is_retryable());
+        assert!(error.is_rate_limit());
+
+        let api = crate::set_api("demo", FixtureClient::status(404, "Not Found"));
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(!error.is_retryable());
+    }
+}
@@ -8,14 +8,28 @@
 //!
 //! [exchange]: https://www.alphavantage.co/documentation/#currency-exchnage
 
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::api::ApiClient;
-use crate::deserialize::from_str;
-use crate::error::{detect_common_helper_error, Error, Result};
+use crate::api::{encode_query_value, ApiClient, OutputSize, Provider};
+use crate::crypto::CryptoFunction;
+use crate::deserialize::{from_none_str, from_str};
+use crate::error::{detect_common_helper_error, Error, FromOrTo, Result};
+use crate::forex::ForexFunction;
+
+/// Well known digital currency codes used for guessing kind of currency
+const DIGITAL_CURRENCIES: [&str; 20] = [
+    "ADA", "AVAX", "BCH", "BNB", "BTC", "DAI", "DOGE", "DOT", "ETC", "ETH", "LINK", "LTC", "MATIC",
+    "SHIB", "SOL", "TRX", "USDC", "USDT", "XLM", "XRP",
+];
 
 /// Struct used for exchanging currency
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct Exchange {
     real_time: RealtimeExchangeRate,
 }
@@ -32,22 +46,128 @@ struct RealtimeExchangeRate {
     #[serde(rename = "4. To_Currency Name")]
     to_name: String,
     #[serde(rename = "5. Exchange Rate", deserialize_with = "from_str")]
-    rate: f64,
+    rate: Number,
     #[serde(rename = "6. Last Refreshed")]
     last_refreshed: String,
     #[serde(rename = "7. Time Zone")]
     time_zone: String,
-    #[serde(rename = "8. Bid Price")]
-    bid_price: String,
-    #[serde(rename = "9. Ask Price")]
-    ask_price: String,
+    #[serde(rename = "8. Bid Price", deserialize_with = "from_none_str", default)]
+    bid_price: Option<Number>,
+    #[serde(rename = "9. Ask Price", deserialize_with = "from_none_str", default)]
+    ask_price: Option<Number>,
+}
+
+/// Number returned by API. When `decimal` feature is enabled lossless decimal
+/// value is also stored along with `f64` value
+#[derive(Debug, Clone, Copy, Default)]
+struct Number {
+    value: f64,
+    #[cfg(feature = "decimal")]
+    decimal: Decimal,
+}
+
+impl FromStr for Number {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(Self {
+            value: s.parse().map_err(|error| format!("{error}"))?,
+            #[cfg(feature = "decimal")]
+            decimal: Decimal::from_str(s)
+                .or_else(|_| Decimal::from_scientific(s))
+                .map_err(|error| format!("{error}"))?,
+        })
+    }
 }
 
 impl Exchange {
     /// Get Rate for exchange
     #[must_use]
     pub fn rate(&self) -> f64 {
-        self.real_time.rate
+        self.real_time.rate.value
+    }
+
+    /// Get rate for exchanging in opposite direction. Returns None if rate is
+    /// zero, negative or not finite
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let exchange = api.exchange("USD", "JPY").json().await.unwrap();
+    ///     if let Some(inverse) = exchange.inverse_rate() {
+    ///         println!("1 JPY = {inverse} USD");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn inverse_rate(&self) -> Option<f64> {
+        self.convert_inverse(1.0)
+    }
+
+    /// Convert amount of from currency to to currency. No rounding is
+    /// performed so result should be rounded by caller as per precision of
+    /// currency
+    #[must_use]
+    pub fn convert(&self, amount: f64) -> f64 {
+        amount * self.rate()
+    }
+
+    /// Convert amount of to currency to from currency. Amount is divided by
+    /// rate directly instead of multiplying with inverse rate to avoid extra
+    /// rounding for very small rate. No rounding is performed so result should
+    /// be rounded by caller. Returns None if rate is zero, negative or not
+    /// finite
+    #[must_use]
+    pub fn convert_inverse(&self, amount: f64) -> Option<f64> {
+        let rate = self.rate();
+        (rate.is_finite() && rate > 0.0).then(|| amount / rate)
+    }
+
+    /// Get rate for exchange as lossless decimal parsed directly from
+    /// response. Rate with many decimal places such as rate of crypto
+    /// currency does not drift as `f64` rate does
+    ///
+    /// ```no_run
+    /// use rust_decimal::Decimal;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let exchange = api.exchange("BTC", "USD").json().await.unwrap();
+    ///     let amount = exchange.convert_decimal(Decimal::from(2)).unwrap();
+    ///     println!("2 BTC = {amount} USD");
+    /// }
+    /// ```
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn rate_decimal(&self) -> Decimal {
+        self.real_time.rate.decimal
+    }
+
+    /// Get bid price as lossless decimal. Returns None if response does not
+    /// contain bid price
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn bid_decimal(&self) -> Option<Decimal> {
+        self.real_time.bid_price.map(|bid| bid.decimal)
+    }
+
+    /// Get ask price as lossless decimal. Returns None if response does not
+    /// contain ask price
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn ask_decimal(&self) -> Option<Decimal> {
+        self.real_time.ask_price.map(|ask| ask.decimal)
+    }
+
+    /// Convert decimal amount of from currency to to currency using lossless
+    /// decimal rate. Returns None if result overflows decimal
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn convert_decimal(&self, amount: Decimal) -> Option<Decimal> {
+        amount.checked_mul(self.rate_decimal())
     }
 
     /// Get time when exchange rate was last refreshed along with time zone.
@@ -62,6 +182,42 @@ impl Exchange {
         &self.real_time.time_zone
     }
 
+    /// Return time when exchange rate was last refreshed parsed using time
+    /// zone returned by API
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let exchange = api.exchange("BTC", "EUR").json().await.unwrap();
+    ///     println!("{}", exchange.refreshed_at().unwrap());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if last refreshed time is not in `YYYY-MM-DD HH:MM:SS`
+    /// format or time zone is not UTC
+    #[cfg(feature = "chrono")]
+    pub fn refreshed_at(&self) -> Result<DateTime<Utc>> {
+        let time_zone = self.real_time.time_zone.trim();
+        if !time_zone.eq_ignore_ascii_case("UTC") {
+            return Err(Error::UnsupportedTimeZone(time_zone.to_string()));
+        }
+        let last_refreshed = &self.real_time.last_refreshed;
+        NaiveDateTime::parse_from_str(last_refreshed.trim(), "%Y-%m-%d %H:%M:%S")
+            .map(|time| time.and_utc())
+            .map_err(|_| Error::ParseDateTime(last_refreshed.clone()))
+    }
+
+    /// Return time elapsed between last refresh of exchange rate and `now`
+    ///
+    /// # Errors
+    /// Raise error if last refreshed time cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn age(&self, now: DateTime<Utc>) -> Result<Duration> {
+        Ok(now - self.refreshed_at()?)
+    }
+
     /// get from code from which exchange is performed
     ///
     /// ```
@@ -126,16 +282,48 @@ impl Exchange {
         &self.real_time.to_name
     }
 
+    /// get bid price. Returns None if response does not contain bid price
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let exchange = api.exchange("BTC", "EUR").json().await.unwrap();
+    ///     if let Some(spread) = exchange.spread() {
+    ///         println!("spread is {spread}");
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn bid(&self) -> Option<f64> {
+        self.real_time.bid_price.map(|bid| bid.value)
+    }
+
+    /// get ask price. Return None if response does not contain ask price
+    #[must_use]
+    pub fn ask(&self) -> Option<f64> {
+        self.real_time.ask_price.map(|ask| ask.value)
+    }
+
+    /// get spread between ask and bid price. Return None if either price is
+    /// not present
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.ask()? - self.bid()?)
+    }
+
     /// get bid price. Returns None if no bid price
+    #[deprecated(note = "use `Exchange::bid` instead")]
     #[must_use]
     pub fn bid_price(&self) -> Option<f64> {
-        self.real_time.bid_price.trim().parse::<f64>().ok()
+        self.bid()
     }
 
     /// get ask price. Return None if no ask price
+    #[deprecated(note = "use `Exchange::ask` instead")]
     #[must_use]
     pub fn ask_price(&self) -> Option<f64> {
-        self.real_time.ask_price.trim().parse::<f64>().ok()
+        self.ask()
     }
 }
 
@@ -155,6 +343,9 @@ pub(crate) struct ExchangeHelper {
 impl ExchangeHelper {
     fn convert(self) -> Result<Exchange> {
         let mut exchange = Exchange::default();
+        if let Some(error) = self.error_message.as_deref().and_then(invalid_currency) {
+            return Err(error);
+        }
         detect_common_helper_error(self.information, self.error_message, self.note)?;
         if self.real_time.is_none() {
             return Err(Error::EmptyResponse);
@@ -164,30 +355,518 @@ impl ExchangeHelper {
     }
 }
 
+/// Detect error message which reports invalid `from_currency` or
+/// `to_currency`. Message which does not name exactly one field followed by
+/// code is not considered as invalid currency error
+fn invalid_currency(message: &str) -> Option<Error> {
+    if !message.to_ascii_lowercase().contains("invalid") {
+        return None;
+    }
+    let mut found = [
+        ("from_currency", FromOrTo::From),
+        ("to_currency", FromOrTo::To),
+    ]
+    .into_iter()
+    .filter_map(|(field, which)| {
+        let (index, _) = message.match_indices(field).find(|(index, _)| {
+            message[..*index]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_ascii_alphanumeric() && c != '_')
+        })?;
+        let code = message[index + field.len()..]
+            .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, ':' | '=' | '"' | '\''))
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .next()?;
+        (!code.is_empty()).then(|| (which, code.to_string()))
+    });
+    let (which, code) = found.next()?;
+    if found.next().is_some() {
+        return None;
+    }
+    Some(Error::InvalidCurrency { which, code })
+}
+
 /// Builder to create `Exchange`
 pub struct ExchangeBuilder<'a> {
     api_client: &'a ApiClient,
-    from_currency: &'a str,
-    to_currency: &'a str,
+    provider: Option<Provider>,
+    from_currency: CurrencyCode,
+    to_currency: CurrencyCode,
 }
 
 impl<'a> ExchangeBuilder<'a> {
-    crate::json_data_struct!(Exchange, ExchangeHelper);
+    crate::json_data_struct!(Exchange, ExchangeHelper, validate = validate);
 
     /// Create new `ExchangeBuilder` from `APIClient`
     #[must_use]
-    pub fn new(api_client: &'a ApiClient, from_currency: &'a str, to_currency: &'a str) -> Self {
+    pub fn new(
+        api_client: &'a ApiClient,
+        from_currency: impl Into<CurrencyCode>,
+        to_currency: impl Into<CurrencyCode>,
+    ) -> Self {
         Self {
             api_client,
-            from_currency,
-            to_currency,
+            provider: None,
+            from_currency: from_currency.into(),
+            to_currency: to_currency.into(),
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.from_currency.validate()?;
+        self.to_currency.validate()
+    }
+
     fn create_url(&self) -> String {
         format!(
             "query?function=CURRENCY_EXCHANGE_RATE&from_currency={}&to_currency={}",
-            self.from_currency, self.to_currency
+            encode_query_value(self.from_currency.as_str()),
+            encode_query_value(self.to_currency.as_str())
         )
     }
 }
+
+/// Struct for storing exchange rate derived from two exchange rate against
+/// common currency
+#[derive(Debug, Default)]
+pub struct CrossRate {
+    rate: f64,
+    from_leg: Exchange,
+    to_leg: Exchange,
+}
+
+impl CrossRate {
+    /// Return implied exchange rate calculated from both legs
+    #[must_use]
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Return exchange rate of from currency against common currency
+    #[must_use]
+    pub fn from_leg(&self) -> &Exchange {
+        &self.from_leg
+    }
+
+    /// Return exchange rate of to currency against common currency
+    #[must_use]
+    pub fn to_leg(&self) -> &Exchange {
+        &self.to_leg
+    }
+
+    /// Return absolute difference between refresh time of both legs. Large
+    /// skew means implied rate is calculated from quotes of different time
+    ///
+    /// # Errors
+    /// Raise error if refresh time of any leg cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn skew(&self) -> Result<Duration> {
+        Ok((self.from_leg.refreshed_at()? - self.to_leg.refreshed_at()?).abs())
+    }
+}
+
+/// Enum for kind of currency which decides API used for historical exchange
+/// rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurrencyKind {
+    /// physical currency such as USD, historical rate is fetched from
+    /// `FX_DAILY`
+    Physical,
+    /// digital currency such as BTC, historical rate is fetched from
+    /// `DIGITAL_CURRENCY_DAILY`
+    Digital,
+}
+
+impl CurrencyKind {
+    /// Guess kind of currency from code. Well known digital currency codes
+    /// such as BTC and ETH are considered digital and all other codes are
+    /// considered physical
+    ///
+    /// ```
+    /// use alpha_vantage::exchange::CurrencyKind;
+    ///
+    /// assert_eq!(CurrencyKind::guess("btc"), CurrencyKind::Digital);
+    /// assert_eq!(CurrencyKind::guess("EUR"), CurrencyKind::Physical);
+    /// ```
+    #[must_use]
+    pub fn guess(code: &str) -> Self {
+        let code = code.trim();
+        if DIGITAL_CURRENCIES
+            .iter()
+            .any(|digital| digital.eq_ignore_ascii_case(code))
+        {
+            CurrencyKind::Digital
+        } else {
+            CurrencyKind::Physical
+        }
+    }
+}
+
+/// Currency code such as `USD` or `BTC` stored in uppercase
+///
+/// Parsing with [`str::parse`] trims whitespace, uppercases code and
+/// validates that code contains 2 to 10 ASCII alphanumeric characters.
+/// Converting with `From` only trims and uppercases code so methods which
+/// accept `impl Into<CurrencyCode>` still accept plain string, code converted
+/// that way is validated by builder before request
+///
+/// ```
+/// use alpha_vantage::exchange::CurrencyCode;
+///
+/// let code: CurrencyCode = " eur ".parse().unwrap();
+/// assert_eq!(code.as_str(), "EUR");
+/// assert!("EU RO".parse::<CurrencyCode>().is_err());
+/// assert!(CurrencyCode::from("EU RO").validate().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurrencyCode(String);
+
+impl CurrencyCode {
+    /// Return currency code as str
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return guessed kind of currency using [`CurrencyKind::guess`]
+    #[must_use]
+    pub fn kind(&self) -> CurrencyKind {
+        CurrencyKind::guess(&self.0)
+    }
+
+    /// Validate that currency code contains 2 to 10 ASCII alphanumeric
+    /// characters same as parsing with [`str::parse`]
+    ///
+    /// # Errors
+    /// Raise error if currency code is not valid
+    pub fn validate(&self) -> Result<()> {
+        if (2..=10).contains(&self.0.len()) && self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+            Ok(())
+        } else {
+            Err(Error::InvalidCurrencyCode(self.0.clone()))
+        }
+    }
+
+    /// Validate that currency code is present in list of currency fetched
+    /// using [`ApiClient::currency_list`]
+    ///
+    /// # Errors
+    /// Raise error if currency code is not present in list
+    pub fn validate_against(&self, currencies: &[Currency]) -> Result<()> {
+        if currencies
+            .iter()
+            .any(|currency| currency.code.eq_ignore_ascii_case(&self.0))
+        {
+            Ok(())
+        } else {
+            Err(Error::InvalidCurrencyCode(self.0.clone()))
+        }
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let code = Self::from(s);
+        code.validate()
+            .map_err(|_| Error::InvalidCurrencyCode(s.to_string()))?;
+        Ok(code)
+    }
+}
+
+impl From<&str> for CurrencyCode {
+    fn from(code: &str) -> Self {
+        Self(code.trim().to_ascii_uppercase())
+    }
+}
+
+impl From<&String> for CurrencyCode {
+    fn from(code: &String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(code: String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl From<&CurrencyCode> for CurrencyCode {
+    fn from(code: &CurrencyCode) -> Self {
+        code.clone()
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Struct for storing currency present in physical or digital currency list
+#[derive(Clone, Debug, Deserialize)]
+pub struct Currency {
+    #[serde(rename = "currency code")]
+    code: String,
+    #[serde(rename = "currency name")]
+    name: String,
+}
+
+impl Currency {
+    /// Return currency code
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Return currency name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Get close rate on date or nearest previous date using daily series of
+/// currency kind
+pub(crate) async fn exchange_on(
+    api_client: &ApiClient,
+    from_currency: &str,
+    to_currency: &str,
+    date: &str,
+    kind: CurrencyKind,
+) -> Result<f64> {
+    let (function, closes) = match kind {
+        CurrencyKind::Physical => {
+            let closes = api_client
+                .forex(ForexFunction::Daily, from_currency, to_currency)
+                .output_size(OutputSize::Full)
+                .json()
+                .await
+                .map(|forex| {
+                    forex
+                        .data()
+                        .iter()
+                        .map(|data| (data.time().to_string(), data.close()))
+                        .collect::<Vec<_>>()
+                });
+            ("FX_DAILY", closes)
+        }
+        CurrencyKind::Digital => {
+            let closes = api_client
+                .crypto(CryptoFunction::Daily, from_currency, to_currency)
+                .json()
+                .await
+                .map(|crypto| {
+                    crypto
+                        .data()
+                        .iter()
+                        .map(|data| (data.time().to_string(), data.close()))
+                        .collect::<Vec<_>>()
+                });
+            ("DIGITAL_CURRENCY_DAILY", closes)
+        }
+    };
+    closes
+        .and_then(|closes| close_on_or_before(closes, date))
+        .map_err(|source| Error::Endpoint {
+            function: function.to_string(),
+            source: Box::new(source),
+        })
+}
+
+/// Find close of latest date which is on or before date
+fn close_on_or_before(closes: Vec<(String, f64)>, date: &str) -> Result<f64> {
+    let date = date.trim();
+    let is_date = date.len() == 10
+        && date.char_indices().all(|(index, character)| {
+            if index == 4 || index == 7 {
+                character == '-'
+            } else {
+                character.is_ascii_digit()
+            }
+        });
+    if !is_date {
+        return Err(Error::ParseDateTime(date.to_string()));
+    }
+    closes
+        .into_iter()
+        .filter(|(time, _)| time.get(..10).is_some_and(|day| day <= date))
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, close)| close)
+        .ok_or_else(|| Error::NoDataForDate(date.to_string()))
+}
+
+/// Get cross exchange rate of currencies using exchange rate of both
+/// currencies against common currency fetched concurrently
+pub(crate) async fn cross_exchange(
+    api_client: &ApiClient,
+    from_currency: &str,
+    to_currency: &str,
+    common_currency: &str,
+) -> Result<CrossRate> {
+    let leg_error = |from: &str, source: Error| Error::ExchangeLeg {
+        from_currency: from.to_string(),
+        to_currency: common_currency.to_string(),
+        source: Box::new(source),
+    };
+    let (from_leg, to_leg) = futures::future::join(
+        api_client.exchange(from_currency, common_currency).json(),
+        api_client.exchange(to_currency, common_currency).json(),
+    )
+    .await;
+    let from_leg = from_leg.map_err(|source| leg_error(from_currency, source))?;
+    let to_leg = to_leg.map_err(|source| leg_error(to_currency, source))?;
+    let rate = to_leg
+        .convert_inverse(from_leg.rate())
+        .ok_or_else(|| leg_error(to_currency, Error::AlphaVantageInvalidData))?;
+    Ok(CrossRate {
+        rate,
+        from_leg,
+        to_leg,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrencyCode, Exchange};
+    use crate::error::Error;
+    use crate::test_utils::FixtureClient;
+
+    async fn fetch(path: &str) -> Exchange {
+        crate::set_api("demo", FixtureClient::new(path))
+            .exchange("BTC", "EUR")
+            .json()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn inverse_rate_of_small_and_zero_rate() {
+        let rate = 0.000_000_000_36;
+        let exchange = fetch("exchange/shib_btc.json").await;
+        assert!((exchange.rate() - rate).abs() < f64::EPSILON * rate);
+        assert_eq!(
+            exchange.inverse_rate().unwrap().to_bits(),
+            (1.0 / rate).to_bits()
+        );
+        assert!((exchange.convert(1_000_000_000.0) - 0.36).abs() < 1e-12);
+
+        // amount is divided by rate instead of multiplied by inverse rate
+        let amount = 0.000_012_345_678_9;
+        let shib = exchange.convert_inverse(amount).unwrap();
+        assert_eq!(shib.to_bits(), (amount / rate).to_bits());
+        assert!((exchange.convert(shib) - amount).abs() <= f64::EPSILON * amount);
+
+        let exchange = fetch("exchange/zero_rate.json").await;
+        assert!(exchange.convert(2.5).abs() < f64::EPSILON);
+        assert!(exchange.inverse_rate().is_none());
+        assert!(exchange.convert_inverse(2.5).is_none());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[tokio::test]
+    async fn decimal_rate_is_lossless() {
+        use rust_decimal::Decimal;
+
+        let exchange = fetch("exchange/precise.json").await;
+        assert_eq!(exchange.rate_decimal().to_string(), "0.00001234567891");
+        assert_eq!(
+            exchange.bid_decimal().unwrap().to_string(),
+            "0.00001234560000"
+        );
+        assert_eq!(exchange.ask_decimal(), None);
+
+        // f64 rate does not round trip to exact decimal rate
+        let from_f64 = Decimal::from_f64_retain(exchange.rate()).unwrap();
+        assert_ne!(from_f64, exchange.rate_decimal());
+
+        let amount = Decimal::from(1_000_000_000_000_u64);
+        assert_eq!(
+            exchange
+                .convert_decimal(amount)
+                .unwrap()
+                .normalize()
+                .to_string(),
+            "12345678.91"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn refreshed_at_and_age() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let exchange = fetch("exchange/btc_eur.json").await;
+        let refreshed_at = Utc.with_ymd_and_hms(2024, 2, 16, 14, 31, 2).unwrap();
+        assert_eq!(exchange.refreshed_at().unwrap(), refreshed_at);
+        let now = Utc.with_ymd_and_hms(2024, 2, 16, 14, 36, 2).unwrap();
+        assert_eq!(exchange.age(now).unwrap(), Duration::minutes(5));
+
+        let exchange = fetch("exchange/malformed_refreshed.json").await;
+        assert!(matches!(
+            exchange.refreshed_at(),
+            Err(Error::ParseDateTime(time)) if time == "2024-02-16T14:31"
+        ));
+
+        let exchange = fetch("exchange/unknown_time_zone.json").await;
+        assert!(matches!(
+            exchange.age(now),
+            Err(Error::UnsupportedTimeZone(time_zone)) if time_zone == "US/Eastern"
+        ));
+    }
+
+    #[tokio::test]
+    async fn bid_ask_and_spread() {
+        let exchange = fetch("exchange/btc_eur.json").await;
+        assert_eq!(
+            exchange.bid().map(|bid| bid.to_string()).as_deref(),
+            Some("48150.1")
+        );
+        assert_eq!(
+            exchange.ask().map(|ask| ask.to_string()).as_deref(),
+            Some("48160.35")
+        );
+        assert!((exchange.spread().unwrap() - 10.25).abs() < 1e-9);
+
+        let exchange = fetch("exchange/without_bid_ask.json").await;
+        assert_eq!(exchange.rate().to_string(), "150.123");
+        assert!(exchange.bid().is_none());
+        assert!(exchange.ask().is_none());
+        assert!(exchange.spread().is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_currency_code_is_not_requested() {
+        let code: CurrencyCode = " eur ".parse().unwrap();
+        assert_eq!(code.as_str(), "EUR");
+        assert_eq!(CurrencyCode::from("btc "), "BTC".parse().unwrap());
+        for invalid in ["EU RO", "B$C", "X", "   "] {
+            assert!(invalid.parse::<CurrencyCode>().is_err());
+        }
+
+        let client = FixtureClient::new("exchange/btc_eur.json");
+        let api = crate::set_api("demo", client.clone());
+        let error = api.exchange("USD", "EU RO").json().await.unwrap_err();
+        assert!(matches!(error, Error::InvalidCurrencyCode(code) if code == "EU RO"));
+        assert_eq!(client.calls(), 0);
+    }
+}
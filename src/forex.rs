@@ -10,12 +10,14 @@
 
 use std::cmp;
 use std::collections::HashMap;
+use std::fmt::Write;
 
 use serde::Deserialize;
 
-use crate::api::{ApiClient, OutputSize, TimeSeriesInterval};
+use crate::api::{encode_query_value, ApiClient, OutputSize, TimeSeriesInterval, Provider};
 use crate::deserialize::from_str;
 use crate::error::{detect_common_helper_error, Error, Result};
+use crate::exchange::CurrencyCode;
 use crate::vec_trait::FindData;
 
 /// Struct used to store metadata value
@@ -277,7 +279,7 @@ impl ForexHelper {
         let mut last_refreshed = meta_data.get("4. Last Refreshed");
         if last_refreshed.is_none() {
             last_refreshed = meta_data.get("5. Last Refreshed");
-        };
+        }
 
         let time_zone_value = meta_data.get("5. Time Zone").unwrap_or_else(|| {
             meta_data.get("6. Time Zone").unwrap_or_else(|| {
@@ -295,15 +297,15 @@ impl ForexHelper {
         let interval = meta_data.get("5. Interval");
 
         let meta_data = MetaData {
-            information: information.to_string(),
-            from_symbol: from_symbol.to_string(),
-            to_symbol: to_symbol.to_string(),
+            information: information.clone(),
+            from_symbol: from_symbol.clone(),
+            to_symbol: to_symbol.clone(),
             last_refreshed: last_refreshed
                 .expect("last refreshed value contains None")
-                .to_string(),
+                .clone(),
             interval: interval.map(ToString::to_string),
             output_size: output_size_value.map(ToString::to_string),
-            time_zone: time_zone_value.to_string(),
+            time_zone: time_zone_value.clone(),
         };
         let mut data_entries: Vec<Data> = Vec::new();
         for hash in self.forex.unwrap().values() {
@@ -313,7 +315,7 @@ impl ForexHelper {
                     .expect("failed to get value from Forex hashmap");
 
                 data_entries.push(Data {
-                    time: val.to_string(),
+                    time: val.clone(),
                     open: data_helper.open,
                     high: data_helper.high,
                     low: data_helper.low,
@@ -330,12 +332,10 @@ impl ForexHelper {
 }
 
 impl FindData for Vec<Data> {
-    #[must_use]
     fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
         self.iter().find(|&data| data.time == time)
     }
 
-    #[must_use]
     fn latest(&self) -> <Self as IntoIterator>::Item {
         let mut latest = &Data::default();
         for data in self {
@@ -367,34 +367,46 @@ impl FindData for Vec<Data> {
 /// Builder to create `Forex`
 pub struct ForexBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     function: ForexFunction,
-    from_symbol: &'a str,
-    to_symbol: &'a str,
+    from_symbol: CurrencyCode,
+    to_symbol: CurrencyCode,
     interval: Option<TimeSeriesInterval>,
     output_size: Option<OutputSize>,
 }
 
 impl<'a> ForexBuilder<'a> {
-    crate::json_data_struct!(Forex, ForexHelper);
+    crate::json_data_struct!(Forex, ForexHelper, validate = validate);
 
     /// Create new `ForexBuilder` from `APIClient`
     #[must_use]
     pub fn new(
         api_client: &'a ApiClient,
         function: ForexFunction,
-        from_symbol: &'a str,
-        to_symbol: &'a str,
+        from_symbol: impl Into<CurrencyCode>,
+        to_symbol: impl Into<CurrencyCode>,
     ) -> Self {
         Self {
             api_client,
+            provider: None,
             function,
-            from_symbol,
-            to_symbol,
+            from_symbol: from_symbol.into(),
+            to_symbol: to_symbol.into(),
             interval: None,
             output_size: None,
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
     /// Define time series interval for forex
     #[must_use]
     pub fn interval(mut self, interval: TimeSeriesInterval) -> Self {
@@ -409,6 +421,11 @@ impl<'a> ForexBuilder<'a> {
         self
     }
 
+    fn validate(&self) -> Result<()> {
+        self.from_symbol.validate()?;
+        self.to_symbol.validate()
+    }
+
     fn create_url(&self) -> String {
         let function = match self.function {
             ForexFunction::IntraDay => "FX_INTRADAY",
@@ -419,7 +436,9 @@ impl<'a> ForexBuilder<'a> {
 
         let mut url = format!(
             "query?function={}&from_symbol={}&to_symbol={}",
-            function, self.from_symbol, self.to_symbol
+            function,
+            encode_query_value(self.from_symbol.as_str()),
+            encode_query_value(self.to_symbol.as_str())
         );
 
         if let Some(forex_interval) = &self.interval {
@@ -430,15 +449,21 @@ impl<'a> ForexBuilder<'a> {
                 TimeSeriesInterval::ThirtyMin => "30min",
                 TimeSeriesInterval::SixtyMin => "60min",
             };
-            url.push_str(&format!("&interval={interval}"));
-        };
+            write!(url, "&interval={interval}").unwrap();
+        }
 
-        if let Some(forex_output_size) = &self.output_size {
+        let default_output_size = match self.function {
+            ForexFunction::IntraDay | ForexFunction::Daily => {
+                self.api_client.defaults().output_size.as_ref()
+            }
+            ForexFunction::Weekly | ForexFunction::Monthly => None,
+        };
+        if let Some(forex_output_size) = self.output_size.as_ref().or(default_output_size) {
             let size = match forex_output_size {
                 OutputSize::Full => "full",
                 OutputSize::Compact => "compact",
             };
-            url.push_str(&format!("&outputsize={size}"));
+            write!(url, "&outputsize={size}").unwrap();
         }
 
         url
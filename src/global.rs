@@ -0,0 +1,171 @@
+//! Module for process wide `ApiClient` which can be used through free
+//! functions instead of passing client around, which is handy for examples
+//! and quick scripts
+//!
+//! Client can be initialized only once with [`init`] or [`init_with`], later
+//! initialization raises [`Error::GlobalClientAlreadyInitialized`] and keeps
+//! client which is already set. Free functions raise
+//! [`Error::GlobalClientNotInitialized`] if they are called before client is
+//! initialized. Client is shared by every thread so it can be used
+//! concurrently.
+//!
+//! ```no_run
+//! use alpha_vantage::global;
+//! use alpha_vantage::stock_time::StockFunction;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     global::init("demo", reqwest::Client::new()).unwrap();
+//!
+//!     let quote = global::quote("MSFT").unwrap().json().await.unwrap();
+//!     println!("{}", quote.price());
+//!
+//!     let stock = global::stock_time(StockFunction::Daily, "IBM")
+//!         .unwrap()
+//!         .json()
+//!         .await
+//!         .unwrap();
+//!     println!("{}", stock.symbol());
+//! }
+//! ```
+use std::sync::OnceLock;
+
+use crate::api::ApiClient;
+use crate::client::HttpClient;
+use crate::error::{Error, Result};
+use crate::exchange::{CurrencyCode, ExchangeBuilder};
+use crate::quote::QuoteBuilder;
+use crate::stock_time::{StockFunction, TimeSeriesBuilder};
+
+static CLIENT: OnceLock<ApiClient> = OnceLock::new();
+
+/// Initialize global client with API key and HTTP client. See
+/// [`set_api`](crate::set_api)
+///
+/// # Errors
+/// Raise error if global client is already initialized
+pub fn init<S, T>(api_key: S, client: T) -> Result<()>
+where
+    S: Into<String>,
+    T: HttpClient + 'static + Send + Sync,
+{
+    init_with(ApiClient::set_api(api_key, client))
+}
+
+/// Initialize global client with already configured `ApiClient`, which can
+/// be used to set provider, cache or any other option of client
+///
+/// # Errors
+/// Raise error if global client is already initialized
+pub fn init_with(api_client: ApiClient) -> Result<()> {
+    CLIENT
+        .set(api_client)
+        .map_err(|_| Error::GlobalClientAlreadyInitialized)
+}
+
+/// Return global client, which can be used for any request not covered by
+/// free functions of module
+///
+/// # Errors
+/// Raise error if global client is not initialized
+pub fn client() -> Result<&'static ApiClient> {
+    CLIENT.get().ok_or(Error::GlobalClientNotInitialized)
+}
+
+/// Create quote builder using global client. See [`ApiClient::quote`]
+///
+/// # Errors
+/// Raise error if global client is not initialized
+pub fn quote(symbol: &str) -> Result<QuoteBuilder<'_>> {
+    Ok(client()?.quote(symbol))
+}
+
+/// Create stock time builder using global client. See
+/// [`ApiClient::stock_time`]
+///
+/// # Errors
+/// Raise error if global client is not initialized
+pub fn stock_time(function: StockFunction, symbol: &str) -> Result<TimeSeriesBuilder<'_>> {
+    Ok(client()?.stock_time(function, symbol))
+}
+
+/// Create exchange builder using global client. See
+/// [`ApiClient::exchange`]
+///
+/// # Errors
+/// Raise error if global client is not initialized
+pub fn exchange(
+    from_currency: impl Into<CurrencyCode>,
+    to_currency: impl Into<CurrencyCode>,
+) -> Result<ExchangeBuilder<'static>> {
+    Ok(client()?.exchange(from_currency, to_currency))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::stock_time::StockFunction;
+    use crate::test_utils::FixtureClient;
+
+    // global client is set once per process so every check is done in single
+    // test
+    #[tokio::test]
+    async fn init_once_and_share() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route("function=TIME_SERIES_DAILY", "stock_time/daily_ibm.json")
+            .route("function=CURRENCY_EXCHANGE_RATE", "exchange/btc_usd.json");
+
+        // client is not initialized yet
+        assert!(matches!(
+            super::quote("MSFT"),
+            Err(Error::GlobalClientNotInitialized)
+        ));
+        assert!(matches!(
+            super::client(),
+            Err(Error::GlobalClientNotInitialized)
+        ));
+
+        // only one of concurrent initialization succeeds
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| super::init("demo", client.clone())))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        assert!(
+            results
+                .iter()
+                .filter_map(|result| result.as_ref().err())
+                .all(|error| matches!(error, Error::GlobalClientAlreadyInitialized))
+        );
+        assert!(matches!(
+            super::init("other", client.clone()),
+            Err(Error::GlobalClientAlreadyInitialized)
+        ));
+        assert_eq!(super::client().unwrap().get_api_key(), "demo");
+
+        // initialized client is shared by concurrent tasks
+        let tasks: Vec<_> = (0..8)
+            .map(|_| tokio::spawn(async { super::quote("MSFT")?.json().await }))
+            .collect();
+        for task in tasks {
+            let quote = task.await.unwrap().unwrap();
+            assert_eq!(quote.symbol(), "MSFT");
+        }
+
+        let stock = super::stock_time(StockFunction::Daily, "IBM")
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(stock.symbol(), "IBM");
+
+        let exchange = super::exchange("BTC", "USD").unwrap().json().await.unwrap();
+        assert_eq!(exchange.rate().to_string(), "52000");
+        assert_eq!(client.calls(), 10);
+    }
+}
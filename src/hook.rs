@@ -0,0 +1,198 @@
+//! Module for observing requests sent by `ApiClient`
+//!
+//! Hooks registered with [`ApiClient::on_request`] and
+//! [`ApiClient::on_response`] are called for every request sent to server,
+//! including retried requests and requests which fail. Response served from
+//! cache is not sent to server so hooks are not called for it.
+//!
+//! With `tracing` feature, JSON and CSV requests are also instrumented with
+//! `alpha_vantage_request` span which records function, symbol like
+//! parameters and provider of request. Events are emitted when request is
+//! sent, rate limit is reached, request is retried or output cannot be
+//! decoded. API key is redacted from every span and event.
+//!
+//! ```no_run
+//! use alpha_vantage::forex::ForexFunction;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     tracing_subscriber::fmt().init();
+//!
+//!     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+//!         .on_request(Box::new(|info| println!("sending {}", info.url())))
+//!         .on_response(Box::new(|info| {
+//!             println!("{} took {:?}", info.function(), info.elapsed());
+//!         }));
+//!     api.forex(ForexFunction::Daily, "EUR", "USD")
+//!         .json()
+//!         .await
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! [`ApiClient::on_request`]: crate::api::ApiClient::on_request
+//! [`ApiClient::on_response`]: crate::api::ApiClient::on_response
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Hook which is called before request is sent
+pub type RequestHook = Box<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// Hook which is called after response is received or request fails
+pub type ResponseHook = Box<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Request hook shared by clones of `ApiClient`
+pub(crate) type SharedRequestHook = Arc<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// Response hook shared by clones of `ApiClient`
+pub(crate) type SharedResponseHook = Arc<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Information of request passed to [`RequestHook`]
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    function: String,
+    url: String,
+}
+
+impl RequestInfo {
+    pub(crate) fn new(function: &str, url: String) -> Self {
+        Self {
+            function: function.to_string(),
+            url,
+        }
+    }
+
+    /// Return name of API function of request, empty if request does not
+    /// contain function
+    #[must_use]
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    /// Return url of request where API key is redacted
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Information of response passed to [`ResponseHook`]
+#[derive(Debug, Clone)]
+pub struct ResponseInfo {
+    function: String,
+    elapsed: Duration,
+    body_length: Option<usize>,
+    api_error: bool,
+}
+
+impl ResponseInfo {
+    pub(crate) fn new(
+        function: &str,
+        elapsed: Duration,
+        body_length: Option<usize>,
+        api_error: bool,
+    ) -> Self {
+        Self {
+            function: function.to_string(),
+            elapsed,
+            body_length,
+            api_error,
+        }
+    }
+
+    /// Return name of API function of request, empty if request does not
+    /// contain function
+    #[must_use]
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    /// Return time elapsed between sending request and receiving response
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Return length of body of response in bytes, 0 if request failed
+    #[must_use]
+    pub fn body_length(&self) -> usize {
+        self.body_length.unwrap_or_default()
+    }
+
+    /// Check whether request failed to get output from server
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        self.body_length.is_none()
+    }
+
+    /// Check whether API returned information, error message or note instead
+    /// of data
+    #[must_use]
+    pub fn is_api_error(&self) -> bool {
+        self.api_error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    use crate::forex::ForexFunction;
+    use crate::test_utils::FixtureClient;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn tracing_redacts_api_key() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let _guard = tracing_subscriber::fmt()
+            .with_max_level(tracing_subscriber::filter::LevelFilter::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .set_default();
+
+        let client = FixtureClient::new("forex/fx_daily.json")
+            .route_status("symbol=BROKEN", 200, r#"{"Global Quote": ["#)
+            .route("symbol=LIMIT", "error/rate_limit.json");
+        let api = crate::set_api("secret-key", client);
+        api.forex(ForexFunction::Daily, "EUR", "USD")
+            .json()
+            .await
+            .unwrap();
+        api.quote("LIMIT").json().await.unwrap_err();
+        api.quote("BROKEN").json().await.unwrap_err();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        #[cfg(feature = "tracing")]
+        {
+            assert!(output.contains(
+                "alpha_vantage_request{function=\"FX_DAILY\" symbol=\"EUR/USD\" provider=\"AlphaVantage\"}"
+            ));
+            assert!(output.contains("&apikey=[REDACTED]"));
+            assert!(output.contains(
+                "alpha_vantage_request{function=\"GLOBAL_QUOTE\" symbol=\"LIMIT\" provider=\"AlphaVantage\"}"
+            ));
+            assert!(output.contains("rate limit of API reached"));
+            assert!(output.contains("failed to decode JSON output"));
+        }
+        #[cfg(not(feature = "tracing"))]
+        assert!(output.is_empty());
+        assert!(!output.contains("secret-key"));
+    }
+}
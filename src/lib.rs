@@ -13,6 +13,15 @@
 /// requesting through that API
 pub mod api;
 
+pub mod batch;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+pub mod cache;
+
+pub mod calendar;
+
 /// Module which provides trait to implement own client as well as default
 /// client in project
 pub mod client;
@@ -34,14 +43,37 @@ pub mod exchange;
 
 pub mod forex;
 
+pub mod global;
+
+pub mod hook;
+
+pub mod metrics;
+
+pub mod middleware;
+
+#[cfg(feature = "local-indicators")]
+pub mod local_indicator;
+
 pub mod quote;
 
+#[cfg(feature = "rate-limit")]
+pub mod rate_limit;
+
+#[cfg(feature = "record")]
+pub mod record;
+
+#[cfg(feature = "retry")]
+pub mod retry;
+
 pub mod search;
 
 pub mod stock_time;
 
 pub mod technical_indicator;
 
+#[cfg(test)]
+mod test_utils;
+
 pub mod vec_trait;
 
 pub use self::api::ApiClient;
@@ -85,8 +117,42 @@ macro_rules! json_data_struct {
         /// API returns any 4 possible known errors
         pub async fn json(&self) -> Result<$output> {
             let url = self.create_url();
-            let helper: $helper = self.api_client.get_json(&url).await?;
-            helper.convert()
+            self.api_client
+                .with_provider_override(self.provider.as_ref())?
+                .get_json_with(&url, None, $helper::convert)
+                .await
+        }
+    };
+    ($output:ident, $helper:ident, validate = $validate:ident) => {
+        /// Returns JSON data
+        ///
+        /// # Errors
+        /// Raise error if parameters are not valid, data obtained cannot be
+        /// properly converted to struct or API returns any 4 possible known
+        /// errors
+        pub async fn json(&self) -> Result<$output> {
+            self.$validate()?;
+            let url = self.create_url();
+            self.api_client
+                .with_provider_override(self.provider.as_ref())?
+                .get_json_with(&url, None, $helper::convert)
+                .await
+        }
+    };
+    ($output:ident, $helper:ident, $ttl:ident) => {
+        /// Returns JSON data. If cache is set for client, response which is
+        /// not older than time to live of builder is served from cache
+        ///
+        /// # Errors
+        /// Raise error if data obtained cannot be properly converted to struct or
+        /// API returns any 4 possible known errors
+        pub async fn json(&self) -> Result<$output> {
+            let url = self.create_url();
+            let ttl = Some(self.$ttl());
+            self.api_client
+                .with_provider_override(self.provider.as_ref())?
+                .get_json_with(&url, ttl, $helper::convert)
+                .await
         }
     };
 }
@@ -0,0 +1,486 @@
+//! Module for computing technical indicator locally
+//!
+//! Technical indicators can be computed from time series which is already
+//! fetched instead of requesting them from API so API quota is not used. Value
+//! of each function is aligned with input values and is None during warm up
+//! period of indicator.
+//!
+//! [`TimeSeries::indicator`] returns data in same form as technical indicator
+//! API so that typed structs such as `SingleValueIndicator` or `MacdIndicator`
+//! can be created in same way for local and remote data.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::stock_time::TimeSeries;
+use crate::technical_indicator::{
+    DataCollector, IndicatorSpec, TechnicalIndicator, TechnicalIndicatorFunction,
+};
+
+/// Default fast period of MACD used by API
+const MACD_FAST_PERIOD: u64 = 12;
+
+/// Default slow period of MACD used by API
+const MACD_SLOW_PERIOD: u64 = 26;
+
+/// Default signal period of MACD used by API
+const MACD_SIGNAL_PERIOD: u64 = 9;
+
+/// Compute simple moving average of values over n period
+///
+/// ```
+/// use alpha_vantage::local_indicator::sma;
+///
+/// let sma = sma(&[1.0, 2.0, 3.0, 4.0], 3);
+/// assert_eq!(sma, [None, None, Some(2.0), Some(3.0)]);
+/// ```
+#[must_use]
+pub fn sma(values: &[f64], n: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if n == 0 {
+        return output;
+    }
+    let mut sum = 0.0;
+    for (index, value) in values.iter().enumerate() {
+        sum += value;
+        if index >= n {
+            sum -= values[index - n];
+        }
+        if index + 1 >= n {
+            output[index] = Some(sum / period(n));
+        }
+    }
+    output
+}
+
+/// Compute exponential moving average of values over n period. First value
+/// is simple moving average of first n values
+///
+/// ```
+/// use alpha_vantage::local_indicator::ema;
+///
+/// let ema = ema(&[1.0, 2.0, 3.0, 4.0], 3);
+/// assert_eq!(ema, [None, None, Some(2.0), Some(3.0)]);
+/// ```
+#[must_use]
+pub fn ema(values: &[f64], n: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if n == 0 || values.len() < n {
+        return output;
+    }
+    let smoothing = 2.0 / (period(n) + 1.0);
+    let mut ema = values[..n].iter().sum::<f64>() / period(n);
+    output[n - 1] = Some(ema);
+    for (index, value) in values.iter().enumerate().skip(n) {
+        ema += (value - ema) * smoothing;
+        output[index] = Some(ema);
+    }
+    output
+}
+
+/// Compute relative strength index of values over n period using Wilder's
+/// smoothing. First value is present at index n
+///
+/// ```
+/// use alpha_vantage::local_indicator::rsi;
+///
+/// let rsi = rsi(&[1.0, 2.0, 1.0, 2.0, 3.0], 2);
+/// assert_eq!(rsi[..2], [None, None]);
+/// assert_eq!(rsi[2], Some(50.0));
+/// assert_eq!(rsi[4], Some(87.5));
+/// ```
+#[must_use]
+pub fn rsi(values: &[f64], n: usize) -> Vec<Option<f64>> {
+    let mut output = vec![None; values.len()];
+    if n == 0 || values.len() <= n {
+        return output;
+    }
+    let changes = values
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<_>>();
+    let mut gain = changes[..n]
+        .iter()
+        .map(|change| change.max(0.0))
+        .sum::<f64>()
+        / period(n);
+    let mut loss = changes[..n]
+        .iter()
+        .map(|change| (-change).max(0.0))
+        .sum::<f64>()
+        / period(n);
+    output[n] = Some(relative_strength_index(gain, loss));
+    for (index, change) in changes.iter().enumerate().skip(n) {
+        gain = (gain * (period(n) - 1.0) + change.max(0.0)) / period(n);
+        loss = (loss * (period(n) - 1.0) + (-change).max(0.0)) / period(n);
+        output[index + 1] = Some(relative_strength_index(gain, loss));
+    }
+    output
+}
+
+/// Compute moving average convergence / divergence of values. Returned value
+/// is tuple of MACD, signal and histogram which is present once signal line
+/// is warmed up
+///
+/// ```
+/// use alpha_vantage::local_indicator::macd;
+///
+/// let macd = macd(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3, 2);
+/// assert_eq!(macd[..3], [None, None, None]);
+/// let (macd_value, signal, histogram) = macd[3].unwrap();
+/// assert!((macd_value - 0.5).abs() < 1e-9);
+/// assert!((signal - 0.5).abs() < 1e-9);
+/// assert!(histogram.abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn macd(
+    values: &[f64],
+    fast: usize,
+    slow: usize,
+    signal: usize,
+) -> Vec<Option<(f64, f64, f64)>> {
+    let macd_line = ema(values, fast)
+        .into_iter()
+        .zip(ema(values, slow))
+        .map(|(fast, slow)| Some(fast? - slow?))
+        .collect::<Vec<_>>();
+    let Some(start) = macd_line.iter().position(Option::is_some) else {
+        return vec![None; values.len()];
+    };
+    let macd_values = macd_line[start..]
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    let signal_line = ema(&macd_values, signal);
+    macd_line
+        .iter()
+        .enumerate()
+        .map(|(index, macd)| {
+            let macd = (*macd)?;
+            let signal = (*signal_line.get(index.checked_sub(start)?)?)?;
+            Some((macd, signal, macd - signal))
+        })
+        .collect()
+}
+
+impl TimeSeries {
+    /// Compute technical indicator of spec from time series locally. SMA,
+    /// EMA, RSI and MACD are supported. Data is returned in same form as
+    /// technical indicator API without data of warm up period
+    ///
+    /// ```no_run
+    /// use alpha_vantage::stock_time::StockFunction;
+    /// use alpha_vantage::technical_indicator::{IndicatorSpec, SingleValueIndicator};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let series = api
+    ///         .stock_time(StockFunction::Daily, "IBM")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let spec = IndicatorSpec::new("SMA").time_period(20).series_type("close");
+    ///     let sma = SingleValueIndicator::try_from(series.indicator(&spec).unwrap()).unwrap();
+    ///     for (time, value) in sma.points() {
+    ///         println!("{time} {value}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if function cannot be computed locally, parameter required
+    /// by function is not set or series type is not valid
+    pub fn indicator(&self, spec: &IndicatorSpec) -> Result<TechnicalIndicator> {
+        let (function, params) = (&spec.function, &spec.params);
+        let series_type = params
+            .series_type
+            .as_deref()
+            .ok_or_else(|| missing_parameter("series_type", function))?;
+        let mut data = self.data().iter().collect::<Vec<_>>();
+        data.sort_by(|a, b| a.time().cmp(b.time()));
+        let values = data
+            .iter()
+            .map(|data| match series_type {
+                "open" => Ok(data.open()),
+                "high" => Ok(data.high()),
+                "low" => Ok(data.low()),
+                "close" => Ok(data.close()),
+                _ => Err(Error::InvalidParameter {
+                    parameter: "series_type".to_string(),
+                    value: series_type.to_string(),
+                }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut metadata = HashMap::new();
+        metadata.insert("1: Symbol".to_string(), Value::from(self.symbol()));
+        metadata.insert(
+            "3: Last Refreshed".to_string(),
+            Value::from(self.last_refreshed()),
+        );
+        metadata.insert(
+            "4: Interval".to_string(),
+            Value::from(self.indicator_interval()),
+        );
+        metadata.insert("6: Series Type".to_string(), Value::from(series_type));
+        metadata.insert("7: Time Zone".to_string(), Value::from(self.time_zone()));
+
+        let (name, rows) = match function {
+            TechnicalIndicatorFunction::Sma
+            | TechnicalIndicatorFunction::Ema
+            | TechnicalIndicatorFunction::Rsi => {
+                let time_period = params
+                    .time_period
+                    .ok_or_else(|| missing_parameter("time_period", function))?;
+                metadata.insert("5: Time Period".to_string(), Value::from(time_period));
+                let n = to_usize(time_period);
+                let (name, output) = match function {
+                    TechnicalIndicatorFunction::Sma => {
+                        ("Simple Moving Average (SMA)", sma(&values, n))
+                    }
+                    TechnicalIndicatorFunction::Ema => {
+                        ("Exponential Moving Average (EMA)", ema(&values, n))
+                    }
+                    _ => ("Relative Strength Index (RSI)", rsi(&values, n)),
+                };
+                let key = function.to_string();
+                let rows = output
+                    .into_iter()
+                    .map(|value| Some(HashMap::from([(key.clone(), value?)])))
+                    .collect::<Vec<_>>();
+                (name, rows)
+            }
+            TechnicalIndicatorFunction::Macd => {
+                let fast_period = params.fast_period.unwrap_or(MACD_FAST_PERIOD);
+                let slow_period = params.slow_period.unwrap_or(MACD_SLOW_PERIOD);
+                let signal_period = params.signal_period.unwrap_or(MACD_SIGNAL_PERIOD);
+                metadata.insert("5.1: Fast Period".to_string(), Value::from(fast_period));
+                metadata.insert("5.2: Slow Period".to_string(), Value::from(slow_period));
+                metadata.insert("5.3: Signal Period".to_string(), Value::from(signal_period));
+                let rows = macd(
+                    &values,
+                    to_usize(fast_period),
+                    to_usize(slow_period),
+                    to_usize(signal_period),
+                )
+                .into_iter()
+                .map(|value| {
+                    let (macd, signal, histogram) = value?;
+                    Some(HashMap::from([
+                        ("MACD".to_string(), macd),
+                        ("MACD_Signal".to_string(), signal),
+                        ("MACD_Hist".to_string(), histogram),
+                    ]))
+                })
+                .collect::<Vec<_>>();
+                ("Moving Average Convergence/Divergence (MACD)", rows)
+            }
+            _ => {
+                return Err(Error::UnsupportedParameter {
+                    parameter: "local computation".to_string(),
+                    function: function.to_string(),
+                });
+            }
+        };
+        metadata.insert("2: Indicator".to_string(), Value::from(name));
+
+        let points = data
+            .iter()
+            .zip(rows)
+            .filter_map(|(data, row)| Some(DataCollector::new(data.time().to_string(), row?)))
+            .collect();
+        Ok(TechnicalIndicator::from_points(
+            metadata,
+            format!("Technical Analysis: {function}"),
+            points,
+        ))
+    }
+
+    // Interval of time series in form used by technical indicator API
+    fn indicator_interval(&self) -> &str {
+        if let Some(interval) = self.interval() {
+            return interval;
+        }
+        let information = self.information().to_ascii_lowercase();
+        if information.contains("weekly") {
+            "weekly"
+        } else if information.contains("monthly") {
+            "monthly"
+        } else {
+            "daily"
+        }
+    }
+}
+
+// Create error for parameter which is required for local computation
+fn missing_parameter(parameter: &str, function: &TechnicalIndicatorFunction) -> Error {
+    Error::MissingParameter {
+        parameter: parameter.to_string(),
+        function: function.to_string(),
+    }
+}
+
+// Convert period to usize saturating to max value
+fn to_usize(period: u64) -> usize {
+    usize::try_from(period).unwrap_or(usize::MAX)
+}
+
+// Convert period to float for averaging
+#[allow(clippy::cast_precision_loss)]
+fn period(n: usize) -> f64 {
+    n as f64
+}
+
+// Relative strength index from average gain and average loss
+fn relative_strength_index(gain: f64, loss: f64) -> f64 {
+    if loss == 0.0 {
+        100.0
+    } else {
+        100.0 - 100.0 / (1.0 + gain / loss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::ApiClient;
+    use crate::error::Error;
+    use crate::stock_time::{StockFunction, TimeSeries};
+    use crate::technical_indicator::{
+        IndicatorSpec, MacdIndicator, SingleValueIndicator, TechnicalIndicatorInterval,
+    };
+    use crate::test_utils::FixtureClient;
+
+    // Client serving daily series of IBM and indicators computed by API from
+    // same series
+    async fn daily_ibm() -> (ApiClient, TimeSeries) {
+        let client = FixtureClient::new("stock_time/daily_ibm.json")
+            .route("function=SMA&", "technical_indicator/sma_20.json")
+            .route("function=EMA&", "technical_indicator/ema_10.json")
+            .route("function=RSI&", "technical_indicator/rsi_14.json")
+            .route("function=MACD&", "technical_indicator/macd_short.json");
+        let api = crate::set_api("demo", client);
+        let series = api
+            .stock_time(StockFunction::Daily, "IBM")
+            .json()
+            .await
+            .unwrap();
+        (api, series)
+    }
+
+    #[tokio::test]
+    async fn single_value_matches_remote() {
+        let (api, series) = daily_ibm().await;
+        let specs = [
+            IndicatorSpec::new("SMA")
+                .time_period(20)
+                .series_type("close"),
+            IndicatorSpec::new("EMA")
+                .time_period(10)
+                .series_type("close"),
+            IndicatorSpec::new("RSI")
+                .time_period(14)
+                .series_type("close"),
+        ];
+        for spec in specs {
+            let local = SingleValueIndicator::try_from(series.indicator(&spec).unwrap()).unwrap();
+            let remote = api
+                .technical_indicators("IBM", TechnicalIndicatorInterval::Daily, &[spec])
+                .await
+                .unwrap()
+                .into_values()
+                .next()
+                .unwrap()
+                .unwrap();
+            let remote = SingleValueIndicator::try_from(remote).unwrap();
+            assert_eq!(local.meta().time_period(), remote.meta().time_period());
+            assert!(!local.points().is_empty());
+            for (time, value) in local.points() {
+                let (_, expected) = remote.points().iter().find(|(t, _)| t == time).unwrap();
+                assert!((value - expected).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn values_are_not_rounded() {
+        let (_, series) = daily_ibm().await;
+        let sma = series
+            .indicator(
+                &IndicatorSpec::new("SMA")
+                    .time_period(20)
+                    .series_type("close"),
+            )
+            .unwrap();
+        assert_eq!(sma.data().unwrap().len(), 6);
+
+        let mut closes = series.data().iter().collect::<Vec<_>>();
+        closes.sort_by(|a, b| a.time().cmp(b.time()));
+        let closes = closes.iter().map(|data| data.close()).collect::<Vec<_>>();
+        let expected = super::sma(&closes, 20)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let mut data = sma.data().unwrap();
+        data.sort_by(|a, b| a.time().cmp(b.time()));
+        let values = data
+            .iter()
+            .map(|data| data.values()["SMA"])
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+        let points = SingleValueIndicator::try_from(sma).unwrap();
+        let values = points
+            .points()
+            .iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+
+    #[tokio::test]
+    async fn macd_matches_remote() {
+        let (api, series) = daily_ibm().await;
+        let spec = IndicatorSpec::new("MACD")
+            .fast_period(5)
+            .slow_period(10)
+            .signal_period(4)
+            .series_type("close");
+        let local = MacdIndicator::try_from(series.indicator(&spec).unwrap()).unwrap();
+        let remote = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .fast_period(5)
+            .slow_period(10)
+            .signal_period(4)
+            .series_type("close")
+            .json_macd()
+            .await
+            .unwrap();
+        assert_eq!(local.points().len(), remote.points().len());
+        for (local, remote) in local.points().iter().zip(remote.points()) {
+            assert_eq!(local.time(), remote.time());
+            assert!((local.macd() - remote.macd()).abs() < 1e-3);
+            assert!((local.signal() - remote.signal()).abs() < 1e-3);
+            assert!((local.histogram() - remote.histogram()).abs() < 1e-3);
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_spec() {
+        let (_, series) = daily_ibm().await;
+        let error = series
+            .indicator(
+                &IndicatorSpec::new("WMA")
+                    .time_period(10)
+                    .series_type("close"),
+            )
+            .unwrap_err();
+        assert!(matches!(error, Error::UnsupportedParameter { .. }));
+        let error = series
+            .indicator(&IndicatorSpec::new("SMA").series_type("close"))
+            .unwrap_err();
+        assert!(matches!(error, Error::MissingParameter { .. }));
+    }
+}
@@ -0,0 +1,76 @@
+//! Module for counting requests sent by `ApiClient`
+//!
+//! Counters are shared by all clones of `ApiClient` and all builders created
+//! from it, so snapshot returned by [`ApiClient::metrics`] covers every
+//! request sent through client
+//!
+//! [`ApiClient::metrics`]: crate::api::ApiClient::metrics
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Snapshot of counters of `ApiClient` at time when it was taken
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// Number of requests sent to server including retried requests
+    pub requests: u64,
+    /// Number of requests which failed to get output, got error status or
+    /// got error returned by API instead of data, along with output which
+    /// could not be decoded
+    pub errors: u64,
+    /// Number of requests rejected by rate limit of API
+    pub rate_limited: u64,
+    /// Number of responses served from cache instead of sending request
+    pub cache_hits: u64,
+    /// Number of retried requests
+    pub retries: u64,
+    /// Sum of time elapsed between sending request and receiving response
+    pub total_latency: Duration,
+}
+
+/// Counters updated by `ApiClient`. Counters are independent from each other
+/// so relaxed ordering is enough
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    rate_limited: AtomicU64,
+    cache_hits: AtomicU64,
+    retries: AtomicU64,
+    total_latency_nanos: AtomicU64,
+}
+
+impl Metrics {
+    pub(crate) fn record_request(&self, latency: Duration) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.total_latency_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_rate_limited(&self) {
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "retry")]
+    pub(crate) fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            requests: self.requests.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            total_latency: Duration::from_nanos(self.total_latency_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
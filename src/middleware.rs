@@ -0,0 +1,223 @@
+//! Module for rewriting requests sent by `ApiClient`
+//!
+//! Middlewares registered with [`ApiClient::with_middleware`] are called in
+//! order of registration for every request of every builder, including CSV,
+//! bytes and custom requests, before request is sent to provider. Unlike
+//! hooks of [`hook`](crate::hook) module, middleware can change path, query
+//! parameters and headers of request. Middleware is applied after response
+//! cache is looked up, so cached response is looked up with request which is
+//! not changed by middleware.
+//!
+//! [`ApiClient::with_middleware`]: crate::api::ApiClient::with_middleware
+//!
+//! ```no_run
+//! use alpha_vantage::middleware::{Middleware, PendingRequest};
+//!
+//! struct Tenant(&'static str);
+//!
+//! impl Middleware for Tenant {
+//!     fn before(&self, request: &mut PendingRequest) {
+//!         request.set_param("tenant", self.0);
+//!         request.set_header("x-tenant", self.0);
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+//!         .with_middleware(Box::new(Tenant("acme")));
+//!     let quote = api.quote("IBM").json().await.unwrap();
+//!     println!("{}", quote.price());
+//! }
+//! ```
+use std::sync::Arc;
+
+use crate::api::encode_query_value;
+
+/// Middleware which can change request before it is sent to provider
+pub trait Middleware {
+    /// Change request before it is sent
+    fn before(&self, request: &mut PendingRequest);
+}
+
+/// Middleware shared by clones of `ApiClient`
+pub(crate) type SharedMiddleware = Arc<dyn Middleware + Send + Sync>;
+
+/// Request which is not yet sent to provider. Path and query parameters do
+/// not contain API key, which is added as per provider after all middlewares
+/// are applied
+#[derive(Debug, Clone)]
+pub struct PendingRequest {
+    path: String,
+    params: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+}
+
+impl PendingRequest {
+    pub(crate) fn new(path_and_query: &str, headers: &[(&str, &str)]) -> Self {
+        let (path, query) = path_and_query
+            .split_once('?')
+            .unwrap_or((path_and_query, ""));
+        let params = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (name.to_string(), value.to_string())
+            })
+            .collect();
+        let headers = headers
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Self {
+            path: path.to_string(),
+            params,
+            headers,
+        }
+    }
+
+    /// Return path of request relative to base url such as `query`
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Set path of request relative to base url
+    pub fn set_path(&mut self, path: impl Into<String>) {
+        self.path = path.into();
+    }
+
+    /// Return query parameters of request in order along with percent-encoded
+    /// value
+    #[must_use]
+    pub fn params(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    /// Return percent-encoded value of query parameter
+    #[must_use]
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set value of query parameter, replacing existing value. Parameter which
+    /// is not present is appended. Value is percent-encoded
+    pub fn set_param(&mut self, name: impl Into<String>, value: &str) {
+        let name = name.into();
+        let value = encode_query_value(value).into_owned();
+        match self.params.iter_mut().find(|(param, _)| *param == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.params.push((name, value)),
+        }
+    }
+
+    /// Remove query parameter from request
+    pub fn remove_param(&mut self, name: &str) {
+        self.params.retain(|(param, _)| param != name);
+    }
+
+    /// Return extra headers of request. Headers of provider are not included
+    #[must_use]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Set value of header, replacing existing value of header whose name
+    /// matches case insensitively
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        let value = value.into();
+        match self
+            .headers
+            .iter_mut()
+            .find(|(header, _)| header.eq_ignore_ascii_case(&name))
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.headers.push((name, value)),
+        }
+    }
+
+    /// Remove header from request
+    pub fn remove_header(&mut self, name: &str) {
+        self.headers
+            .retain(|(header, _)| !header.eq_ignore_ascii_case(name));
+    }
+
+    // Path along with query of request
+    pub(crate) fn path_and_query(&self) -> String {
+        let query = self
+            .params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        format!("{}?{query}", self.path)
+    }
+
+    // Headers of request as pairs of str
+    pub(crate) fn header_pairs(&self) -> Vec<(&str, &str)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Middleware, PendingRequest};
+    use crate::test_utils::FixtureClient;
+
+    struct Tenant(&'static str);
+
+    impl Middleware for Tenant {
+        fn before(&self, request: &mut PendingRequest) {
+            request.set_param("tenant", self.0);
+        }
+    }
+
+    struct Signer;
+
+    impl Middleware for Signer {
+        fn before(&self, request: &mut PendingRequest) {
+            // tenant parameter added by previous middleware is visible
+            let signature = format!("{}:{}", request.path(), request.params().len());
+            request.set_header("x-signature", signature);
+        }
+    }
+
+    #[tokio::test]
+    async fn applied_in_order() {
+        let client = FixtureClient::new("quote/global_quote.json")
+            .route("datatype=csv", "quote_csv/global_quote.csv");
+        let api = crate::set_api("demo", client.clone())
+            .with_middleware(Box::new(Tenant("acme corp")))
+            .with_middleware(Box::new(Signer));
+
+        api.quote("IBM").json().await.unwrap();
+        api.quote("IBM").csv().await.unwrap();
+        api.custom("GLOBAL_QUOTE")
+            .extra_params("symbol", "MSFT")
+            .text()
+            .await
+            .unwrap();
+
+        let requests = client
+            .requests()
+            .into_iter()
+            .map(|request| format!("{} {:?}", request.url, request.headers))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            [
+                r#"https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&tenant=acme%20corp&apikey=demo [("x-signature", "query:3")]"#,
+                r#"https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&datatype=csv&tenant=acme%20corp&apikey=demo [("x-signature", "query:4")]"#,
+                r#"https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=MSFT&tenant=acme%20corp&apikey=demo [("x-signature", "query:3")]"#,
+            ]
+        );
+    }
+}
@@ -8,41 +8,108 @@
 //!
 //! [quote]: https://www.alphavantage.co/documentation/#latestprice
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc, Weekday};
+#[cfg(feature = "stream")]
+use futures::Stream;
 use serde::Deserialize;
+use serde_json::Value;
+#[cfg(feature = "stream")]
+use tokio::time::MissedTickBehavior;
 
-use crate::api::ApiClient;
-use crate::deserialize::{from_str, percent_f64};
+use crate::api::{encode_query_value, ApiClient, Entitlement, Provider};
+use crate::deserialize::{from_none_str, from_str, percent_none_f64};
 use crate::error::{detect_common_helper_error, Error, Result};
 
+/// Time to live of cached quote
+const QUOTE_TTL: Duration = Duration::from_secs(30);
+
 /// Struct storing Global Quote Value
+///
+/// Alpha Vantage have changed numbering of keys in past so older spelling of
+/// keys are accepted as alias. Unknown keys are ignored. Header of CSV
+/// response are also accepted as alias.
 #[derive(Debug, Deserialize, Clone, Default)]
 struct GlobalQuote {
-    #[serde(rename = "01. symbol")]
+    #[serde(rename = "01. symbol", alias = "1. symbol", alias = "symbol")]
     symbol: String,
-    #[serde(rename = "02. open", deserialize_with = "from_str")]
+    #[serde(
+        rename = "02. open",
+        alias = "2. open",
+        alias = "open",
+        deserialize_with = "from_str"
+    )]
     open: f64,
-    #[serde(rename = "03. high", deserialize_with = "from_str")]
+    #[serde(
+        rename = "03. high",
+        alias = "3. high",
+        alias = "high",
+        deserialize_with = "from_str"
+    )]
     high: f64,
-    #[serde(rename = "04. low", deserialize_with = "from_str")]
+    #[serde(
+        rename = "04. low",
+        alias = "4. low",
+        alias = "low",
+        deserialize_with = "from_str"
+    )]
     low: f64,
-    #[serde(rename = "05. price", deserialize_with = "from_str")]
+    #[serde(
+        rename = "05. price",
+        alias = "5. price",
+        alias = "price",
+        deserialize_with = "from_str"
+    )]
     price: f64,
-    #[serde(rename = "06. volume", deserialize_with = "from_str")]
+    #[serde(
+        rename = "06. volume",
+        alias = "6. volume",
+        alias = "volume",
+        deserialize_with = "from_str"
+    )]
     volume: u64,
-    #[serde(rename = "07. latest trading day")]
+    #[serde(
+        rename = "07. latest trading day",
+        alias = "7. latest trading day",
+        alias = "latest trading day",
+        alias = "latestDay"
+    )]
     last_day: String,
-    #[serde(rename = "08. previous close", deserialize_with = "from_str")]
-    previous_close: f64,
-    #[serde(rename = "09. change", deserialize_with = "from_str")]
-    change: f64,
-    #[serde(rename = "10. change percent", deserialize_with = "percent_f64")]
-    change_percent: f64,
+    #[serde(
+        rename = "08. previous close",
+        alias = "8. previous close",
+        alias = "previous close",
+        alias = "previousClose",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    previous_close: Option<f64>,
+    #[serde(
+        rename = "09. change",
+        alias = "9. change",
+        alias = "change",
+        default,
+        deserialize_with = "from_none_str"
+    )]
+    change: Option<f64>,
+    #[serde(
+        rename = "10. change percent",
+        alias = "change percent",
+        alias = "changePercent",
+        default,
+        deserialize_with = "percent_none_f64"
+    )]
+    change_percent: Option<f64>,
 }
 
 /// Struct for storing Quote related information
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct Quote {
     global_quote: GlobalQuote,
+    data_notice: Option<String>,
 }
 
 impl Quote {
@@ -76,21 +143,46 @@ impl Quote {
         self.global_quote.volume
     }
 
-    /// return previous
+    /// return previous close. Returns NaN if API does not return previous
+    /// close, use [`previous_opt`](Self::previous_opt) to check whether it is
+    /// present
     #[must_use]
     pub fn previous(&self) -> f64 {
+        self.previous_opt().unwrap_or(f64::NAN)
+    }
+
+    /// return previous close. Returns None if API does not return previous
+    /// close
+    #[must_use]
+    pub fn previous_opt(&self) -> Option<f64> {
         self.global_quote.previous_close
     }
 
-    /// return change
+    /// return change. Returns NaN if API does not return change, use
+    /// [`change_opt`](Self::change_opt) to check whether it is present
     #[must_use]
     pub fn change(&self) -> f64 {
+        self.change_opt().unwrap_or(f64::NAN)
+    }
+
+    /// return change. Returns None if API does not return change
+    #[must_use]
+    pub fn change_opt(&self) -> Option<f64> {
         self.global_quote.change
     }
 
-    /// return change percent
+    /// return change percent. Returns NaN if API does not return change
+    /// percent, use [`change_percent_opt`](Self::change_percent_opt) to check
+    /// whether it is present
     #[must_use]
     pub fn change_percent(&self) -> f64 {
+        self.change_percent_opt().unwrap_or(f64::NAN)
+    }
+
+    /// return change percent. Returns None if API does not return change
+    /// percent
+    #[must_use]
+    pub fn change_percent_opt(&self) -> Option<f64> {
         self.global_quote.change_percent
     }
 
@@ -115,6 +207,130 @@ impl Quote {
     pub fn symbol(&self) -> &str {
         &self.global_quote.symbol
     }
+
+    /// Return position of price within low high range of day where 0.0 is low
+    /// and 1.0 is high. Returns None if high and low are same
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     if let Some(position) = quote.range_position() {
+    ///         println!("price is {:.0}% of day range", position * 100.0);
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn range_position(&self) -> Option<f64> {
+        let range = self.high() - self.low();
+        if range == 0.0 {
+            return None;
+        }
+        Some((self.price() - self.low()) / range)
+    }
+
+    /// Return opening gap in percent relative to previous close. Returns None
+    /// if previous close is not present or is zero
+    #[must_use]
+    pub fn gap_percent(&self) -> Option<f64> {
+        let previous = self.previous_opt()?;
+        if previous == 0.0 {
+            return None;
+        }
+        Some((self.open() - previous) / previous * 100.0)
+    }
+
+    /// Return notice which premium API returns along with quote such as `DATA
+    /// DELAYED BY 15 MINUTES`. Returns None if no notice is present
+    #[must_use]
+    pub fn data_notice(&self) -> Option<&str> {
+        self.data_notice.as_deref()
+    }
+
+    /// Return entitlement of quote data detected from data notice. Returns
+    /// None if response does not contain entitlement information
+    #[must_use]
+    pub fn entitlement(&self) -> Option<Entitlement> {
+        let notice = self.data_notice.as_deref()?.to_uppercase();
+        if notice.contains("DELAYED") {
+            Some(Entitlement::Delayed)
+        } else if notice.contains("REALTIME") || notice.contains("REAL-TIME") {
+            Some(Entitlement::Realtime)
+        } else {
+            None
+        }
+    }
+
+    /// Return latest trading day parsed as date
+    ///
+    /// # Errors
+    /// Raise error if latest trading day is not in `YYYY-MM-DD` format
+    #[cfg(feature = "chrono")]
+    pub fn last_trading_date(&self) -> Result<NaiveDate> {
+        NaiveDate::parse_from_str(&self.global_quote.last_day, "%Y-%m-%d")
+            .map_err(|_| Error::ParseDateTime(self.global_quote.last_day.clone()))
+    }
+
+    /// Return number of calendar days between latest trading day and `now`
+    ///
+    /// # Errors
+    /// Raise error if latest trading day cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn age_days(&self, now: DateTime<Utc>) -> Result<i64> {
+        Ok((now.date_naive() - self.last_trading_date()?).num_days())
+    }
+
+    /// Check whether quote is older than `max_age` at `now`.
+    ///
+    /// Age is counted from the end of the latest trading day and only time
+    /// falling on weekdays is counted, so friday data checked during weekend
+    /// has zero age. Quote whose latest trading day cannot be parsed is always
+    /// considered as stale.
+    ///
+    /// ```no_run
+    /// use chrono::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let quote = api.quote("MSFT").json().await.unwrap();
+    ///     if quote.is_stale_now(Duration::days(1)) {
+    ///         println!("quote of {} is stale", quote.last_trading());
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn is_stale(&self, max_age: chrono::Duration, now: DateTime<Utc>) -> bool {
+        let Ok(last_trading_date) = self.last_trading_date() else {
+            return true;
+        };
+        let Some(day_end) = last_trading_date.succ_opt() else {
+            return true;
+        };
+        let mut cursor = day_end.and_time(NaiveTime::MIN).and_utc();
+        let mut age = chrono::Duration::zero();
+        while cursor < now {
+            let next_day = (cursor.date_naive() + chrono::Duration::days(1))
+                .and_time(NaiveTime::MIN)
+                .and_utc();
+            let till = next_day.min(now);
+            if !matches!(cursor.weekday(), Weekday::Sat | Weekday::Sun) {
+                age += till - cursor;
+            }
+            cursor = till;
+        }
+        age > max_age
+    }
+
+    /// Check whether quote is older than `max_age` at current time. See
+    /// [`Quote::is_stale`] for how age is counted.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn is_stale_now(&self, max_age: chrono::Duration) -> bool {
+        self.is_stale(max_age, Utc::now())
+    }
 }
 
 /// Struct for helping creation of Quote
@@ -128,36 +344,532 @@ pub(crate) struct QuoteHelper {
     note: Option<String>,
     #[serde(rename = "Global Quote")]
     global_quote: Option<GlobalQuote>,
+    #[serde(flatten)]
+    extras: HashMap<String, Value>,
 }
 
 impl QuoteHelper {
     fn convert(self) -> Result<Quote> {
         let mut quote = Quote::default();
         detect_common_helper_error(self.information, self.error_message, self.note)?;
-        if self.global_quote.is_none() {
-            return Err(Error::EmptyResponse);
+        if let Some(global_quote) = self.global_quote {
+            quote.global_quote = global_quote;
+            return Ok(quote);
         }
-        quote.global_quote = self.global_quote.unwrap();
+        // premium API returns quote with notice appended to key such as
+        // `Global Quote - DATA DELAYED BY 15 MINUTES`
+        let (key, value) = self
+            .extras
+            .into_iter()
+            .find(|(key, _)| key.starts_with("Global Quote - "))
+            .ok_or(Error::EmptyResponse)?;
+        quote.global_quote =
+            serde_json::from_value(value).map_err(|_| Error::DecodeJsonToStruct)?;
+        quote.data_notice = Some(key.trim_start_matches("Global Quote - ").to_string());
         Ok(quote)
     }
 }
 
-/// Builder to create `Quote`
+/// Builder to create `Quote`. Symbol is percent-encoded before being added to
+/// url of request
 pub struct QuoteBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     symbol: &'a str,
+    entitlement: Option<Entitlement>,
 }
 
 impl<'a> QuoteBuilder<'a> {
-    crate::json_data_struct!(Quote, QuoteHelper);
+    crate::json_data_struct!(Quote, QuoteHelper, cache_ttl);
+
+    #[allow(clippy::unused_self)]
+    fn cache_ttl(&self) -> Duration {
+        QUOTE_TTL
+    }
 
     /// Create new `QuoteBuilder` from `APIClient`
     #[must_use]
     pub fn new(api_client: &'a ApiClient, symbol: &'a str) -> Self {
-        Self { api_client, symbol }
+        Self {
+            api_client,
+            provider: None,
+            symbol,
+            entitlement: None,
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client, while
+    /// provider of client is still used by other requests. Base url, rate
+    /// limit, cache and hooks of client also apply to request sent through
+    /// overriding provider
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::Provider;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let quote = api
+    ///         .quote("MSFT")
+    ///         .provider(Provider::RapidAPI)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(quote.symbol(), "MSFT");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Returns quote fetched as CSV which have smaller response than JSON
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let quote = api.quote("IBM").csv().await.unwrap();
+    ///     assert_eq!(quote.symbol(), "IBM");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors
+    pub async fn csv(&self) -> Result<Quote> {
+        let url = self.create_url();
+        let api_client = self
+            .api_client
+            .with_provider_override(self.provider.as_ref())?;
+        api_client
+            .in_request(&url, async {
+                let text = api_client.get_csv(&url).await?;
+                let global_quote = csv::Reader::from_reader(text.as_bytes())
+                    .deserialize::<GlobalQuote>()
+                    .next()
+                    .ok_or(Error::EmptyResponse)?
+                    .map_err(|_| Error::DecodeJsonToStruct)?;
+                Ok(Quote {
+                    global_quote,
+                    data_notice: None,
+                })
+            })
+            .await
+    }
+
+    /// Define entitlement of data to be requested for premium API key
+    ///
+    /// ```no_run
+    /// use alpha_vantage::api::Entitlement;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("premium_key", reqwest::Client::new());
+    ///     let quote = api
+    ///         .quote("IBM")
+    ///         .entitlement(Entitlement::Delayed)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(quote.entitlement(), Some(Entitlement::Delayed));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.entitlement = Some(entitlement);
+        self
+    }
+
+    /// Create stream which fetches quote repeatedly after every `interval`
+    /// using default `StreamPolicy`. See [`QuoteBuilder::stream_with_policy`]
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero
+    #[cfg(feature = "stream")]
+    pub fn stream(&self, interval: Duration) -> impl Stream<Item = Result<Quote>> + '_ {
+        self.stream_with_policy(interval, StreamPolicy::default())
+    }
+
+    /// Create stream which fetches quote repeatedly after every `interval`.
+    ///
+    /// Requests are sent on fixed ticks so slow response does not shift later
+    /// requests, tick missed due to slow response is skipped. Every tick
+    /// requests server even if cache is set for client. Errors are
+    /// returned as stream item and stream ends only when `policy` says so.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use alpha_vantage::quote::StreamPolicy;
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let builder = api.quote("MSFT");
+    ///     let policy = StreamPolicy::default().stop_on_rate_limit(true);
+    ///     let mut quotes = Box::pin(builder.stream_with_policy(Duration::from_secs(60), policy));
+    ///     while let Some(quote) = quotes.next().await {
+    ///         match quote {
+    ///             Ok(quote) => println!("{} {}", quote.symbol(), quote.price()),
+    ///             Err(error) => eprintln!("{error}"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero
+    #[cfg(feature = "stream")]
+    pub fn stream_with_policy(
+        &self,
+        interval: Duration,
+        policy: StreamPolicy,
+    ) -> impl Stream<Item = Result<Quote>> + '_ {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        futures::stream::unfold(
+            (ticker, 0_usize, false),
+            move |(mut ticker, emitted, finished)| async move {
+                if finished || policy.max_items.is_some_and(|max| emitted >= max) {
+                    return None;
+                }
+                ticker.tick().await;
+                let quote = self.fresh_json().await;
+                let finished = match &quote {
+                    Ok(_) => false,
+                    Err(error) => {
+                        policy.stop_on_error || (policy.stop_on_rate_limit && error.is_rate_limit())
+                    }
+                };
+                Some((quote, (ticker, emitted + 1, finished)))
+            },
+        )
+    }
+
+    // Fetch quote without reading cache, otherwise stream polling faster
+    // than time to live of quote would return same cached quote
+    #[cfg(feature = "stream")]
+    async fn fresh_json(&self) -> Result<Quote> {
+        let url = self.create_url();
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_json_with(&url, None, QuoteHelper::convert)
+            .await
     }
 
     fn create_url(&self) -> String {
-        format!("query?function=GLOBAL_QUOTE&symbol={}", self.symbol)
+        let mut url = format!(
+            "query?function=GLOBAL_QUOTE&symbol={}",
+            encode_query_value(self.symbol)
+        );
+        if let Some(entitlement) = self.entitlement {
+            url.push_str("&entitlement=");
+            url.push_str(entitlement.as_str());
+        }
+        url
+    }
+}
+
+/// Policy which decides when quote stream created by
+/// [`QuoteBuilder::stream_with_policy`] ends. By default stream never ends
+#[cfg(feature = "stream")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamPolicy {
+    max_items: Option<usize>,
+    stop_on_rate_limit: bool,
+    stop_on_error: bool,
+}
+
+#[cfg(feature = "stream")]
+impl StreamPolicy {
+    /// End stream after returning `max_items` items
+    #[must_use]
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// End stream after returning rate limit error
+    #[must_use]
+    pub fn stop_on_rate_limit(mut self, stop: bool) -> Self {
+        self.stop_on_rate_limit = stop;
+        self
+    }
+
+    /// End stream after returning any error
+    #[must_use]
+    pub fn stop_on_error(mut self, stop: bool) -> Self {
+        self.stop_on_error = stop;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "rate-limit", feature = "stream"))]
+    use std::time::Duration;
+
+    #[cfg(feature = "stream")]
+    use futures::StreamExt;
+
+    #[cfg(feature = "stream")]
+    use super::StreamPolicy;
+    use crate::api::{Entitlement, Provider};
+    use crate::cache::InMemoryCache;
+    use crate::error::Error;
+    use crate::test_utils::FixtureClient;
+
+    #[cfg(any(feature = "chrono", feature = "stream"))]
+    const FRIDAY_QUOTE: &str = r#"{"Global Quote": {"01. symbol": "MSFT", "02. open": "1.0",
+        "03. high": "1.0", "04. low": "1.0", "05. price": "1.0",
+        "06. volume": "1", "07. latest trading day": "2023-06-02",
+        "08. previous close": "1.0", "09. change": "0.0",
+        "10. change percent": "0.0%"}}"#;
+
+    #[tokio::test]
+    async fn every_fixture_parses() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/quote");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let body = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            let api = crate::set_api("demo", FixtureClient::status(200, &body));
+            let quote = api.quote("MSFT").json().await.unwrap();
+            assert!(quote.price() > 0.0);
+            if quote.symbol() == "ARM" {
+                assert!(quote.previous_opt().is_none());
+                assert!(quote.previous().is_nan());
+                assert!(quote.change_percent_opt().is_none());
+            } else {
+                assert!(quote.previous_opt().is_some());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn range_position_and_gap() {
+        // open 409.13, high 410.26, low 405.65, price 406.32, previous close 409.49
+        let api = crate::set_api("demo", FixtureClient::new("quote/global_quote.json"));
+        let quote = api.quote("MSFT").json().await.unwrap();
+        assert!((quote.range_position().unwrap() - 0.145_336_225_6).abs() < 1e-9);
+        assert!((quote.gap_percent().unwrap() - -0.087_914_234_8).abs() < 1e-9);
+
+        // high and low are same and previous close is zero
+        let api = crate::set_api("demo", FixtureClient::new("quote/flat_range.json"));
+        let quote = api.quote("TPL").json().await.unwrap();
+        assert!(quote.range_position().is_none());
+        assert!(quote.gap_percent().is_none());
+
+        let api = crate::set_api(
+            "demo",
+            FixtureClient::new("quote/missing_previous_close.json"),
+        );
+        let quote = api.quote("ARM").json().await.unwrap();
+        assert!((quote.range_position().unwrap() - 0.580_620_155).abs() < 1e-9);
+        assert!(quote.gap_percent().is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn staleness_counts_weekdays() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let api = crate::set_api("demo", FixtureClient::status(200, FRIDAY_QUOTE));
+        let quote = api.quote("MSFT").json().await.unwrap();
+        let sunday = Utc.with_ymd_and_hms(2023, 6, 4, 18, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2023, 6, 5, 10, 0, 0).unwrap();
+        let next_week = Utc.with_ymd_and_hms(2023, 6, 9, 10, 0, 0).unwrap();
+        assert_eq!(quote.age_days(monday).unwrap(), 3);
+        assert!(!quote.is_stale(Duration::zero(), sunday));
+        assert!(!quote.is_stale(Duration::days(1), monday));
+        assert!(quote.is_stale(Duration::hours(1), monday));
+        assert!(quote.is_stale(Duration::days(1), next_week));
+    }
+
+    #[tokio::test]
+    async fn symbol_is_encoded() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone());
+        api.quote("BRK.B").json().await.unwrap();
+        api.quote("MSFT&apikey=other").json().await.unwrap();
+        assert_eq!(
+            client.urls(),
+            [
+                "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=BRK.B&apikey=demo",
+                "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=MSFT%26apikey%3Dother&apikey=demo",
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn provider_overrides_client_provider() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone());
+        #[cfg(feature = "rate-limit")]
+        let api = api.with_rate_limit(crate::rate_limit::RateLimitPolicy::default().per_minute(1));
+
+        let start = tokio::time::Instant::now();
+        api.quote("MSFT").json().await.unwrap();
+        api.quote("MSFT")
+            .provider(Provider::RapidAPI)
+            .json()
+            .await
+            .unwrap();
+        // overridden request shares rate limit of client
+        #[cfg(feature = "rate-limit")]
+        assert_eq!(start.elapsed(), Duration::from_mins(1));
+        #[cfg(not(feature = "rate-limit"))]
+        assert!(start.elapsed().is_zero());
+
+        let custom = Provider::Custom {
+            base_url: "https://proxy.internal/av".to_string(),
+            headers: vec![("authorization".to_string(), "Bearer secret".to_string())],
+            key_param: None,
+        };
+        api.quote("MSFT").provider(custom).json().await.unwrap();
+
+        // base url of client is used in place of base url of provider
+        let api = api.with_base_url("http://localhost:8080").unwrap();
+        api.quote("MSFT")
+            .provider(Provider::RapidAPI)
+            .json()
+            .await
+            .unwrap();
+
+        // provider of client is not changed
+        api.quote("MSFT").json().await.unwrap();
+
+        let requests = client
+            .requests()
+            .into_iter()
+            .map(|request| format!("{} {:?}", request.url, request.headers))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            [
+                "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=MSFT&apikey=demo []",
+                r#"https://alpha-vantage.p.rapidapi.com/query?function=GLOBAL_QUOTE&symbol=MSFT [("x-rapidapi-host", "alpha-vantage.p.rapidapi.com"), ("x-rapidapi-key", "demo")]"#,
+                r#"https://proxy.internal/av/query?function=GLOBAL_QUOTE&symbol=MSFT [("authorization", "Bearer secret")]"#,
+                r#"http://localhost:8080/query?function=GLOBAL_QUOTE&symbol=MSFT [("x-rapidapi-host", "alpha-vantage.p.rapidapi.com"), ("x-rapidapi-key", "demo")]"#,
+                "http://localhost:8080/query?function=GLOBAL_QUOTE&symbol=MSFT&apikey=demo []",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn csv_quote_and_errors() {
+        let client = FixtureClient::new("quote_csv/global_quote.csv");
+        let api = crate::set_api("demo", client.clone());
+        let quote = api.quote("IBM").csv().await.unwrap();
+        assert!(client.urls()[0].contains("&datatype=csv"));
+        assert_eq!(quote.symbol(), "IBM");
+        assert_eq!(quote.price().to_string(), "168.21");
+        assert_eq!(quote.volume(), 2_354_318);
+        assert_eq!(quote.last_trading(), "2024-02-15");
+        assert_eq!(quote.previous().to_string(), "168.52");
+        assert_eq!(quote.change_percent().to_string(), "-0.184");
+
+        // alpha vantage returns JSON error even if CSV is requested
+        let api = crate::set_api("demo", FixtureClient::new("error/rate_limit.json"));
+        let error = api.quote("IBM").csv().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageInformation(_)));
+        let api = crate::set_api("demo", FixtureClient::new("error/invalid_call.json"));
+        let error = api.quote("IBM").csv().await.unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageErrorMessage(_)));
+    }
+
+    #[tokio::test]
+    async fn entitlement_is_sent() {
+        let client = FixtureClient::new("quote/delayed.json");
+        let quote = crate::set_api("demo", client.clone())
+            .quote("IBM")
+            .entitlement(Entitlement::Delayed)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(quote.symbol(), "IBM");
+        assert_eq!(quote.data_notice(), Some("DATA DELAYED BY 15 MINUTES"));
+        assert_eq!(quote.entitlement(), Some(Entitlement::Delayed));
+
+        crate::set_rapid_api("demo", client.clone())
+            .quote("IBM")
+            .entitlement(Entitlement::Realtime)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(
+            client.urls(),
+            [
+                "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&entitlement=delayed&apikey=demo",
+                "https://alpha-vantage.p.rapidapi.com/query?function=GLOBAL_QUOTE&symbol=IBM&entitlement=realtime",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn json_serves_cached_quote() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone()).with_cache(InMemoryCache::default());
+        let first = api.quote("IBM").json().await.unwrap();
+        let second = api.quote("IBM").json().await.unwrap();
+        assert_eq!(first.symbol(), second.symbol());
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn stream_keeps_ticks_and_returns_errors() {
+        let note = r#"{"Note": "rate limit reached"}"#;
+        let client = FixtureClient::status(200, FRIDAY_QUOTE)
+            .queue(200, FRIDAY_QUOTE)
+            .queue(200, note)
+            .delay(Duration::from_millis(300));
+        let api = crate::set_api("demo", client.clone());
+        let builder = api.quote("MSFT");
+        let start = tokio::time::Instant::now();
+        let quotes = builder
+            .stream_with_policy(Duration::from_secs(1), StreamPolicy::default().max_items(4))
+            .collect::<Vec<_>>()
+            .await;
+        // rate limit error is returned as item without ending stream
+        assert_eq!(quotes.len(), 4);
+        assert!(
+            quotes[1]
+                .as_ref()
+                .is_err_and(crate::error::Error::is_rate_limit)
+        );
+        assert_eq!(client.calls(), 4);
+        // last request is sent at fourth tick instead of drifting by response time
+        assert_eq!(start.elapsed(), Duration::from_millis(3300));
+
+        let client = FixtureClient::status(200, FRIDAY_QUOTE)
+            .queue(200, FRIDAY_QUOTE)
+            .queue(200, note);
+        let api = crate::set_api("demo", client.clone());
+        let policy = StreamPolicy::default().stop_on_rate_limit(true);
+        let quotes = api
+            .quote("MSFT")
+            .stream_with_policy(Duration::from_secs(1), policy)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(client.calls(), 2);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test(start_paused = true)]
+    async fn stream_bypasses_cache() {
+        let client = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("demo", client.clone()).with_cache(InMemoryCache::default());
+        let policy = StreamPolicy::default().max_items(3);
+        let quotes = api
+            .quote("IBM")
+            .stream_with_policy(Duration::from_secs(1), policy)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(quotes.len(), 3);
+        assert!(quotes.iter().all(Result::is_ok));
+        assert_eq!(client.calls(), 3);
     }
 }
@@ -0,0 +1,157 @@
+//! Module for limiting rate of requests sent by `ApiClient`
+//!
+//! Limit is shared by all builders created from same `ApiClient` so requests
+//! sent concurrently are also spaced as per policy
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+const MINUTE: Duration = Duration::from_mins(1);
+const DAY: Duration = Duration::from_hours(24);
+
+/// Policy which decides how many requests can be sent by `ApiClient`. By
+/// default number of requests is unlimited
+///
+/// ```
+/// use alpha_vantage::rate_limit::RateLimitPolicy;
+///
+/// let policy = RateLimitPolicy::default().per_minute(5).per_day(25);
+/// assert_eq!(policy, RateLimitPolicy::free_tier());
+///
+/// // zero removes limit instead of blocking every request
+/// let policy = RateLimitPolicy::free_tier().per_minute(0).per_day(0);
+/// assert_eq!(policy, RateLimitPolicy::default());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitPolicy {
+    per_minute: Option<u32>,
+    per_day: Option<u32>,
+}
+
+impl RateLimitPolicy {
+    /// Policy for free tier of alpha vantage which allows 5 requests per
+    /// minute and 25 requests per day
+    #[must_use]
+    pub fn free_tier() -> Self {
+        Self::default().per_minute(5).per_day(25)
+    }
+
+    /// Allow at most `per_minute` requests in any window of one minute. Zero
+    /// means requests per minute are unlimited
+    #[must_use]
+    pub fn per_minute(mut self, per_minute: u32) -> Self {
+        self.per_minute = (per_minute > 0).then_some(per_minute);
+        self
+    }
+
+    /// Allow at most `per_day` requests in any window of one day. Zero means
+    /// requests per day are unlimited
+    #[must_use]
+    pub fn per_day(mut self, per_day: u32) -> Self {
+        self.per_day = (per_day > 0).then_some(per_day);
+        self
+    }
+}
+
+/// Limiter which keeps time of requests sent in last minute and last day
+pub(crate) struct RateLimiter {
+    policy: RateLimitPolicy,
+    sent: Mutex<Sent>,
+}
+
+#[derive(Default)]
+struct Sent {
+    minute: VecDeque<Instant>,
+    day: VecDeque<Instant>,
+}
+
+impl Sent {
+    // Remove time of request which is out of window
+    fn prune(&mut self, now: Instant) {
+        while self
+            .minute
+            .front()
+            .is_some_and(|&time| now - time >= MINUTE)
+        {
+            self.minute.pop_front();
+        }
+        while self.day.front().is_some_and(|&time| now - time >= DAY) {
+            self.day.pop_front();
+        }
+    }
+
+    // Duration after which next request can be sent
+    fn wait(&self, policy: RateLimitPolicy, now: Instant) -> Duration {
+        let minute = window_wait(&self.minute, policy.per_minute, MINUTE, now);
+        let day = window_wait(&self.day, policy.per_day, DAY, now);
+        minute.max(day)
+    }
+}
+
+// Duration after which number of requests sent in window is less than limit
+fn window_wait(
+    times: &VecDeque<Instant>,
+    limit: Option<u32>,
+    window: Duration,
+    now: Instant,
+) -> Duration {
+    match limit {
+        Some(limit) if times.len() >= limit as usize => times
+            .get(times.len() - limit as usize)
+            .map_or(window, |&time| {
+                (time + window).saturating_duration_since(now)
+            }),
+        _ => Duration::ZERO,
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(policy: RateLimitPolicy) -> Self {
+        Self {
+            policy,
+            sent: Mutex::new(Sent::default()),
+        }
+    }
+
+    fn sent(&self) -> MutexGuard<'_, Sent> {
+        self.sent.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Wait until request can be sent and reserve slot for request
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut sent = self.sent();
+                let now = Instant::now();
+                sent.prune(now);
+                let wait = sent.wait(self.policy, now);
+                if wait.is_zero() {
+                    sent.minute.push_back(now);
+                    sent.day.push_back(now);
+                    return;
+                }
+                wait
+            };
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?wait, "rate limit of client reached, waiting for slot");
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    pub(crate) fn remaining_today(&self) -> Option<u32> {
+        let per_day = self.policy.per_day?;
+        let mut sent = self.sent();
+        sent.prune(Instant::now());
+        let used = u32::try_from(sent.day.len()).unwrap_or(u32::MAX);
+        Some(per_day.saturating_sub(used))
+    }
+
+    pub(crate) fn next_slot_in(&self) -> Duration {
+        let mut sent = self.sent();
+        let now = Instant::now();
+        sent.prune(now);
+        sent.wait(self.policy, now)
+    }
+}
@@ -0,0 +1,113 @@
+//! Module for recording responses of `ApiClient` to files and replaying them
+//! later without sending request, which can be used for running tests
+//! offline
+//!
+//! Response is stored in file named after hash of url of request whose API
+//! key is redacted, and API key is also redacted from stored response.
+//! Replaying client never sends request so request whose response is not
+//! recorded raises [`Error::MissingCassette`].
+//!
+//! ```no_run
+//! #[tokio::main]
+//! async fn main() {
+//!     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+//!         .with_recorder("tests/cassettes");
+//!     api.quote("MSFT").json().await.unwrap();
+//!
+//!     // replaying client never sends request to server
+//!     let api = alpha_vantage::set_api("demo", reqwest::Client::new())
+//!         .with_replayer("tests/cassettes");
+//!     let quote = api.quote("MSFT").json().await.unwrap();
+//!     println!("{}", quote.price());
+//! }
+//! ```
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+
+/// Mode of `ApiClient` which records or replays response
+#[derive(Clone, Debug)]
+pub(crate) enum Cassette {
+    Record(Arc<Path>),
+    Replay(Arc<Path>),
+}
+
+// File which stores response of url whose API key is redacted. FNV-1a hash
+// is used since it is stable across Rust versions and platforms
+fn cassette_file(dir: &Path, url: &str) -> PathBuf {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    dir.join(format!("{hash:016x}.txt"))
+}
+
+// Write body of response of url to directory after redacting API key
+pub(crate) fn record(dir: &Path, url: &str, body: &[u8], api_key: &str) -> Result<()> {
+    let file = cassette_file(dir, url);
+    let body = match std::str::from_utf8(body) {
+        Ok(text) if !api_key.is_empty() => text.replace(api_key, "[REDACTED]").into_bytes(),
+        _ => body.to_vec(),
+    };
+    std::fs::create_dir_all(dir)
+        .and_then(|()| std::fs::write(&file, body))
+        .map_err(|_| Error::RecordCassette {
+            url: url.to_string(),
+            file: file.display().to_string(),
+        })
+}
+
+// Read body of response of url recorded in directory
+pub(crate) fn replay(dir: &Path, url: &str) -> Result<Vec<u8>> {
+    let file = cassette_file(dir, url);
+    std::fs::read(&file).map_err(|_| Error::MissingCassette {
+        url: url.to_string(),
+        file: file.display().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::test_utils::FixtureClient;
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("alpha_vantage_record_{}", std::process::id()));
+        let echo = r#"{"Information": "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=ECHO&apikey=secret-key"}"#;
+        let client =
+            FixtureClient::new("quote/global_quote.json").route_status("symbol=ECHO", 200, echo);
+        let api = crate::set_api("secret-key", client).with_recorder(&dir);
+        let recorded = api.quote("MSFT").json().await.unwrap();
+        api.quote("ECHO").json().await.unwrap_err();
+
+        let offline = FixtureClient::new("quote/global_quote.json");
+        let api = crate::set_api("secret-key", offline.clone()).with_replayer(&dir);
+        let replayed = api.quote("MSFT").json().await.unwrap();
+        assert_eq!(replayed.symbol(), recorded.symbol());
+        assert_eq!(replayed.price().to_string(), recorded.price().to_string());
+
+        // API key is redacted from recorded response
+        let error = api.quote("ECHO").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::AlphaVantageInformation(information)
+                if information == "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=ECHO&apikey=[REDACTED]"
+        ));
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let body = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!body.contains("secret-key"));
+        }
+
+        let error = api.quote("IBM").json().await.unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::MissingCassette { url, .. }
+                if url == "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol=IBM&apikey=[REDACTED]"
+        ));
+        // replaying client never sends request
+        assert_eq!(offline.calls(), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
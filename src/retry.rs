@@ -0,0 +1,55 @@
+//! Module for retrying request of `ApiClient` which fails to get output from
+//! server
+use std::time::Duration;
+
+/// Policy which decides how many times request whose output cannot be
+/// fetched from server is retried. Delay before retry starts from backoff and
+/// doubles after every retry. Error returned by API such as rate limit is
+/// not retried
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use alpha_vantage::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3).backoff(Duration::from_millis(500));
+/// assert_eq!(policy.max_retries(), 3);
+/// assert_eq!(policy.delay(0), Duration::from_millis(500));
+/// assert_eq!(policy.delay(2), Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Create policy which retries request at most `max_retries` times with
+    /// backoff of one second
+    #[must_use]
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// Set delay before first retry
+    #[must_use]
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Return maximum number of retry
+    #[must_use]
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Return delay before retry whose index starts from 0
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(2_u32.saturating_pow(retry))
+    }
+}
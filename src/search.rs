@@ -8,11 +8,15 @@
 //!
 //! [symbol_search]: https://www.alphavantage.co/documentation/#symbolsearch
 
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, FixedOffset, NaiveTime, Utc, Weekday};
 use serde::Deserialize;
 
-use crate::api::ApiClient;
+use crate::api::{encode_query_value, ApiClient, Provider};
 use crate::deserialize::from_str;
 use crate::error::{detect_common_helper_error, Error, Result};
+use crate::quote::QuoteBuilder;
+use crate::stock_time::{StockFunction, TimeSeriesBuilder};
 
 /// Struct which stores matches data for search keyword
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -34,7 +38,7 @@ pub struct Match {
     #[serde(rename = "8. currency")]
     currency: String,
     #[serde(rename = "9. matchScore", deserialize_with = "from_str")]
-    match_score: f64,
+    score: f64,
 }
 
 impl Match {
@@ -45,7 +49,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let symbol = search.matches()[0].symbol();
+    ///     let symbol = search.best_match().unwrap().symbol();
     ///     assert_eq!(symbol, "BA");
     /// }
     /// ```
@@ -61,7 +65,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let name = search.matches()[0].name();
+    ///     let name = search.best_match().unwrap().name();
     ///     assert_eq!(name, "Boeing Company");
     /// }
     /// ```
@@ -77,7 +81,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let stock_type = search.matches()[0].stock_type();
+    ///     let stock_type = search.best_match().unwrap().stock_type();
     ///     assert_eq!(stock_type, "Equity");
     /// }
     #[must_use]
@@ -85,6 +89,23 @@ impl Match {
         &self.stock_type
     }
 
+    /// Return stock type parsed as `StockType`
+    ///
+    /// ```
+    /// use alpha_vantage::search::StockType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("BA").json().await.unwrap();
+    ///     let stock_type = search.best_match().unwrap().stock_type_enum();
+    ///     assert_eq!(stock_type, StockType::Equity);
+    /// }
+    #[must_use]
+    pub fn stock_type_enum(&self) -> StockType {
+        StockType::from(self.stock_type.as_str())
+    }
+
     /// Return region of search data
     ///
     /// ```
@@ -92,7 +113,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let region = search.matches()[0].region();
+    ///     let region = search.best_match().unwrap().region();
     ///     assert_eq!(region, "United States");
     /// }
     #[must_use]
@@ -100,6 +121,23 @@ impl Match {
         &self.region
     }
 
+    /// Return region parsed as `Region`
+    ///
+    /// ```
+    /// use alpha_vantage::search::Region;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("BA").json().await.unwrap();
+    ///     let region = search.best_match().unwrap().region_enum();
+    ///     assert_eq!(region, Region::UnitedStates);
+    /// }
+    #[must_use]
+    pub fn region_enum(&self) -> Region {
+        Region::from(self.region.as_str())
+    }
+
     /// Return market open time
     ///
     /// ```
@@ -107,7 +145,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let market_open = search.matches()[0].market_open();
+    ///     let market_open = search.best_match().unwrap().market_open();
     ///     assert_eq!(market_open, "09:30");
     /// }
     #[must_use]
@@ -122,7 +160,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let market_close = search.matches()[0].market_close();
+    ///     let market_close = search.best_match().unwrap().market_close();
     ///     assert_eq!(market_close, "16:00");
     /// }
     #[must_use]
@@ -143,7 +181,7 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let currency = search.matches()[0].currency();
+    ///     let currency = search.best_match().unwrap().currency();
     ///     assert_eq!(currency, "USD");
     /// }
     #[must_use]
@@ -158,17 +196,399 @@ impl Match {
     /// async fn main() {
     ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
     ///     let search = api.search("BA").json().await.unwrap();
-    ///     let match_score = search.matches()[0].match_score();
+    ///     let match_score = search.best_match().unwrap().match_score();
     ///     assert_eq!(match_score, 1.0);
     /// }
     #[must_use]
     pub fn match_score(&self) -> f64 {
-        self.match_score
+        self.score
+    }
+
+    /// Create `QuoteBuilder` for symbol of match
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("tesco").json().await.unwrap();
+    ///     let quote = search.best_match().unwrap().quote(&api).json().await.unwrap();
+    ///     println!("{}", quote.price());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn quote<'a>(&'a self, api: &'a ApiClient) -> QuoteBuilder<'a> {
+        api.quote(&self.symbol)
+    }
+
+    /// Create `TimeSeriesBuilder` for symbol of match using stock function
+    ///
+    /// ```no_run
+    /// use alpha_vantage::stock_time::StockFunction;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("tesco").json().await.unwrap();
+    ///     let stock_time = search
+    ///         .best_match()
+    ///         .unwrap()
+    ///         .stock_time(&api, StockFunction::Daily)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", stock_time.data().len());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn stock_time<'a>(
+        &'a self,
+        api: &'a ApiClient,
+        function: StockFunction,
+    ) -> TimeSeriesBuilder<'a> {
+        api.stock_time(function, &self.symbol)
+    }
+
+    /// Return market open time parsed from `HH:MM` format
+    ///
+    /// # Errors
+    /// Raise error if market open time cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn market_open_time(&self) -> Result<NaiveTime> {
+        parse_market_time(&self.market_open)
+    }
+
+    /// Return market close time parsed from `HH:MM` format
+    ///
+    /// # Errors
+    /// Raise error if market close time cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn market_close_time(&self) -> Result<NaiveTime> {
+        parse_market_time(&self.market_close)
+    }
+
+    /// Return UTC offset of market parsed from time zone such as `UTC-05`,
+    /// `UTC+05:30` or `UTC+5.5`
+    ///
+    /// # Errors
+    /// Raise error if time zone cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn utc_offset(&self) -> Result<FixedOffset> {
+        let error = || Error::ParseDateTime(self.time_zone.clone());
+        let offset = self
+            .time_zone
+            .trim()
+            .strip_prefix("UTC")
+            .ok_or_else(error)?;
+        if offset.is_empty() {
+            return FixedOffset::east_opt(0).ok_or_else(error);
+        }
+        let (sign, offset) = match offset.split_at(1) {
+            ("+", offset) => (1, offset),
+            ("-", offset) => (-1, offset),
+            _ => return Err(error()),
+        };
+        let minutes = if let Some((hour, minute)) = offset.split_once(':') {
+            let hour = hour.parse::<i32>().map_err(|_| error())?;
+            let minute = minute.parse::<i32>().map_err(|_| error())?;
+            hour * 60 + minute
+        } else if let Some((hour, fraction)) = offset.split_once('.') {
+            let hour = hour.parse::<i32>().map_err(|_| error())?;
+            let fraction = format!("0.{fraction}")
+                .parse::<f64>()
+                .map_err(|_| error())?;
+            #[allow(clippy::cast_possible_truncation)]
+            let minute = (fraction * 60.0).round() as i32;
+            hour * 60 + minute
+        } else {
+            offset.parse::<i32>().map_err(|_| error())? * 60
+        };
+        FixedOffset::east_opt(sign * minutes * 60).ok_or_else(error)
+    }
+
+    /// Check whether market is open at given time. Market is considered closed
+    /// during weekend of market local time, holidays are not considered.
+    ///
+    /// ```no_run
+    /// use chrono::Utc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("reliance").json().await.unwrap();
+    ///     for data in search.matches() {
+    ///         let open = data.is_open_at(Utc::now()).unwrap();
+    ///         println!("{} {open}", data.symbol());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if market time or time zone cannot be parsed
+    #[cfg(feature = "chrono")]
+    pub fn is_open_at(&self, date_time: DateTime<Utc>) -> Result<bool> {
+        let local = date_time.with_timezone(&self.utc_offset()?);
+        if matches!(local.weekday(), Weekday::Sat | Weekday::Sun) {
+            return Ok(false);
+        }
+        let open = self.market_open_time()?;
+        let close = self.market_close_time()?;
+        let time = local.time();
+        if open <= close {
+            Ok(open <= time && time < close)
+        } else {
+            Ok(open <= time || time < close)
+        }
+    }
+}
+
+/// Enum for type of security returned by search. Type is parsed case
+/// insensitively and unknown type is stored as `Other`
+///
+/// ```
+/// use alpha_vantage::search::StockType;
+///
+/// for (raw, stock_type) in [
+///     ("Equity", StockType::Equity),
+///     ("ETF", StockType::Etf),
+///     ("Mutual Fund", StockType::MutualFund),
+/// ] {
+///     assert_eq!(raw.parse::<StockType>().unwrap(), stock_type);
+///     assert_eq!(stock_type.to_string(), raw);
+/// }
+/// assert_eq!("etf".parse::<StockType>().unwrap(), StockType::Etf);
+/// let other = "Warrant".parse::<StockType>().unwrap();
+/// assert_eq!(other, StockType::Other("Warrant".to_string()));
+/// assert_eq!(other.to_string(), "Warrant");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StockType {
+    /// Equity
+    Equity,
+    /// Exchange traded fund
+    Etf,
+    /// Mutual fund
+    MutualFund,
+    /// Type not known by library
+    Other(String),
+}
+
+impl StockType {
+    fn as_str(&self) -> &str {
+        match self {
+            StockType::Equity => "Equity",
+            StockType::Etf => "ETF",
+            StockType::MutualFund => "Mutual Fund",
+            StockType::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for StockType {
+    fn from(value: &str) -> Self {
+        [StockType::Equity, StockType::Etf, StockType::MutualFund]
+            .into_iter()
+            .find(|stock_type| stock_type.as_str().eq_ignore_ascii_case(value.trim()))
+            .unwrap_or_else(|| StockType::Other(value.to_string()))
+    }
+}
+
+impl std::str::FromStr for StockType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
     }
 }
 
+impl std::fmt::Display for StockType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Enum for region of listing returned by search. Region is parsed case
+/// insensitively and unknown region is stored as `Other`
+///
+/// ```
+/// use alpha_vantage::search::Region;
+///
+/// for (raw, region) in [
+///     ("United States", Region::UnitedStates),
+///     ("United Kingdom", Region::UnitedKingdom),
+///     ("Frankfurt", Region::Frankfurt),
+///     ("XETRA", Region::Xetra),
+///     ("Toronto", Region::Toronto),
+///     ("Toronto Venture", Region::TorontoVenture),
+///     ("Amsterdam", Region::Amsterdam),
+///     ("Paris", Region::Paris),
+///     ("Brussels", Region::Brussels),
+///     ("Lisbon", Region::Lisbon),
+///     ("India/Bombay", Region::India),
+///     ("Brazil/Sao Paolo", Region::Brazil),
+///     ("Shanghai", Region::Shanghai),
+///     ("Shenzhen", Region::Shenzhen),
+///     ("Tokyo", Region::Tokyo),
+/// ] {
+///     assert_eq!(raw.parse::<Region>().unwrap(), region);
+///     assert_eq!(region.to_string(), raw);
+/// }
+/// assert_eq!("united states".parse::<Region>().unwrap(), Region::UnitedStates);
+/// let other = "Mars".parse::<Region>().unwrap();
+/// assert_eq!(other, Region::Other("Mars".to_string()));
+/// assert_eq!(other.to_string(), "Mars");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// United States
+    UnitedStates,
+    /// United Kingdom
+    UnitedKingdom,
+    /// Frankfurt
+    Frankfurt,
+    /// XETRA
+    Xetra,
+    /// Toronto
+    Toronto,
+    /// Toronto Venture
+    TorontoVenture,
+    /// Amsterdam
+    Amsterdam,
+    /// Paris
+    Paris,
+    /// Brussels
+    Brussels,
+    /// Lisbon
+    Lisbon,
+    /// India/Bombay
+    India,
+    /// Brazil/Sao Paolo
+    Brazil,
+    /// Shanghai
+    Shanghai,
+    /// Shenzhen
+    Shenzhen,
+    /// Tokyo
+    Tokyo,
+    /// Region not known by library
+    Other(String),
+}
+
+impl Region {
+    const KNOWN: [Region; 15] = [
+        Region::UnitedStates,
+        Region::UnitedKingdom,
+        Region::Frankfurt,
+        Region::Xetra,
+        Region::Toronto,
+        Region::TorontoVenture,
+        Region::Amsterdam,
+        Region::Paris,
+        Region::Brussels,
+        Region::Lisbon,
+        Region::India,
+        Region::Brazil,
+        Region::Shanghai,
+        Region::Shenzhen,
+        Region::Tokyo,
+    ];
+
+    fn as_str(&self) -> &str {
+        match self {
+            Region::UnitedStates => "United States",
+            Region::UnitedKingdom => "United Kingdom",
+            Region::Frankfurt => "Frankfurt",
+            Region::Xetra => "XETRA",
+            Region::Toronto => "Toronto",
+            Region::TorontoVenture => "Toronto Venture",
+            Region::Amsterdam => "Amsterdam",
+            Region::Paris => "Paris",
+            Region::Brussels => "Brussels",
+            Region::Lisbon => "Lisbon",
+            Region::India => "India/Bombay",
+            Region::Brazil => "Brazil/Sao Paolo",
+            Region::Shanghai => "Shanghai",
+            Region::Shenzhen => "Shenzhen",
+            Region::Tokyo => "Tokyo",
+            Region::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for Region {
+    fn from(value: &str) -> Self {
+        Region::KNOWN
+            .into_iter()
+            .find(|region| region.as_str().eq_ignore_ascii_case(value.trim()))
+            .unwrap_or_else(|| Region::Other(value.to_string()))
+    }
+}
+
+impl std::str::FromStr for Region {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl std::fmt::Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn parse_market_time(time: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(time.trim(), "%H:%M")
+        .map_err(|_| Error::ParseDateTime(time.to_string()))
+}
+
+/// Listings of same company in different region returned by
+/// [`Search::grouped`]
+#[derive(Debug, Clone)]
+pub struct MatchGroup<'a> {
+    listings: Vec<&'a Match>,
+}
+
+impl<'a> MatchGroup<'a> {
+    /// Return name of company as returned for primary listing
+    #[must_use]
+    pub fn name(&self) -> &'a str {
+        &self.primary().name
+    }
+
+    /// Return all listings sorted by match score in descending order
+    #[must_use]
+    pub fn listings(&self) -> &[&'a Match] {
+        &self.listings
+    }
+
+    /// Return highest scoring listing
+    #[must_use]
+    pub fn primary(&self) -> &'a Match {
+        // group is never created without listing
+        self.listings[0]
+    }
+}
+
+/// Normalize company name by case folding and replacing punctuation with space
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Compare match by score, treating smaller symbol as greater on tie
+fn compare_score(a: &Match, b: &Match) -> std::cmp::Ordering {
+    a.score
+        .total_cmp(&b.score)
+        .then_with(|| b.symbol.cmp(&a.symbol))
+}
+
 /// struct for storing search method data
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct Search {
     matches: Vec<Match>,
 }
@@ -179,6 +599,378 @@ impl Search {
     pub fn matches(&self) -> &Vec<Match> {
         &self.matches
     }
+
+    /// Return match with highest match score. Matches with equal score are
+    /// ordered by symbol so smallest symbol is returned
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("bank").json().await.unwrap();
+    ///     if let Some(best) = search.best_match() {
+    ///         println!("{}", best.symbol());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn best_match(&self) -> Option<&Match> {
+        self.matches.iter().max_by(|a, b| compare_score(a, b))
+    }
+
+    /// Return owned match with highest match score. Ties are broken in same
+    /// way as [`Search::best_match`]
+    #[must_use]
+    pub fn into_best_match(self) -> Option<Match> {
+        self.matches.into_iter().max_by(compare_score)
+    }
+
+    /// Return matches re-ranked by blending match score returned by API with
+    /// similarity of query against name and symbol of match. Matches with
+    /// equal blended score keep order returned by API
+    ///
+    /// ```no_run
+    /// use alpha_vantage::search::RerankWeights;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("tesla").json().await.unwrap();
+    ///     let weights = RerankWeights::default().name(0.5);
+    ///     for data in search.rerank("tesla", weights) {
+    ///         println!("{}", data.symbol());
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    #[must_use]
+    pub fn rerank(&self, query: &str, weights: RerankWeights) -> Vec<&Match> {
+        let query = query.trim().to_lowercase();
+        let mut ranked = self
+            .matches
+            .iter()
+            .map(|data| (weights.blend(&query, data), data))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranked.into_iter().map(|(_, data)| data).collect()
+    }
+
+    /// Return matches grouped by normalized company name. Name is normalized
+    /// by case folding, replacing punctuation with space and collapsing
+    /// whitespace so `Vodafone Group Plc` and `VODAFONE GROUP PLC.` are in
+    /// same group while `Vodafone Group PLC ADR` is not. Listings of group
+    /// are sorted by match score in descending order and groups are sorted
+    /// by score of their best listing
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("vodafone").json().await.unwrap();
+    ///     for group in search.grouped() {
+    ///         println!("{} {}", group.name(), group.primary().symbol());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn grouped(&self) -> Vec<MatchGroup<'_>> {
+        let mut groups: Vec<(String, Vec<&Match>)> = Vec::new();
+        for data in &self.matches {
+            let key = normalize_name(&data.name);
+            match groups.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, listings)) => listings.push(data),
+                None => groups.push((key, vec![data])),
+            }
+        }
+        let mut groups = groups
+            .into_iter()
+            .map(|(_, listings)| MatchGroup {
+                listings: listings.sorted_by_score(),
+            })
+            .collect::<Vec<_>>();
+        groups.sort_by(|a, b| b.primary().score.total_cmp(&a.primary().score));
+        groups
+    }
+
+    /// Return only highest scoring listing for each normalized company name.
+    /// Name is normalized in same way as [`Search::grouped`]
+    #[must_use]
+    pub fn dedup_primary(&self) -> Vec<&Match> {
+        self.grouped().iter().map(MatchGroup::primary).collect()
+    }
+
+    /// Return true if search does not contain any match
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Return matches for which `predicate` returns true
+    #[must_use]
+    pub fn filter<F>(&self, predicate: F) -> Vec<&Match>
+    where
+        F: Fn(&Match) -> bool,
+    {
+        self.matches.iter().collect::<Vec<_>>().filter(predicate)
+    }
+
+    /// Return matches listed in region. Region is compared case insensitively
+    ///
+    /// ```no_run
+    /// use alpha_vantage::search::MatchFilter;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let search = api.search("bank").json().await.unwrap();
+    ///     let us_equity = search
+    ///         .by_region("united states")
+    ///         .by_type("equity")
+    ///         .min_score(0.5);
+    ///     println!("{}", us_equity.len());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn by_region(&self, region: &str) -> Vec<&Match> {
+        self.matches.iter().collect::<Vec<_>>().by_region(region)
+    }
+
+    /// Return matches of stock type. Stock type is compared case insensitively
+    #[must_use]
+    pub fn by_type(&self, stock_type: &str) -> Vec<&Match> {
+        self.matches.iter().collect::<Vec<_>>().by_type(stock_type)
+    }
+
+    /// Return matches traded in currency. Currency is compared case
+    /// insensitively
+    #[must_use]
+    pub fn by_currency(&self, currency: &str) -> Vec<&Match> {
+        self.matches
+            .iter()
+            .collect::<Vec<_>>()
+            .by_currency(currency)
+    }
+
+    /// Return matches whose match score is greater than or equal to score
+    #[must_use]
+    pub fn min_score(&self, score: f64) -> Vec<&Match> {
+        self.matches.iter().collect::<Vec<_>>().min_score(score)
+    }
+
+    /// Return matches sorted by match score in descending order
+    #[must_use]
+    pub fn sorted_by_score(&self) -> Vec<&Match> {
+        self.matches.iter().collect::<Vec<_>>().sorted_by_score()
+    }
+
+    pub(crate) fn into_matches(self) -> Vec<Match> {
+        self.matches
+    }
+}
+
+/// Trait which helps for chaining filter and sort operation on borrowed
+/// matches returned by `Search` without cloning matches
+pub trait MatchFilter<'a> {
+    /// Keep matches for which `predicate` returns true
+    #[must_use]
+    fn filter<F>(self, predicate: F) -> Vec<&'a Match>
+    where
+        F: Fn(&Match) -> bool;
+
+    /// Keep matches listed in region. Region is compared case insensitively
+    #[must_use]
+    fn by_region(self, region: &str) -> Vec<&'a Match>;
+
+    /// Keep matches of stock type. Stock type is compared case insensitively
+    #[must_use]
+    fn by_type(self, stock_type: &str) -> Vec<&'a Match>;
+
+    /// Keep matches traded in currency. Currency is compared case
+    /// insensitively
+    #[must_use]
+    fn by_currency(self, currency: &str) -> Vec<&'a Match>;
+
+    /// Keep matches whose match score is greater than or equal to score
+    #[must_use]
+    fn min_score(self, score: f64) -> Vec<&'a Match>;
+
+    /// Sort matches by match score in descending order. Matches with same
+    /// score keep their order
+    #[must_use]
+    fn sorted_by_score(self) -> Vec<&'a Match>;
+}
+
+impl<'a> MatchFilter<'a> for Vec<&'a Match> {
+    fn filter<F>(self, predicate: F) -> Vec<&'a Match>
+    where
+        F: Fn(&Match) -> bool,
+    {
+        self.into_iter().filter(|data| predicate(data)).collect()
+    }
+
+    fn by_region(self, region: &str) -> Vec<&'a Match> {
+        MatchFilter::filter(self, |data| data.region.eq_ignore_ascii_case(region))
+    }
+
+    fn by_type(self, stock_type: &str) -> Vec<&'a Match> {
+        MatchFilter::filter(self, |data| {
+            data.stock_type.eq_ignore_ascii_case(stock_type)
+        })
+    }
+
+    fn by_currency(self, currency: &str) -> Vec<&'a Match> {
+        MatchFilter::filter(self, |data| data.currency.eq_ignore_ascii_case(currency))
+    }
+
+    fn min_score(self, score: f64) -> Vec<&'a Match> {
+        MatchFilter::filter(self, |data| data.score >= score)
+    }
+
+    fn sorted_by_score(mut self) -> Vec<&'a Match> {
+        self.sort_by(|a, b| b.score.total_cmp(&a.score));
+        self
+    }
+}
+
+/// Weights used by [`Search::rerank`] for blending match score returned by
+/// API with similarity of query against name and symbol. Similarity is
+/// normalized between 0 and 1 using Jaro-Winkler distance
+#[cfg(feature = "fuzzy")]
+#[derive(Debug, Clone, Copy)]
+pub struct RerankWeights {
+    score: f64,
+    name: f64,
+    symbol: f64,
+}
+
+#[cfg(feature = "fuzzy")]
+impl Default for RerankWeights {
+    fn default() -> Self {
+        Self {
+            score: 0.5,
+            name: 0.3,
+            symbol: 0.2,
+        }
+    }
+}
+
+#[cfg(feature = "fuzzy")]
+impl RerankWeights {
+    /// Set weight of match score returned by API. Defaults to 0.5
+    #[must_use]
+    pub fn score(mut self, score: f64) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Set weight of similarity between query and name. Defaults to 0.3
+    #[must_use]
+    pub fn name(mut self, name: f64) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Set weight of similarity between query and symbol. Defaults to 0.2
+    #[must_use]
+    pub fn symbol(mut self, symbol: f64) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Blend score of match for lower cased query
+    fn blend(&self, query: &str, data: &Match) -> f64 {
+        let name = strsim::jaro_winkler(query, &data.name.to_lowercase());
+        let symbol = strsim::jaro_winkler(query, &data.symbol.to_lowercase());
+        self.score * data.score + self.name * name + self.symbol * symbol
+    }
+}
+
+/// Preferences used by [`ApiClient::resolve_symbol`] for selecting a match.
+/// All comparisons are case insensitive
+#[derive(Debug, Clone)]
+pub struct ResolvePrefs {
+    region: Option<String>,
+    currency: Option<String>,
+    stock_type: Option<String>,
+    fallback: bool,
+}
+
+impl Default for ResolvePrefs {
+    fn default() -> Self {
+        Self {
+            region: None,
+            currency: None,
+            stock_type: None,
+            fallback: true,
+        }
+    }
+}
+
+impl ResolvePrefs {
+    /// Prefer match listed in region such as `United States`
+    #[must_use]
+    pub fn region<S: Into<String>>(mut self, region: S) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Prefer match traded in currency such as `USD`
+    #[must_use]
+    pub fn currency<S: Into<String>>(mut self, currency: S) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    /// Prefer match of stock type such as `Equity`
+    #[must_use]
+    pub fn stock_type<S: Into<String>>(mut self, stock_type: S) -> Self {
+        self.stock_type = Some(stock_type.into());
+        self
+    }
+
+    /// Define whether best overall match is returned when no match satisfies
+    /// preferences. Defaults to true
+    #[must_use]
+    pub fn fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    fn is_satisfied_by(&self, data: &Match) -> bool {
+        let satisfies = |preference: &Option<String>, value: &str| {
+            preference
+                .as_ref()
+                .is_none_or(|preference| preference.eq_ignore_ascii_case(value))
+        };
+        satisfies(&self.region, &data.region)
+            && satisfies(&self.currency, &data.currency)
+            && satisfies(&self.stock_type, &data.stock_type)
+    }
+
+    /// Select best match for keywords. Match satisfying preferences is
+    /// selected first, ties are broken by exact symbol equality, match score
+    /// and then symbol
+    pub(crate) fn select(&self, keywords: &str, matches: Vec<Match>) -> Result<Match> {
+        let is_exact = |data: &Match| data.symbol.eq_ignore_ascii_case(keywords.trim());
+        let best = |candidates: Vec<Match>| {
+            candidates.into_iter().max_by(|a, b| {
+                is_exact(a)
+                    .cmp(&is_exact(b))
+                    .then_with(|| compare_score(a, b))
+            })
+        };
+        let (preferred, others): (Vec<Match>, Vec<Match>) = matches
+            .into_iter()
+            .partition(|data| self.is_satisfied_by(data));
+        let selected = if preferred.is_empty() && self.fallback {
+            best(others)
+        } else {
+            best(preferred)
+        };
+        selected.ok_or_else(|| Error::NoMatchFound {
+            keywords: keywords.to_string(),
+        })
+    }
 }
 
 /// struct for helping creation of search struct
@@ -204,25 +996,303 @@ impl SearchHelper {
     }
 }
 
-/// Builder to create new `Search`
+/// Builder to create new `Search`. Keywords are percent-encoded before being
+/// added to url of request
+///
+/// ```no_run
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let search = api.search("tesco & co plc").json().await.unwrap();
+///     println!("{}", search.matches().len());
+/// }
+/// ```
 pub struct SearchBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     keywords: &'a str,
+    error_on_empty: bool,
 }
 
 impl<'a> SearchBuilder<'a> {
-    crate::json_data_struct!(Search, SearchHelper);
-
     /// Create new `SearchBuilder` from `APIClient`
     #[must_use]
     pub fn new(api_client: &'a ApiClient, keywords: &'a str) -> Self {
         Self {
             api_client,
+            provider: None,
             keywords,
+            error_on_empty: false,
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Define whether search returning empty matches should raise
+    /// `Error::NoMatchFound` instead of returning empty `Search`. Error is
+    /// wrapped with context of request same as other error of request
+    ///
+    /// ```no_run
+    /// use alpha_vantage::error::Error;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let result = api.search("qwertyuiop").error_on_empty(true).json().await;
+    ///     if let Err(error) = result {
+    ///         assert!(matches!(error.inner(), Error::NoMatchFound { .. }));
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn error_on_empty(mut self, error_on_empty: bool) -> Self {
+        self.error_on_empty = error_on_empty;
+        self
+    }
+
+    /// Returns JSON data
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors. Also raise error if no match
+    /// is found and builder is set to raise error on empty matches
+    pub async fn json(&self) -> Result<Search> {
+        let url = self.create_url();
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_json_with(&url, None, |helper: SearchHelper| {
+                let search = helper.convert()?;
+                if self.error_on_empty && search.is_empty() {
+                    return Err(Error::NoMatchFound {
+                        keywords: self.keywords.to_string(),
+                    });
+                }
+                Ok(search)
+            })
+            .await
+    }
+
     fn create_url(&self) -> String {
-        format!("query?function=SYMBOL_SEARCH&keywords={}", self.keywords)
+        format!(
+            "query?function=SYMBOL_SEARCH&keywords={}",
+            encode_query_value(self.keywords)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    use chrono::{NaiveTime, TimeZone, Utc};
+
+    #[cfg(feature = "fuzzy")]
+    use super::RerankWeights;
+    use super::{Match, MatchFilter, Search};
+    use crate::error::Error;
+    use crate::stock_time::StockFunction;
+    use crate::test_utils::FixtureClient;
+
+    async fn fetch(path: &str) -> Search {
+        let api = crate::set_api("demo", FixtureClient::new(path));
+        api.search("keywords").json().await.unwrap()
+    }
+
+    fn symbols(matches: &[&Match]) -> Vec<String> {
+        matches
+            .iter()
+            .map(|data| data.symbol().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn quote_and_stock_time_of_match() {
+        let client = FixtureClient::new("quote/tesco.json")
+            .route("SYMBOL_SEARCH", "search/tesco.json")
+            .route("TIME_SERIES_DAILY", "stock_time/daily.json");
+        let api = crate::set_api("demo", client.clone());
+        let search = api.search("tesco").json().await.unwrap();
+        let best = search.best_match().unwrap();
+
+        let quote = best.quote(&api).json().await.unwrap();
+        assert_eq!(quote.symbol(), "TSCO.LON");
+        let stock_time = best
+            .stock_time(&api, StockFunction::Daily)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(stock_time.symbol(), "TSCO.LON");
+        assert_eq!(stock_time.data().len(), 2);
+
+        let urls = client.urls();
+        assert!(urls[1].contains("function=GLOBAL_QUOTE&symbol=TSCO.LON"));
+        assert!(urls[2].contains("function=TIME_SERIES_DAILY&symbol=TSCO.LON"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn market_hours() {
+        let search = fetch("search/reliance.json").await;
+
+        // indian listing opens at 09:15 UTC+05:30 which is 03:45 UTC
+        let india = search.best_match().unwrap();
+        assert_eq!(india.utc_offset().unwrap().local_minus_utc(), 19800);
+        assert_eq!(
+            india.market_open_time().unwrap(),
+            NaiveTime::from_hms_opt(9, 15, 0).unwrap()
+        );
+        let before_open = Utc.with_ymd_and_hms(2024, 2, 15, 3, 44, 0).unwrap();
+        let at_open = Utc.with_ymd_and_hms(2024, 2, 15, 3, 45, 0).unwrap();
+        assert!(!india.is_open_at(before_open).unwrap());
+        assert!(india.is_open_at(at_open).unwrap());
+
+        // us listing closes at 16:00 UTC-04 which is 20:00 UTC
+        let us = &search.matches()[2];
+        let before_close = Utc.with_ymd_and_hms(2024, 2, 15, 19, 59, 0).unwrap();
+        let at_close = Utc.with_ymd_and_hms(2024, 2, 15, 20, 0, 0).unwrap();
+        assert!(us.is_open_at(before_close).unwrap());
+        assert!(!us.is_open_at(at_close).unwrap());
+        // 2024-02-17 is saturday
+        let saturday = Utc.with_ymd_and_hms(2024, 2, 17, 15, 0, 0).unwrap();
+        assert!(!us.is_open_at(saturday).unwrap());
+    }
+
+    #[tokio::test]
+    async fn best_match_breaks_tie_by_symbol() {
+        let search = fetch("search/bank.json").await;
+        assert_eq!(search.best_match().unwrap().symbol(), "BAC");
+
+        // SHOP.TRT and SHOP have same score
+        let search = fetch("search/tie.json").await;
+        assert_eq!(search.matches()[0].symbol(), "SHOP.TRT");
+        assert_eq!(search.best_match().unwrap().symbol(), "SHOP");
+        assert_eq!(search.into_best_match().unwrap().symbol(), "SHOP");
+
+        let search = fetch("search/empty.json").await;
+        assert!(search.best_match().is_none());
+    }
+
+    #[cfg(feature = "fuzzy")]
+    #[tokio::test]
+    async fn rerank_by_name_and_symbol() {
+        let search = fetch("search/tesla.json").await;
+        // frankfurt listing is ranked above primary listing by API
+        assert_eq!(search.best_match().unwrap().symbol(), "TL0.FRK");
+
+        let reranked = search.rerank("tesla", RerankWeights::default());
+        assert_eq!(symbols(&reranked), ["TSLA", "TL0.FRK", "TSLA34.SAO"]);
+
+        // only using match score reproduces API ordering
+        let weights = RerankWeights::default().name(0.0).symbol(0.0);
+        assert_eq!(
+            symbols(&search.rerank("tesla", weights)),
+            ["TL0.FRK", "TSLA", "TSLA34.SAO"]
+        );
+    }
+
+    #[tokio::test]
+    async fn grouped_by_normalized_name() {
+        let search = fetch("search/vodafone.json").await;
+        let groups = search.grouped();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].name(), "Vodafone Group PLC");
+        assert_eq!(
+            symbols(groups[0].listings()),
+            ["VOD.LON", "VODI.DEX", "VODI.FRK"]
+        );
+        assert_eq!(groups[0].primary().symbol(), "VOD.LON");
+        assert_eq!(symbols(groups[1].listings()), ["VOD"]);
+        assert_eq!(symbols(groups[2].listings()), ["IDEA.BSE"]);
+
+        assert_eq!(
+            symbols(&search.dedup_primary()),
+            ["VOD.LON", "VOD", "IDEA.BSE"]
+        );
+    }
+
+    #[tokio::test]
+    async fn filter_matches() {
+        let search = fetch("search/bank.json").await;
+        assert_eq!(search.matches().len(), 12);
+
+        // one of listing have lower case region
+        assert_eq!(search.by_region("UNITED STATES").len(), 7);
+        let us_equity = search
+            .by_region("united states")
+            .by_type("equity")
+            .min_score(0.5);
+        assert_eq!(symbols(&us_equity), ["BAC", "BMO", "BNS"]);
+        assert_eq!(symbols(&search.by_currency("eur")), ["BIRG.DEX", "CBK.DEX"]);
+        assert_eq!(
+            symbols(&search.by_type("ETF").sorted_by_score()),
+            ["BKLN", "KBE"]
+        );
+        assert_eq!(
+            symbols(&search.filter(|data| data.name().starts_with("Bank of Montreal"))),
+            ["BMO", "BMO.TRT"]
+        );
+        let sorted = search.sorted_by_score();
+        assert_eq!(sorted[0].symbol(), "BAC");
+        assert_eq!(sorted[11].symbol(), "8306.TYO");
+    }
+
+    #[tokio::test]
+    async fn keywords_are_encoded() {
+        let client = FixtureClient::new("search/tesco.json");
+        let api = crate::set_api("demo", client.clone());
+        api.search("tesco & co plc").json().await.unwrap();
+        api.search("^GSPC").json().await.unwrap();
+        api.search("tesco%20plc").json().await.unwrap();
+        assert_eq!(
+            client.urls(),
+            [
+                "https://www.alphavantage.co/query?function=SYMBOL_SEARCH&keywords=tesco%20%26%20co%20plc&apikey=demo",
+                "https://www.alphavantage.co/query?function=SYMBOL_SEARCH&keywords=%5EGSPC&apikey=demo",
+                // already encoded value is not encoded again
+                "https://www.alphavantage.co/query?function=SYMBOL_SEARCH&keywords=tesco%20plc&apikey=demo",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn error_on_empty() {
+        let search = fetch("search/empty.json").await;
+        assert!(search.is_empty());
+
+        let api = crate::set_api("demo", FixtureClient::new("search/tesco.json"));
+        let search = api
+            .search("tesco")
+            .error_on_empty(true)
+            .json()
+            .await
+            .unwrap();
+        assert!(!search.is_empty());
+        assert_eq!(search.matches().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn no_match_is_wrapped_with_request() {
+        let api = crate::set_api("demo", FixtureClient::new("search/empty.json"));
+        let error = api
+            .search("qwertyuiop")
+            .error_on_empty(true)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::Request { endpoint, .. } if endpoint == "SYMBOL_SEARCH"
+        ));
+        assert!(matches!(
+            error.inner(),
+            Error::NoMatchFound { keywords } if keywords == "qwertyuiop"
+        ));
     }
 }
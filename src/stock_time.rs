@@ -12,15 +12,23 @@
 
 use std::cmp;
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::str::FromStr;
+use std::time::Duration;
 
 use serde::Deserialize;
 
-use crate::api::{ApiClient, OutputSize, TimeSeriesInterval};
+use crate::api::{encode_query_value, ApiClient, OutputSize, TimeSeriesInterval, Provider};
 use crate::deserialize::from_str;
 use crate::error::{detect_common_helper_error, Error, Result};
 use crate::vec_trait::FindData;
 
+/// Time to live of cached intraday time series
+const INTRADAY_TTL: Duration = Duration::from_mins(1);
+
+/// Time to live of cached daily, weekly and monthly time series
+const SERIES_TTL: Duration = Duration::from_hours(6);
+
 /// Struct for storing Meta Data value
 #[derive(Debug, Clone, Default)]
 pub struct MetaData {
@@ -325,12 +333,12 @@ impl TimeSeriesHelper {
         });
 
         let meta_data = MetaData {
-            information: information.to_string(),
-            symbol: symbol.to_string(),
-            last_refreshed: last_refreshed.to_string(),
+            information: information.clone(),
+            symbol: symbol.clone(),
+            last_refreshed: last_refreshed.clone(),
             interval: interval.map(ToString::to_string),
             output_size: output_size.map(ToString::to_string),
-            time_zone: time_zone.to_string(),
+            time_zone: time_zone.clone(),
         };
 
         let mut data_value: Vec<Data> = Vec::new();
@@ -343,7 +351,7 @@ impl TimeSeriesHelper {
                         .expect("failed to get value from stock time hashmap");
 
                     data_value.push(Data {
-                        time: val.to_string(),
+                        time: val.clone(),
                         open: data_helper.open,
                         high: data_helper.high,
                         low: data_helper.low,
@@ -363,15 +371,15 @@ impl TimeSeriesHelper {
                         .expect("failed to get value from adjusted series");
 
                     data_value.push(Data {
-                        time: val.to_string(),
+                        time: val.clone(),
                         open: data_helper.open,
                         high: data_helper.high,
                         low: data_helper.low,
                         close: data_helper.close,
                         volume: data_helper.volume,
-                        adjusted_close: option_from_str(&data_helper.adjusted_close),
-                        split_coefficient: option_from_str(&data_helper.split_coefficient),
-                        dividend_amount: option_from_str(&data_helper.dividend_amount),
+                        adjusted_close: option_from_str(data_helper.adjusted_close.as_ref()),
+                        split_coefficient: option_from_str(data_helper.split_coefficient.as_ref()),
+                        dividend_amount: option_from_str(data_helper.dividend_amount.as_ref()),
                     });
                 }
             }
@@ -385,12 +393,10 @@ impl TimeSeriesHelper {
 }
 
 impl FindData for Vec<Data> {
-    #[must_use]
     fn find(&self, time: &str) -> Option<&<Self as IntoIterator>::Item> {
         self.iter().find(|&data| data.time == time)
     }
 
-    #[must_use]
     fn latest(&self) -> <Self as IntoIterator>::Item {
         let mut latest = &Data::default();
         for data in self {
@@ -420,17 +426,18 @@ impl FindData for Vec<Data> {
 }
 
 // convert string to optional T
-fn option_from_str<T>(val: &Option<String>) -> Option<T>
+fn option_from_str<T>(val: Option<&String>) -> Option<T>
 where
     T: FromStr,
     T::Err: std::error::Error,
 {
-    val.as_ref().map(|s| T::from_str(s).unwrap())
+    val.map(|s| T::from_str(s).unwrap())
 }
 
 /// Builder to create new `TimeSeries`
 pub struct TimeSeriesBuilder<'a> {
     api_client: &'a ApiClient,
+    provider: Option<Provider>,
     function: StockFunction,
     symbol: &'a str,
     interval: Option<TimeSeriesInterval>,
@@ -439,13 +446,14 @@ pub struct TimeSeriesBuilder<'a> {
 }
 
 impl<'a> TimeSeriesBuilder<'a> {
-    crate::json_data_struct!(TimeSeries, TimeSeriesHelper);
+    crate::json_data_struct!(TimeSeries, TimeSeriesHelper, cache_ttl);
 
     /// Create new `TimeSeriesBuilder` form `APIClient`
     #[must_use]
     pub fn new(api_client: &'a ApiClient, function: StockFunction, symbol: &'a str) -> Self {
         Self {
             api_client,
+            provider: None,
             function,
             symbol,
             interval: None,
@@ -454,6 +462,16 @@ impl<'a> TimeSeriesBuilder<'a> {
         }
     }
 
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    #[must_use]
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
     /// Define time series interval for intraday stock time series
     #[must_use]
     pub fn interval(mut self, interval: TimeSeriesInterval) -> Self {
@@ -476,6 +494,13 @@ impl<'a> TimeSeriesBuilder<'a> {
         self
     }
 
+    fn cache_ttl(&self) -> Duration {
+        match self.function {
+            StockFunction::IntraDay => INTRADAY_TTL,
+            _ => SERIES_TTL,
+        }
+    }
+
     fn create_url(&self) -> String {
         let function = match self.function {
             StockFunction::IntraDay => "TIME_SERIES_INTRADAY",
@@ -487,7 +512,10 @@ impl<'a> TimeSeriesBuilder<'a> {
             StockFunction::MonthlyAdjusted => "TIME_SERIES_MONTHLY_ADJUSTED",
         };
 
-        let mut url = format!("query?function={function}&symbol={}", self.symbol);
+        let mut url = format!(
+            "query?function={function}&symbol={}",
+            encode_query_value(self.symbol)
+        );
 
         if let Some(stock_time_interval) = &self.interval {
             let interval = match stock_time_interval {
@@ -497,15 +525,21 @@ impl<'a> TimeSeriesBuilder<'a> {
                 TimeSeriesInterval::ThirtyMin => "30min",
                 TimeSeriesInterval::SixtyMin => "60min",
             };
-            url.push_str(&format!("&interval={interval}"));
-        };
+            write!(url, "&interval={interval}").unwrap();
+        }
 
-        if let Some(stock_time_output_size) = &self.output_size {
+        let default_output_size = match self.function {
+            StockFunction::IntraDay | StockFunction::Daily | StockFunction::DailyAdjusted => {
+                self.api_client.defaults().output_size.as_ref()
+            }
+            _ => None,
+        };
+        if let Some(stock_time_output_size) = self.output_size.as_ref().or(default_output_size) {
             let size = match stock_time_output_size {
                 OutputSize::Full => "full",
                 OutputSize::Compact => "compact",
             };
-            url.push_str(&format!("&outputsize={size}"));
+            write!(url, "&outputsize={size}").unwrap();
         }
 
         if let Some(adjusted) = self.adjusted {
@@ -514,7 +548,7 @@ impl<'a> TimeSeriesBuilder<'a> {
             } else {
                 url.push_str("&adjusted=false");
             }
-        };
+        }
 
         url
     }
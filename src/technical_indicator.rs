@@ -9,24 +9,34 @@
 //!
 //! [technical_indicator]: https://www.alphavantage.co/documentation/#technical-indicators
 
+use std::cmp;
 use std::collections::HashMap;
+use std::fmt::Write;
 
+use futures::StreamExt;
 use serde::Deserialize;
 use serde_json::value::Value;
 
-use crate::api::ApiClient;
+use crate::api::{encode_query_value, format_float, ApiClient, Provider};
 use crate::error::{detect_common_helper_error, Error, Result};
+use crate::stock_time::{Data, TimeSeries};
 
 type DataType = HashMap<String, HashMap<String, HashMap<String, String>>>;
 
 /// Struct for storing a data values
-#[derive(Default)]
+#[derive(Default, Debug, Clone)]
 pub struct DataCollector {
     time: String,
     values: HashMap<String, f64>,
 }
 
 impl DataCollector {
+    /// Create `DataCollector` from time and values
+    #[cfg(feature = "local-indicators")]
+    pub(crate) fn new(time: String, values: HashMap<String, f64>) -> Self {
+        Self { time, values }
+    }
+
     /// Return time
     #[must_use]
     pub fn time(&self) -> &str {
@@ -40,14 +50,115 @@ impl DataCollector {
     }
 }
 
+/// trait which helps for performing some common operation on data of
+/// technical indicator. Time of data is compared as string so both daily date
+/// and intraday timestamp are supported
+///
+/// ```no_run
+/// use alpha_vantage::technical_indicator::{FindIndicatorData, TechnicalIndicatorInterval};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let rsi = api
+///         .technical_indicator("RSI", "IBM", TechnicalIndicatorInterval::Weekly)
+///         .time_period(10)
+///         .series_type("open")
+///         .json()
+///         .await
+///         .unwrap();
+///     let data = rsi.data().unwrap();
+///     if let Some(latest) = data.latest() {
+///         println!("{} {:?}", latest.time(), latest.values());
+///     }
+///     println!("{}", data.between("2024-02-01", "2024-02-09").len());
+/// }
+/// ```
+pub trait FindIndicatorData {
+    /// Find a data with a given time as a input return none if no data found
+    fn find(&self, time: &str) -> Option<&DataCollector>;
+    /// Return a data which is of latest time. Return none if there is no data
+    fn latest(&self) -> Option<&DataCollector>;
+    /// Return a top n latest data in descending order of time
+    /// # Errors
+    /// If n is greater than no of data
+    fn latest_n(&self, n: usize) -> Result<Vec<&DataCollector>>;
+    /// Return data whose time lies between start and end (both inclusive) in
+    /// ascending order of time. Date used as end includes all intraday
+    /// timestamp of that date
+    fn between(&self, start: &str, end: &str) -> Vec<&DataCollector>;
+}
+
+impl FindIndicatorData for Vec<DataCollector> {
+    fn find(&self, time: &str) -> Option<&DataCollector> {
+        self.iter().find(|&data| data.time == time)
+    }
+
+    fn latest(&self) -> Option<&DataCollector> {
+        self.iter().max_by(|a, b| a.time.cmp(&b.time))
+    }
+
+    fn latest_n(&self, n: usize) -> Result<Vec<&DataCollector>> {
+        if n > self.len() {
+            return Err(Error::DesiredNumberOfDataNotPresent(self.len()));
+        }
+        let mut full_list = self.iter().collect::<Vec<_>>();
+        full_list.sort_by_key(|data| cmp::Reverse(&data.time));
+        full_list.truncate(n);
+        Ok(full_list)
+    }
+
+    fn between(&self, start: &str, end: &str) -> Vec<&DataCollector> {
+        let mut full_list = self
+            .iter()
+            .filter(|data| {
+                data.time.as_str() >= start
+                    && (data.time.as_str() <= end || data.time.starts_with(end))
+            })
+            .collect::<Vec<_>>();
+        full_list.sort_by(|a, b| a.time.cmp(&b.time));
+        full_list
+    }
+}
+
 /// Struct for indicator
 #[derive(Default, Debug)]
 pub struct TechnicalIndicator {
     metadata: HashMap<String, Value>,
     data: DataType,
+    // Points computed locally, returned by `data` as they are so values are
+    // not rounded by formatting
+    points: Option<Vec<DataCollector>>,
 }
 
 impl TechnicalIndicator {
+    /// Create `TechnicalIndicator` from meta data and points computed
+    /// locally. Data in form returned by API is stored under `block` using
+    /// shortest form of value which parses back to same float
+    #[cfg(feature = "local-indicators")]
+    pub(crate) fn from_points(
+        metadata: HashMap<String, Value>,
+        block: String,
+        points: Vec<DataCollector>,
+    ) -> Self {
+        let values = points
+            .iter()
+            .map(|point| {
+                let values = point
+                    .values
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.to_string()))
+                    .collect();
+                (point.time.clone(), values)
+            })
+            .collect();
+        Self {
+            metadata,
+            data: HashMap::from([(block, values)]),
+            points: Some(points),
+        }
+    }
+
     /// Return meta data in hash form with key as `String` and values as
     /// `serde_json::value::Value`
     #[must_use]
@@ -55,16 +166,45 @@ impl TechnicalIndicator {
         &self.metadata
     }
 
+    /// Return typed meta data. Key of meta data is numbered differently
+    /// between indicators so meta data is matched by name of key
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let sma = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .time_period(10)
+    ///         .series_type("close")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     let meta = sma.meta();
+    ///     println!("{} {}", meta.symbol(), meta.last_refreshed());
+    ///     println!("{:?} {:?}", meta.time_period(), meta.series_type());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn meta(&self) -> IndicatorMetaData {
+        IndicatorMetaData::from_meta_data(&self.metadata)
+    }
+
     /// Return data as a vector
     ///
     /// # Errors
     /// When alpha vantage contains data in other format
     pub fn data(&self) -> Result<Vec<DataCollector>> {
+        if let Some(points) = &self.points {
+            return Ok(points.clone());
+        }
         let mut vector = Vec::new();
         for hash in self.data.values() {
             for time in hash.keys() {
                 let mut data_collector = DataCollector {
-                    time: time.to_string(),
+                    time: time.clone(),
                     ..DataCollector::default()
                 };
                 let hash_values = hash.get(time).ok_or(Error::AlphaVantageInvalidData)?;
@@ -74,7 +214,7 @@ impl TechnicalIndicator {
                         .trim()
                         .parse::<f64>()
                         .map_err(|_| Error::AlphaVantageInvalidData)?;
-                    data_collector.values.insert(key.to_string(), value_f64);
+                    data_collector.values.insert(key.clone(), value_f64);
                 }
                 vector.push(data_collector);
             }
@@ -83,140 +223,2807 @@ impl TechnicalIndicator {
     }
 }
 
-/// Struct for helping `TechnicalIndicator` struct
-#[derive(Deserialize)]
-pub(crate) struct TechnicalIndicatorHelper {
-    #[serde(rename = "Error Message")]
-    error_message: Option<String>,
-    #[serde(rename = "Information")]
-    information: Option<String>,
-    #[serde(rename = "Note")]
-    note: Option<String>,
-    #[serde(rename = "Meta Data")]
-    metadata: Option<HashMap<String, Value>>,
-    #[serde(flatten)]
-    data: Option<DataType>,
+/// Struct for storing meta data of technical indicator
+#[derive(Default, Debug, Clone)]
+pub struct IndicatorMetaData {
+    symbol: String,
+    indicator: String,
+    last_refreshed: String,
+    interval: String,
+    time_period: Option<u64>,
+    fast_period: Option<u64>,
+    slow_period: Option<u64>,
+    signal_period: Option<u64>,
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+    series_type: Option<String>,
+    time_zone: String,
+    raw: HashMap<String, String>,
 }
 
-impl TechnicalIndicatorHelper {
-    fn convert(self) -> Result<TechnicalIndicator> {
-        detect_common_helper_error(self.information, self.error_message, self.note)?;
-        if self.metadata.is_none() || self.data.is_none() {
-            return Err(Error::EmptyResponse);
+impl IndicatorMetaData {
+    /// Return symbol
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Return name of indicator
+    #[must_use]
+    pub fn indicator(&self) -> &str {
+        &self.indicator
+    }
+
+    /// Return last refreshed time
+    #[must_use]
+    pub fn last_refreshed(&self) -> &str {
+        &self.last_refreshed
+    }
+
+    /// Return interval
+    #[must_use]
+    pub fn interval(&self) -> &str {
+        &self.interval
+    }
+
+    /// Return interval as enum. Returns None if interval is not known by
+    /// library
+    #[must_use]
+    pub fn interval_enum(&self) -> Option<TechnicalIndicatorInterval> {
+        TechnicalIndicatorInterval::parse(&self.interval)
+    }
+
+    /// Return time period. Returns None if indicator does not use time period
+    #[must_use]
+    pub fn time_period(&self) -> Option<u64> {
+        self.time_period
+    }
+
+    /// Return fast period. Returns None if indicator does not use fast period
+    #[must_use]
+    pub fn fast_period(&self) -> Option<u64> {
+        self.fast_period
+    }
+
+    /// Return slow period. Returns None if indicator does not use slow period
+    #[must_use]
+    pub fn slow_period(&self) -> Option<u64> {
+        self.slow_period
+    }
+
+    /// Return signal period. Returns None if indicator does not use signal
+    /// period
+    #[must_use]
+    pub fn signal_period(&self) -> Option<u64> {
+        self.signal_period
+    }
+
+    /// Return fast limit of MAMA. Returns None if indicator does not use fast
+    /// limit
+    #[must_use]
+    pub fn fast_limit(&self) -> Option<f64> {
+        self.fast_limit
+    }
+
+    /// Return slow limit of MAMA. Returns None if indicator does not use slow
+    /// limit
+    #[must_use]
+    pub fn slow_limit(&self) -> Option<f64> {
+        self.slow_limit
+    }
+
+    /// Return series type. Returns None if indicator does not use series type
+    #[must_use]
+    pub fn series_type(&self) -> Option<&str> {
+        self.series_type.as_deref()
+    }
+
+    /// Return time zone
+    #[must_use]
+    pub fn time_zone(&self) -> &str {
+        &self.time_zone
+    }
+
+    /// Return meta data which is not known by library with its original key
+    #[must_use]
+    pub fn raw(&self) -> &HashMap<String, String> {
+        &self.raw
+    }
+
+    // Create from meta data whose keys are numbered such as `1: Symbol`
+    fn from_meta_data(metadata: &HashMap<String, Value>) -> Self {
+        let mut meta = Self::default();
+        for (key, value) in metadata {
+            let name = key.split_once(": ").map_or(key.as_str(), |(_, name)| name);
+            let value = match value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            match name {
+                "Symbol" => meta.symbol = value,
+                "Indicator" => meta.indicator = value,
+                "Last Refreshed" => meta.last_refreshed = value,
+                "Interval" => meta.interval = value,
+                "Time Period" => meta.time_period = value.trim().parse().ok(),
+                "Fast Period" => meta.fast_period = value.trim().parse().ok(),
+                "Slow Period" => meta.slow_period = value.trim().parse().ok(),
+                "Signal Period" => meta.signal_period = value.trim().parse().ok(),
+                "Fast Limit" => meta.fast_limit = value.trim().parse().ok(),
+                "Slow Limit" => meta.slow_limit = value.trim().parse().ok(),
+                "Series Type" => meta.series_type = Some(value),
+                "Time Zone" => meta.time_zone = value,
+                _ => {
+                    meta.raw.insert(key.clone(), value);
+                }
+            }
         }
-        Ok(TechnicalIndicator {
-            metadata: self.metadata.unwrap(),
-            data: self.data.unwrap(),
-        })
+        meta
     }
 }
 
-/// Builder to help create `TechnicalIndicator`
-pub struct TechnicalIndicatorBuilder<'a> {
-    api_client: &'a ApiClient,
-    function: &'a str,
-    symbol: &'a str,
-    interval: TechnicalIndicatorInterval,
-    time_period: Option<u64>,
-    series_type: Option<&'a str>,
-    extra_params: HashMap<String, String>,
+/// Struct for storing technical indicator which has single value for each
+/// time such as SMA, EMA, WMA or RSI
+#[derive(Default, Debug, Clone)]
+pub struct SingleValueIndicator {
+    meta: IndicatorMetaData,
+    points: Vec<(String, f64)>,
 }
 
-impl<'a> TechnicalIndicatorBuilder<'a> {
-    crate::json_data_struct!(TechnicalIndicator, TechnicalIndicatorHelper);
+impl SingleValueIndicator {
+    /// Return meta data
+    #[must_use]
+    pub fn meta(&self) -> &IndicatorMetaData {
+        &self.meta
+    }
 
-    /// Create new `TechnicalIndicatorBuilder` form `APIClient`
+    /// Return time and value of points in ascending order of time
     #[must_use]
-    pub fn new(
-        api_client: &'a ApiClient,
-        function: &'a str,
-        symbol: &'a str,
-        interval: TechnicalIndicatorInterval,
-    ) -> Self {
-        Self {
-            api_client,
-            function,
-            symbol,
-            interval,
-            time_period: None,
-            series_type: None,
-            extra_params: HashMap::new(),
-        }
+    pub fn points(&self) -> &[(String, f64)] {
+        &self.points
     }
+}
 
-    /// Set time period for API
-    pub fn time_period(&mut self, time: u64) -> &mut Self {
-        self.time_period = Some(time);
-        self
+impl TryFrom<TechnicalIndicator> for SingleValueIndicator {
+    type Error = Error;
+
+    fn try_from(indicator: TechnicalIndicator) -> Result<Self> {
+        let meta = IndicatorMetaData::from_meta_data(&indicator.metadata);
+        let mut points = analysis_block(indicator.data)?
+            .into_iter()
+            .map(|(time, values)| {
+                let mut values = values.into_values();
+                let value = values.next().ok_or(Error::AlphaVantageInvalidData)?;
+                if values.next().is_some() {
+                    return Err(Error::AlphaVantageInvalidData);
+                }
+                let value = value
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| Error::AlphaVantageInvalidData)?;
+                Ok((time, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        points.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(Self { meta, points })
     }
+}
 
-    /// Set series type for API
-    pub fn series_type(&mut self, series_type: &'a str) -> &mut Self {
-        self.series_type = Some(series_type);
-        self
+/// Struct for storing MACD values of time
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct MacdPoint {
+    time: String,
+    macd: f64,
+    signal: f64,
+    histogram: f64,
+}
+
+impl MacdPoint {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
     }
 
-    /// Add extra param to builder
-    pub fn extra_param<T, U>(&mut self, param: T, value: U) -> &mut Self
-    where
-        T: std::fmt::Display,
-        U: std::fmt::Display,
-    {
-        self.extra_params
-            .insert(param.to_string(), value.to_string());
-        self
+    /// Return MACD value
+    #[must_use]
+    pub fn macd(&self) -> f64 {
+        self.macd
     }
 
-    fn create_url(&self) -> String {
-        let interval_val = match self.interval {
-            TechnicalIndicatorInterval::OneMin => "1min",
-            TechnicalIndicatorInterval::FiveMin => "5min",
-            TechnicalIndicatorInterval::FifteenMin => "15min",
-            TechnicalIndicatorInterval::ThirtyMin => "30min",
-            TechnicalIndicatorInterval::SixtyMin => "60min",
-            TechnicalIndicatorInterval::Daily => "daily",
-            TechnicalIndicatorInterval::Weekly => "weekly",
-            TechnicalIndicatorInterval::Monthly => "monthly",
-        };
+    /// Return signal value
+    #[must_use]
+    pub fn signal(&self) -> f64 {
+        self.signal
+    }
 
-        let mut created_link = format!(
-            "query?function={}&symbol={}&interval={interval_val}",
-            &self.function, &self.symbol
-        );
+    /// Return histogram value
+    #[must_use]
+    pub fn histogram(&self) -> f64 {
+        self.histogram
+    }
+}
 
-        if let Some(time_period) = &self.time_period {
-            created_link.push_str(&format!("&time_period={time_period}"));
-        }
+/// Struct for storing MACD or MACDEXT technical indicator
+#[derive(Default, Debug, Clone)]
+pub struct MacdIndicator {
+    meta: IndicatorMetaData,
+    points: Vec<MacdPoint>,
+}
 
-        if let Some(series_type) = &self.series_type {
-            created_link.push_str(&format!("&series_type={series_type}"));
-        }
+impl MacdIndicator {
+    /// Return meta data
+    #[must_use]
+    pub fn meta(&self) -> &IndicatorMetaData {
+        &self.meta
+    }
 
-        for (param, value) in &self.extra_params {
-            created_link.push_str(&format!("&{param}={value}"));
-        }
+    /// Return points in ascending order of time
+    #[must_use]
+    pub fn points(&self) -> &[MacdPoint] {
+        &self.points
+    }
+}
 
-        created_link
+impl TryFrom<TechnicalIndicator> for MacdIndicator {
+    type Error = Error;
+
+    fn try_from(indicator: TechnicalIndicator) -> Result<Self> {
+        let meta = IndicatorMetaData::from_meta_data(&indicator.metadata);
+        let mut points = analysis_block(indicator.data)?
+            .into_iter()
+            .map(|(time, values)| {
+                let value = |key| parse_value(&values, &time, key);
+                Ok(MacdPoint {
+                    macd: value("MACD")?,
+                    signal: value("MACD_Signal")?,
+                    histogram: value("MACD_Hist")?,
+                    time,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        points.sort_by(|a, b| a.time.cmp(&b.time));
+        Ok(Self { meta, points })
     }
 }
 
-/// Enum for declaring interval for technical indicator
-#[derive(Clone)]
-pub enum TechnicalIndicatorInterval {
-    /// 1 min interval
-    OneMin,
-    /// 5 min interval
-    FiveMin,
-    /// 15 min interval
-    FifteenMin,
-    /// 30 min interval
-    ThirtyMin,
-    /// 60 min interval
-    SixtyMin,
-    /// daily interval
-    Daily,
-    /// weekly interval
-    Weekly,
-    /// monthly interval
-    Monthly,
+/// Struct for storing Bollinger Bands values of time
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BbandsPoint {
+    time: String,
+    upper: f64,
+    middle: f64,
+    lower: f64,
+}
+
+impl BbandsPoint {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
+    }
+
+    /// Return upper band value
+    #[must_use]
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Return middle band value
+    #[must_use]
+    pub fn middle(&self) -> f64 {
+        self.middle
+    }
+
+    /// Return lower band value
+    #[must_use]
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Return width of bands relative to middle band. Returns None if middle
+    /// band is zero
+    #[must_use]
+    pub fn bandwidth(&self) -> Option<f64> {
+        (self.middle != 0.0).then(|| (self.upper - self.lower) / self.middle)
+    }
+
+    /// Return position of close relative to bands where 0 is lower band and 1
+    /// is upper band. Returns None if upper and lower band are same
+    #[must_use]
+    pub fn percent_b(&self, close: f64) -> Option<f64> {
+        let width = self.upper - self.lower;
+        (width != 0.0).then(|| (close - self.lower) / width)
+    }
+}
+
+/// Struct for storing Bollinger Bands technical indicator
+#[derive(Default, Debug, Clone)]
+pub struct BbandsIndicator {
+    meta: IndicatorMetaData,
+    points: Vec<BbandsPoint>,
+}
+
+impl BbandsIndicator {
+    /// Return meta data
+    #[must_use]
+    pub fn meta(&self) -> &IndicatorMetaData {
+        &self.meta
+    }
+
+    /// Return points in ascending order of time
+    #[must_use]
+    pub fn points(&self) -> &[BbandsPoint] {
+        &self.points
+    }
+}
+
+impl TryFrom<TechnicalIndicator> for BbandsIndicator {
+    type Error = Error;
+
+    fn try_from(indicator: TechnicalIndicator) -> Result<Self> {
+        let meta = IndicatorMetaData::from_meta_data(&indicator.metadata);
+        let mut points = analysis_block(indicator.data)?
+            .into_iter()
+            .map(|(time, values)| {
+                let value = |key| parse_value(&values, &time, key);
+                Ok(BbandsPoint {
+                    upper: value("Real Upper Band")?,
+                    middle: value("Real Middle Band")?,
+                    lower: value("Real Lower Band")?,
+                    time,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        points.sort_by(|a, b| a.time.cmp(&b.time));
+        Ok(Self { meta, points })
+    }
+}
+
+/// Struct for storing stochastic oscillator values of time. K and D are
+/// slow values for STOCH and fast values for STOCHF
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StochPoint {
+    time: String,
+    k: f64,
+    d: f64,
+}
+
+impl StochPoint {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
+    }
+
+    /// Return K value
+    #[must_use]
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Return D value
+    #[must_use]
+    pub fn d(&self) -> f64 {
+        self.d
+    }
+}
+
+/// Struct for storing STOCH or STOCHF technical indicator
+#[derive(Default, Debug, Clone)]
+pub struct StochIndicator {
+    meta: IndicatorMetaData,
+    points: Vec<StochPoint>,
+}
+
+impl StochIndicator {
+    /// Return meta data
+    #[must_use]
+    pub fn meta(&self) -> &IndicatorMetaData {
+        &self.meta
+    }
+
+    /// Return points in ascending order of time
+    #[must_use]
+    pub fn points(&self) -> &[StochPoint] {
+        &self.points
+    }
+}
+
+impl TryFrom<TechnicalIndicator> for StochIndicator {
+    type Error = Error;
+
+    fn try_from(indicator: TechnicalIndicator) -> Result<Self> {
+        let meta = IndicatorMetaData::from_meta_data(&indicator.metadata);
+        let mut points = analysis_block(indicator.data)?
+            .into_iter()
+            .map(|(time, values)| {
+                let (k, d) = if values.contains_key("SlowK") || values.contains_key("SlowD") {
+                    ("SlowK", "SlowD")
+                } else {
+                    ("FastK", "FastD")
+                };
+                Ok(StochPoint {
+                    k: parse_value(&values, &time, k)?,
+                    d: parse_value(&values, &time, d)?,
+                    time,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        points.sort_by(|a, b| a.time.cmp(&b.time));
+        Ok(Self { meta, points })
+    }
+}
+
+/// Struct for storing AROON values of time
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AroonPoint {
+    time: String,
+    up: f64,
+    down: f64,
+}
+
+impl AroonPoint {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
+    }
+
+    /// Return Aroon up value
+    #[must_use]
+    pub fn up(&self) -> f64 {
+        self.up
+    }
+
+    /// Return Aroon down value
+    #[must_use]
+    pub fn down(&self) -> f64 {
+        self.down
+    }
+}
+
+/// Struct for storing AROON technical indicator
+#[derive(Default, Debug, Clone)]
+pub struct AroonIndicator {
+    meta: IndicatorMetaData,
+    points: Vec<AroonPoint>,
+}
+
+impl AroonIndicator {
+    /// Return meta data
+    #[must_use]
+    pub fn meta(&self) -> &IndicatorMetaData {
+        &self.meta
+    }
+
+    /// Return points in ascending order of time
+    #[must_use]
+    pub fn points(&self) -> &[AroonPoint] {
+        &self.points
+    }
+}
+
+impl TryFrom<TechnicalIndicator> for AroonIndicator {
+    type Error = Error;
+
+    fn try_from(indicator: TechnicalIndicator) -> Result<Self> {
+        let meta = IndicatorMetaData::from_meta_data(&indicator.metadata);
+        let mut points = analysis_block(indicator.data)?
+            .into_iter()
+            .map(|(time, values)| {
+                Ok(AroonPoint {
+                    up: parse_value(&values, &time, "Aroon Up")?,
+                    down: parse_value(&values, &time, "Aroon Down")?,
+                    time,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        points.sort_by(|a, b| a.time.cmp(&b.time));
+        Ok(Self { meta, points })
+    }
+}
+
+/// Enum for declaring how indicator values are joined with time series
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlignMode {
+    /// join on exact time
+    #[default]
+    Exact,
+    /// join with most recent indicator value on or before time which is used
+    /// when indicator is computed at a different interval than time series
+    AsOf,
+}
+
+/// Struct for storing time series data joined with indicator value
+#[derive(Debug, Clone)]
+pub struct AlignedRow {
+    time: String,
+    data: Data,
+    indicator: Option<f64>,
+}
+
+impl AlignedRow {
+    /// Return time
+    #[must_use]
+    pub fn time(&self) -> &str {
+        &self.time
+    }
+
+    /// Return open, high, low, close and volume of time series
+    #[must_use]
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Return indicator value. Returns None if indicator has no value for
+    /// time
+    #[must_use]
+    pub fn indicator(&self) -> Option<f64> {
+        self.indicator
+    }
+}
+
+/// Struct for storing result of joining time series with indicator
+#[derive(Debug, Clone, Default)]
+pub struct Alignment {
+    rows: Vec<AlignedRow>,
+    dropped: usize,
+}
+
+impl Alignment {
+    /// Return rows in ascending order of time
+    #[must_use]
+    pub fn rows(&self) -> &[AlignedRow] {
+        &self.rows
+    }
+
+    /// Return number of indicator points which were dropped as they were not
+    /// joined with any row of time series
+    #[must_use]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Consume alignment and return rows
+    #[must_use]
+    pub fn into_rows(self) -> Vec<AlignedRow> {
+        self.rows
+    }
+}
+
+/// Join time series with indicator points such as points of
+/// `SingleValueIndicator` on exact time. Every row of time series is kept and
+/// indicator points which do not have a row are dropped
+///
+/// ```no_run
+/// use alpha_vantage::stock_time::StockFunction;
+/// use alpha_vantage::technical_indicator::{align, TechnicalIndicatorInterval};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let series = api
+///         .stock_time(StockFunction::Daily, "IBM")
+///         .json()
+///         .await
+///         .unwrap();
+///     let sma = api
+///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+///         .time_period(10)
+///         .series_type("close")
+///         .json_single()
+///         .await
+///         .unwrap();
+///     for row in align(&series, sma.points()) {
+///         println!("{} {} {:?}", row.time(), row.data().close(), row.indicator());
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn align(series: &TimeSeries, indicator_points: &[(String, f64)]) -> Vec<AlignedRow> {
+    align_with(series, indicator_points, AlignMode::Exact).into_rows()
+}
+
+/// Join time series with indicator points using given mode and count
+/// indicator points which were dropped
+///
+/// ```no_run
+/// use alpha_vantage::stock_time::StockFunction;
+/// use alpha_vantage::technical_indicator::{align_with, AlignMode, TechnicalIndicatorInterval};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+///     let series = api
+///         .stock_time(StockFunction::Daily, "IBM")
+///         .json()
+///         .await
+///         .unwrap();
+///     let rsi = api
+///         .technical_indicator("RSI", "IBM", TechnicalIndicatorInterval::Weekly)
+///         .time_period(10)
+///         .series_type("close")
+///         .json_single()
+///         .await
+///         .unwrap();
+///     // weekly value is carried forward to each trading day of week
+///     let aligned = align_with(&series, rsi.points(), AlignMode::AsOf);
+///     println!("{} {}", aligned.rows().len(), aligned.dropped());
+/// }
+/// ```
+#[must_use]
+pub fn align_with(
+    series: &TimeSeries,
+    indicator_points: &[(String, f64)],
+    mode: AlignMode,
+) -> Alignment {
+    let mut points = indicator_points.iter().collect::<Vec<_>>();
+    points.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut data = series.data().iter().collect::<Vec<_>>();
+    data.sort_by(|a, b| a.time().cmp(b.time()));
+
+    let mut used = vec![false; points.len()];
+    let rows = data
+        .into_iter()
+        .map(|data| {
+            let index = match mode {
+                AlignMode::Exact => points
+                    .binary_search_by(|(time, _)| time.as_str().cmp(data.time()))
+                    .ok(),
+                AlignMode::AsOf => points
+                    .partition_point(|(time, _)| time.as_str() <= data.time())
+                    .checked_sub(1),
+            };
+            if let Some(index) = index {
+                used[index] = true;
+            }
+            AlignedRow {
+                time: data.time().to_string(),
+                data: data.clone(),
+                indicator: index.map(|index| points[index].1),
+            }
+        })
+        .collect();
+    Alignment {
+        rows,
+        dropped: used.iter().filter(|used| !**used).count(),
+    }
+}
+
+// Parse month in `YYYY-MM` format to year and month
+fn parse_month(month: &str) -> Option<(u16, u8)> {
+    let (year, month) = month.split_once('-')?;
+    if year.len() != 4 || month.len() != 2 {
+        return None;
+    }
+    if !year
+        .bytes()
+        .chain(month.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let month = month.parse::<u8>().ok()?;
+    (1..=12)
+        .contains(&month)
+        .then_some((year.parse().ok()?, month))
+}
+
+// Parse value of key from values of time
+fn parse_value(values: &HashMap<String, String>, time: &str, key: &str) -> Result<f64> {
+    values
+        .get(key)
+        .ok_or_else(|| Error::MissingValue {
+            time: time.to_string(),
+            key: key.to_string(),
+        })?
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| Error::AlphaVantageInvalidData)
+}
+
+// Return lone `Technical Analysis` block of data
+fn analysis_block(data: DataType) -> Result<HashMap<String, HashMap<String, String>>> {
+    let mut blocks = data.into_values();
+    let block = blocks.next().ok_or(Error::EmptyResponse)?;
+    if blocks.next().is_some() {
+        return Err(Error::AlphaVantageInvalidData);
+    }
+    Ok(block)
+}
+
+/// Struct for helping `TechnicalIndicator` struct
+#[derive(Deserialize)]
+pub(crate) struct TechnicalIndicatorHelper {
+    #[serde(rename = "Error Message")]
+    error_message: Option<String>,
+    #[serde(rename = "Information")]
+    information: Option<String>,
+    #[serde(rename = "Note")]
+    note: Option<String>,
+    #[serde(rename = "Meta Data")]
+    metadata: Option<HashMap<String, Value>>,
+    #[serde(flatten)]
+    data: Option<DataType>,
+}
+
+impl TechnicalIndicatorHelper {
+    fn convert(self) -> Result<TechnicalIndicator> {
+        detect_common_helper_error(self.information, self.error_message, self.note)?;
+        if self.metadata.is_none() || self.data.is_none() {
+            return Err(Error::EmptyResponse);
+        }
+        Ok(TechnicalIndicator {
+            metadata: self.metadata.unwrap(),
+            data: self.data.unwrap(),
+            points: None,
+        })
+    }
+}
+
+/// Builder to help create `TechnicalIndicator`
+pub struct TechnicalIndicatorBuilder<'a> {
+    api_client: &'a ApiClient,
+    provider: Option<Provider>,
+    function: TechnicalIndicatorFunction,
+    symbol: &'a str,
+    interval: TechnicalIndicatorInterval,
+    month: Option<&'a str>,
+    params: IndicatorParams,
+}
+
+impl<'a> TechnicalIndicatorBuilder<'a> {
+    /// Create new `TechnicalIndicatorBuilder` form `APIClient`
+    #[must_use]
+    pub fn new(
+        api_client: &'a ApiClient,
+        function: impl Into<TechnicalIndicatorFunction>,
+        symbol: &'a str,
+        interval: TechnicalIndicatorInterval,
+    ) -> Self {
+        Self {
+            api_client,
+            provider: None,
+            function: function.into(),
+            symbol,
+            interval,
+            month: None,
+            params: IndicatorParams::default(),
+        }
+    }
+
+    /// Send request through `provider` instead of provider of client. See
+    /// [`QuoteBuilder::provider`] for example
+    ///
+    /// [`QuoteBuilder::provider`]: crate::quote::QuoteBuilder::provider
+    pub fn provider(&mut self, provider: Provider) -> &mut Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Set time period for API. Time period is validated before request, it
+    /// must be set for function which requires it such as SMA, RSI or BBANDS
+    /// and must not be set for function which does not accept it such as
+    /// MACD, OBV or VWAP. Zero time period is rejected
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let sma = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .time_period(10)
+    ///         .series_type("close")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", sma.data().unwrap().len());
+    /// }
+    /// ```
+    pub fn time_period(&mut self, time: u64) -> &mut Self {
+        self.params.time_period = Some(time);
+        self
+    }
+
+    /// Set series type for API. Series type is only accepted by function
+    /// which is computed from single price such as SMA or MACD and is rejected
+    /// before request for function such as AD, OBV, ADX or AROON
+    pub fn series_type(&mut self, series_type: &'a str) -> &mut Self {
+        self.params.series_type = Some(series_type.to_string());
+        self
+    }
+
+    /// Set fast period for MACD or MACDEXT
+    pub fn fast_period(&mut self, fast_period: u64) -> &mut Self {
+        self.params.fast_period = Some(fast_period);
+        self
+    }
+
+    /// Set slow period for MACD or MACDEXT
+    pub fn slow_period(&mut self, slow_period: u64) -> &mut Self {
+        self.params.slow_period = Some(slow_period);
+        self
+    }
+
+    /// Set signal period for MACD or MACDEXT
+    pub fn signal_period(&mut self, signal_period: u64) -> &mut Self {
+        self.params.signal_period = Some(signal_period);
+        self
+    }
+
+    /// Set standard deviation multiplier of upper band for BBANDS
+    pub fn nb_dev_up(&mut self, nb_dev_up: u64) -> &mut Self {
+        self.params.nb_dev_up = Some(nb_dev_up);
+        self
+    }
+
+    /// Set standard deviation multiplier of lower band for BBANDS
+    pub fn nb_dev_dn(&mut self, nb_dev_dn: u64) -> &mut Self {
+        self.params.nb_dev_dn = Some(nb_dev_dn);
+        self
+    }
+
+    /// Set moving average type for BBANDS
+    pub fn ma_type(&mut self, ma_type: MovingAverageType) -> &mut Self {
+        self.params.ma_type = Some(ma_type);
+        self
+    }
+
+    /// Set fast K period for STOCH or STOCHF. Period must be positive
+    pub fn fast_k_period(&mut self, fast_k_period: u64) -> &mut Self {
+        self.params.fast_k_period = Some(fast_k_period);
+        self
+    }
+
+    /// Set slow K period for STOCH. Period must be positive
+    pub fn slow_k_period(&mut self, slow_k_period: u64) -> &mut Self {
+        self.params.slow_k_period = Some(slow_k_period);
+        self
+    }
+
+    /// Set slow D period for STOCH. Period must be positive
+    pub fn slow_d_period(&mut self, slow_d_period: u64) -> &mut Self {
+        self.params.slow_d_period = Some(slow_d_period);
+        self
+    }
+
+    /// Set moving average type of slow K for STOCH
+    pub fn slow_k_ma_type(&mut self, slow_k_ma_type: MovingAverageType) -> &mut Self {
+        self.params.slow_k_ma_type = Some(slow_k_ma_type);
+        self
+    }
+
+    /// Set moving average type of slow D for STOCH
+    pub fn slow_d_ma_type(&mut self, slow_d_ma_type: MovingAverageType) -> &mut Self {
+        self.params.slow_d_ma_type = Some(slow_d_ma_type);
+        self
+    }
+
+    /// Set fast limit for MAMA. Fast limit must be greater than 0 and less
+    /// than or equal to 1. Value is sent with at most 10 decimal places
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let mama = api
+    ///         .technical_indicator("MAMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .series_type("close")
+    ///         .fast_limit(0.5)
+    ///         .slow_limit(0.05)
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", mama.meta().fast_limit());
+    /// }
+    /// ```
+    pub fn fast_limit(&mut self, fast_limit: f64) -> &mut Self {
+        self.params.fast_limit = Some(fast_limit);
+        self
+    }
+
+    /// Set slow limit for MAMA. Slow limit must be greater than 0 and less
+    /// than or equal to 1. Value is sent with at most 10 decimal places
+    pub fn slow_limit(&mut self, slow_limit: f64) -> &mut Self {
+        self.params.slow_limit = Some(slow_limit);
+        self
+    }
+
+    /// Set acceleration factor for SAR. Acceleration must be greater than 0.
+    /// Value is sent with at most 10 decimal places
+    pub fn acceleration(&mut self, acceleration: f64) -> &mut Self {
+        self.params.acceleration = Some(acceleration);
+        self
+    }
+
+    /// Set maximum acceleration factor for SAR. Maximum must be greater than
+    /// 0. Value is sent with at most 10 decimal places
+    pub fn maximum(&mut self, maximum: f64) -> &mut Self {
+        self.params.maximum = Some(maximum);
+        self
+    }
+
+    /// Set month in `YYYY-MM` format for calculating intraday technical
+    /// indicator of historical month. Month is validated before request and
+    /// only supported for intraday interval
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let sma = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::FiveMin)
+    ///         .time_period(10)
+    ///         .series_type("close")
+    ///         .month("2009-01")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{}", sma.data().unwrap().len());
+    /// }
+    /// ```
+    pub fn month(&mut self, month: &'a str) -> &mut Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Add extra param to builder. Extra param replaces value set by typed
+    /// method of same name in query
+    pub fn extra_param<T, U>(&mut self, param: T, value: U) -> &mut Self
+    where
+        T: std::fmt::Display,
+        U: std::fmt::Display,
+    {
+        self.params
+            .extra_params
+            .insert(param.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns JSON data
+    ///
+    /// # Errors
+    /// Raise error if data obtained cannot be properly converted to struct or
+    /// API returns any 4 possible known errors. Also raise error without
+    /// request if parameters are not valid for function
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let sma = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .time_period(10)
+    ///         .series_type("close")
+    ///         .json()
+    ///         .await
+    ///         .unwrap();
+    ///     for data in sma.data().unwrap() {
+    ///         println!("{} {:?}", data.time(), data.values());
+    ///     }
+    /// }
+    /// ```
+    pub async fn json(&self) -> Result<TechnicalIndicator> {
+        self.json_month(self.month).await
+    }
+
+    /// Returns JSON data of intraday indicator for each month of range (both
+    /// inclusive) in `YYYY-MM` format. One request is sent per month
+    /// sequentially and data of all months are merged into single
+    /// `TechnicalIndicator`, where data of timestamp present in more than one
+    /// month is taken from later month. Month set on builder is ignored
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let mut builder =
+    ///         api.technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::SixtyMin);
+    ///     builder.time_period(10).series_type("close");
+    ///     let sma = builder.fetch_months("2023-12"..="2024-01").await.unwrap();
+    ///     println!("{}", sma.data().unwrap().len());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if month of range is not valid or parameters are not valid
+    /// for function. If request of first month fails its error is raised
+    /// otherwise `Error::PartialBackfill` containing data of previous months
+    /// is raised
+    pub async fn fetch_months(
+        &self,
+        range: std::ops::RangeInclusive<&str>,
+    ) -> Result<TechnicalIndicator> {
+        let (start, end) = range.into_inner();
+        let invalid = |value: &str| Error::InvalidParameter {
+            parameter: "month".to_string(),
+            value: value.to_string(),
+        };
+        let (mut year, mut month) = parse_month(start).ok_or_else(|| invalid(start))?;
+        let last = parse_month(end).ok_or_else(|| invalid(end))?;
+        if (year, month) > last {
+            return Err(invalid(&format!("{start}..={end}")));
+        }
+
+        let mut merged: Option<TechnicalIndicator> = None;
+        while (year, month) <= last {
+            let month_val = format!("{year:04}-{month:02}");
+            match (self.json_month(Some(&month_val)).await, merged.as_mut()) {
+                (Ok(indicator), None) => merged = Some(indicator),
+                (Ok(indicator), Some(merged)) => {
+                    merged.metadata = indicator.metadata;
+                    for (block, data) in indicator.data {
+                        merged.data.entry(block).or_default().extend(data);
+                    }
+                }
+                (Err(error), None) => return Err(error),
+                (Err(error), Some(_)) => {
+                    return Err(Error::PartialBackfill {
+                        month: month_val,
+                        fetched: Box::new(merged.unwrap_or_default()),
+                        source: Box::new(error),
+                    });
+                }
+            }
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+        merged.ok_or(Error::EmptyResponse)
+    }
+
+    // Send request for month which shares validation and creation of url for
+    // all request
+    async fn json_month(&self, month: Option<&str>) -> Result<TechnicalIndicator> {
+        self.validate(month)?;
+        let url = self.create_url(month);
+        self.api_client
+            .with_provider_override(self.provider.as_ref())?
+            .get_json_with(&url, None, TechnicalIndicatorHelper::convert)
+            .await
+    }
+
+    /// Returns JSON data of technical indicator which has single value for
+    /// each time such as SMA, EMA, WMA or RSI. Value is taken from lone entry
+    /// of each time irrespective of its name
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let sma = api
+    ///         .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .time_period(10)
+    ///         .series_type("close")
+    ///         .json_single()
+    ///         .await
+    ///         .unwrap();
+    ///     for (time, value) in sma.points() {
+    ///         println!("{time} {value}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if [`TechnicalIndicatorBuilder::json`] raises error or any
+    /// time does not have exactly one value
+    pub async fn json_single(&self) -> Result<SingleValueIndicator> {
+        SingleValueIndicator::try_from(self.json().await?)
+    }
+
+    /// Returns JSON data of MACD or MACDEXT technical indicator
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let macd = api
+    ///         .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .series_type("open")
+    ///         .fast_period(12)
+    ///         .slow_period(26)
+    ///         .signal_period(9)
+    ///         .json_macd()
+    ///         .await
+    ///         .unwrap();
+    ///     for point in macd.points() {
+    ///         println!("{} {} {}", point.time(), point.macd(), point.signal());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if [`TechnicalIndicatorBuilder::json`] raises error or any
+    /// time does not have MACD, signal or histogram value
+    pub async fn json_macd(&self) -> Result<MacdIndicator> {
+        MacdIndicator::try_from(self.json().await?)
+    }
+
+    /// Returns JSON data of BBANDS technical indicator
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::{MovingAverageType, TechnicalIndicatorInterval};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let bbands = api
+    ///         .technical_indicator("BBANDS", "IBM", TechnicalIndicatorInterval::Weekly)
+    ///         .time_period(5)
+    ///         .series_type("close")
+    ///         .nb_dev_up(3)
+    ///         .nb_dev_dn(3)
+    ///         .ma_type(MovingAverageType::Ema)
+    ///         .json_bbands()
+    ///         .await
+    ///         .unwrap();
+    ///     for point in bbands.points() {
+    ///         println!("{} {} {}", point.time(), point.upper(), point.lower());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if [`TechnicalIndicatorBuilder::json`] raises error or any
+    /// time does not have upper, middle or lower band value
+    pub async fn json_bbands(&self) -> Result<BbandsIndicator> {
+        BbandsIndicator::try_from(self.json().await?)
+    }
+
+    /// Returns JSON data of STOCH or STOCHF technical indicator
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::{MovingAverageType, TechnicalIndicatorInterval};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let stoch = api
+    ///         .technical_indicator("STOCH", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .fast_k_period(5)
+    ///         .slow_k_ma_type(MovingAverageType::Sma)
+    ///         .json_stoch()
+    ///         .await
+    ///         .unwrap();
+    ///     for point in stoch.points() {
+    ///         println!("{} {} {}", point.time(), point.k(), point.d());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if [`TechnicalIndicatorBuilder::json`] raises error or any
+    /// time does not have K or D value
+    pub async fn json_stoch(&self) -> Result<StochIndicator> {
+        StochIndicator::try_from(self.json().await?)
+    }
+
+    /// Returns JSON data of AROON technical indicator. AROON only accepts time
+    /// period so series type is rejected before request
+    ///
+    /// ```no_run
+    /// use alpha_vantage::technical_indicator::TechnicalIndicatorInterval;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = alpha_vantage::set_api("demo", reqwest::Client::new());
+    ///     let aroon = api
+    ///         .technical_indicator("AROON", "IBM", TechnicalIndicatorInterval::Daily)
+    ///         .time_period(14)
+    ///         .json_aroon()
+    ///         .await
+    ///         .unwrap();
+    ///     for point in aroon.points() {
+    ///         println!("{} {} {}", point.time(), point.up(), point.down());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Raise error if [`TechnicalIndicatorBuilder::json`] raises error or any
+    /// time does not have Aroon up or Aroon down value
+    pub async fn json_aroon(&self) -> Result<AroonIndicator> {
+        AroonIndicator::try_from(self.json().await?)
+    }
+
+    // Validate parameters as per function before request
+    fn validate(&self, month: Option<&str>) -> Result<()> {
+        self.params.validate(&self.function)?;
+        if self.function == TechnicalIndicatorFunction::Vwap && !self.interval.is_intraday() {
+            return Err(Error::UnsupportedParameter {
+                parameter: format!("{} interval", self.interval.as_str()),
+                function: self.function.to_string(),
+            });
+        }
+        if let Some(month) = month {
+            if !self.interval.is_intraday() {
+                return Err(Error::UnsupportedParameter {
+                    parameter: "month".to_string(),
+                    function: format!("{} with {} interval", self.function, self.interval.as_str()),
+                });
+            }
+            if parse_month(month).is_none() {
+                return Err(Error::InvalidParameter {
+                    parameter: "month".to_string(),
+                    value: month.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn create_url(&self, month: Option<&str>) -> String {
+        let interval_val = self.interval.as_str();
+
+        let mut created_link = format!(
+            "query?function={}&symbol={}&interval={interval_val}",
+            self.function,
+            encode_query_value(self.symbol)
+        );
+
+        self.params.write_query(&mut created_link, month);
+        created_link
+    }
+}
+
+/// Parameters of technical indicator shared by `TechnicalIndicatorBuilder`
+/// and `IndicatorSpec`
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IndicatorParams {
+    pub(crate) series_type: Option<String>,
+    pub(crate) time_period: Option<u64>,
+    pub(crate) fast_period: Option<u64>,
+    pub(crate) slow_period: Option<u64>,
+    pub(crate) signal_period: Option<u64>,
+    nb_dev_up: Option<u64>,
+    nb_dev_dn: Option<u64>,
+    ma_type: Option<MovingAverageType>,
+    fast_k_period: Option<u64>,
+    slow_k_period: Option<u64>,
+    slow_d_period: Option<u64>,
+    slow_k_ma_type: Option<MovingAverageType>,
+    slow_d_ma_type: Option<MovingAverageType>,
+    fast_limit: Option<f64>,
+    slow_limit: Option<f64>,
+    acceleration: Option<f64>,
+    maximum: Option<f64>,
+    extra_params: HashMap<String, String>,
+}
+
+impl IndicatorParams {
+    // Name of validated parameters which are set either by typed method or
+    // extra param
+    fn names(&self) -> Vec<&'static str> {
+        let typed = [
+            ("series_type", self.series_type.is_some()),
+            ("time_period", self.time_period.is_some()),
+            ("fastperiod", self.fast_period.is_some()),
+            ("slowperiod", self.slow_period.is_some()),
+            ("signalperiod", self.signal_period.is_some()),
+            ("nbdevup", self.nb_dev_up.is_some()),
+            ("nbdevdn", self.nb_dev_dn.is_some()),
+            ("matype", self.ma_type.is_some()),
+            ("fastkperiod", self.fast_k_period.is_some()),
+            ("slowkperiod", self.slow_k_period.is_some()),
+            ("slowdperiod", self.slow_d_period.is_some()),
+            ("slowkmatype", self.slow_k_ma_type.is_some()),
+            ("slowdmatype", self.slow_d_ma_type.is_some()),
+            ("fastlimit", self.fast_limit.is_some()),
+            ("slowlimit", self.slow_limit.is_some()),
+            ("acceleration", self.acceleration.is_some()),
+            ("maximum", self.maximum.is_some()),
+            // only set by extra param
+            ("fastdperiod", false),
+            ("fastdmatype", false),
+        ];
+        typed
+            .into_iter()
+            .filter(|(name, set)| *set || self.extra_params.contains_key(*name))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    // Validate parameters as per function
+    fn validate(&self, function: &TechnicalIndicatorFunction) -> Result<()> {
+        if let Some((required, optional)) = function.parameters() {
+            let present = self.names();
+            if let Some(missing) = required.iter().find(|name| !present.contains(name)) {
+                return Err(Error::MissingParameter {
+                    parameter: (*missing).to_string(),
+                    function: function.to_string(),
+                });
+            }
+            if let Some(unexpected) = present
+                .iter()
+                .find(|name| !required.contains(name) && !optional.contains(name))
+            {
+                return Err(Error::UnexpectedParameter {
+                    parameter: (*unexpected).to_string(),
+                    function: function.to_string(),
+                });
+            }
+        }
+        if self
+            .extra_params
+            .get("time_period")
+            .is_some_and(|time_period| time_period.trim() == "0")
+        {
+            return Err(Error::InvalidParameter {
+                parameter: "time_period".to_string(),
+                value: "0".to_string(),
+            });
+        }
+        for (parameter, value) in [
+            ("time_period", self.time_period),
+            ("fastperiod", self.fast_period),
+            ("slowperiod", self.slow_period),
+            ("signalperiod", self.signal_period),
+            ("nbdevup", self.nb_dev_up),
+            ("nbdevdn", self.nb_dev_dn),
+            ("fastkperiod", self.fast_k_period),
+            ("slowkperiod", self.slow_k_period),
+            ("slowdperiod", self.slow_d_period),
+        ] {
+            if value == Some(0) {
+                return Err(Error::InvalidParameter {
+                    parameter: parameter.to_string(),
+                    value: "0".to_string(),
+                });
+            }
+        }
+        for (parameter, value, max) in [
+            ("fastlimit", self.fast_limit, Some(1.0)),
+            ("slowlimit", self.slow_limit, Some(1.0)),
+            ("acceleration", self.acceleration, None),
+            ("maximum", self.maximum, None),
+        ] {
+            if let Some(value) = value {
+                if !(value.is_finite() && value > 0.0 && max.is_none_or(|max| value <= max)) {
+                    return Err(Error::InvalidParameter {
+                        parameter: parameter.to_string(),
+                        value: format_float(value),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Append parameters to query of url along with month. Extra param
+    // overrides typed parameter of same name so key is not repeated
+    fn write_query(&self, created_link: &mut String, month: Option<&str>) {
+        let number = |value: Option<u64>| value.map(|value| value.to_string());
+        let ma_type =
+            |value: Option<MovingAverageType>| value.map(|value| (value as u8).to_string());
+        let float = |value: Option<f64>| value.map(format_float);
+        let typed = [
+            ("time_period", number(self.time_period)),
+            (
+                "series_type",
+                self.series_type
+                    .as_deref()
+                    .map(|series_type| encode_query_value(series_type).into_owned()),
+            ),
+            ("month", month.map(str::to_string)),
+            ("fastperiod", number(self.fast_period)),
+            ("slowperiod", number(self.slow_period)),
+            ("signalperiod", number(self.signal_period)),
+            ("nbdevup", number(self.nb_dev_up)),
+            ("nbdevdn", number(self.nb_dev_dn)),
+            ("matype", ma_type(self.ma_type)),
+            ("fastkperiod", number(self.fast_k_period)),
+            ("slowkperiod", number(self.slow_k_period)),
+            ("slowdperiod", number(self.slow_d_period)),
+            ("slowkmatype", ma_type(self.slow_k_ma_type)),
+            ("slowdmatype", ma_type(self.slow_d_ma_type)),
+            ("fastlimit", float(self.fast_limit)),
+            ("slowlimit", float(self.slow_limit)),
+            ("acceleration", float(self.acceleration)),
+            ("maximum", float(self.maximum)),
+        ];
+        for (param, value) in typed {
+            if let Some(value) = value {
+                if !self.extra_params.contains_key(param) {
+                    write!(created_link, "&{param}={value}").unwrap();
+                }
+            }
+        }
+
+        for (param, value) in &self.extra_params {
+            write!(created_link, "&{param}={}", encode_query_value(value)).unwrap();
+        }
+    }
+}
+
+/// Result of single technical indicator fetched by
+/// [`ApiClient::technical_indicators`]
+pub type IndicatorResult = Result<TechnicalIndicator>;
+
+/// Maximum number of technical indicator requested at same time by
+/// [`ApiClient::technical_indicators`]
+const MAX_CONCURRENT_INDICATORS: usize = 4;
+
+/// Struct for declaring technical indicator requested by
+/// [`ApiClient::technical_indicators`]. Parameters are validated same as
+/// `TechnicalIndicatorBuilder`
+#[derive(Clone, Debug)]
+pub struct IndicatorSpec {
+    key: Option<String>,
+    pub(crate) function: TechnicalIndicatorFunction,
+    pub(crate) params: IndicatorParams,
+}
+
+impl IndicatorSpec {
+    /// Create new `IndicatorSpec` for function
+    #[must_use]
+    pub fn new(function: impl Into<TechnicalIndicatorFunction>) -> Self {
+        Self {
+            key: None,
+            function: function.into(),
+            params: IndicatorParams::default(),
+        }
+    }
+
+    /// Set key of result. By default name of function is used as key so key
+    /// must be set when same function is requested more than once
+    #[must_use]
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Set series type
+    #[must_use]
+    pub fn series_type(mut self, series_type: impl Into<String>) -> Self {
+        self.params.series_type = Some(series_type.into());
+        self
+    }
+
+    /// Set time period
+    #[must_use]
+    pub fn time_period(mut self, time_period: u64) -> Self {
+        self.params.time_period = Some(time_period);
+        self
+    }
+
+    /// Set fast period
+    #[must_use]
+    pub fn fast_period(mut self, fast_period: u64) -> Self {
+        self.params.fast_period = Some(fast_period);
+        self
+    }
+
+    /// Set slow period
+    #[must_use]
+    pub fn slow_period(mut self, slow_period: u64) -> Self {
+        self.params.slow_period = Some(slow_period);
+        self
+    }
+
+    /// Set signal period
+    #[must_use]
+    pub fn signal_period(mut self, signal_period: u64) -> Self {
+        self.params.signal_period = Some(signal_period);
+        self
+    }
+
+    /// Set standard deviation multiplier of upper band
+    #[must_use]
+    pub fn nb_dev_up(mut self, nb_dev_up: u64) -> Self {
+        self.params.nb_dev_up = Some(nb_dev_up);
+        self
+    }
+
+    /// Set standard deviation multiplier of lower band
+    #[must_use]
+    pub fn nb_dev_dn(mut self, nb_dev_dn: u64) -> Self {
+        self.params.nb_dev_dn = Some(nb_dev_dn);
+        self
+    }
+
+    /// Set moving average type
+    #[must_use]
+    pub fn ma_type(mut self, ma_type: MovingAverageType) -> Self {
+        self.params.ma_type = Some(ma_type);
+        self
+    }
+
+    /// Set fast K period
+    #[must_use]
+    pub fn fast_k_period(mut self, fast_k_period: u64) -> Self {
+        self.params.fast_k_period = Some(fast_k_period);
+        self
+    }
+
+    /// Set slow K period
+    #[must_use]
+    pub fn slow_k_period(mut self, slow_k_period: u64) -> Self {
+        self.params.slow_k_period = Some(slow_k_period);
+        self
+    }
+
+    /// Set slow D period
+    #[must_use]
+    pub fn slow_d_period(mut self, slow_d_period: u64) -> Self {
+        self.params.slow_d_period = Some(slow_d_period);
+        self
+    }
+
+    /// Set moving average type of slow K
+    #[must_use]
+    pub fn slow_k_ma_type(mut self, slow_k_ma_type: MovingAverageType) -> Self {
+        self.params.slow_k_ma_type = Some(slow_k_ma_type);
+        self
+    }
+
+    /// Set moving average type of slow D
+    #[must_use]
+    pub fn slow_d_ma_type(mut self, slow_d_ma_type: MovingAverageType) -> Self {
+        self.params.slow_d_ma_type = Some(slow_d_ma_type);
+        self
+    }
+
+    /// Set fast limit
+    #[must_use]
+    pub fn fast_limit(mut self, fast_limit: f64) -> Self {
+        self.params.fast_limit = Some(fast_limit);
+        self
+    }
+
+    /// Set slow limit
+    #[must_use]
+    pub fn slow_limit(mut self, slow_limit: f64) -> Self {
+        self.params.slow_limit = Some(slow_limit);
+        self
+    }
+
+    /// Set acceleration factor
+    #[must_use]
+    pub fn acceleration(mut self, acceleration: f64) -> Self {
+        self.params.acceleration = Some(acceleration);
+        self
+    }
+
+    /// Set maximum acceleration factor
+    #[must_use]
+    pub fn maximum(mut self, maximum: f64) -> Self {
+        self.params.maximum = Some(maximum);
+        self
+    }
+
+    /// Add extra param. Extra param replaces value set by typed method of same
+    /// name in query
+    #[must_use]
+    pub fn extra_param<T, U>(mut self, param: T, value: U) -> Self
+    where
+        T: std::fmt::Display,
+        U: std::fmt::Display,
+    {
+        self.params
+            .extra_params
+            .insert(param.to_string(), value.to_string());
+        self
+    }
+
+    /// Return key of result
+    #[must_use]
+    pub fn result_key(&self) -> String {
+        self.key
+            .clone()
+            .unwrap_or_else(|| self.function.to_string())
+    }
+
+    /// Return function of technical indicator
+    #[must_use]
+    pub fn function(&self) -> &TechnicalIndicatorFunction {
+        &self.function
+    }
+
+    // Create builder with parameters of spec
+    fn builder<'a>(
+        &'a self,
+        api_client: &'a ApiClient,
+        symbol: &'a str,
+        interval: TechnicalIndicatorInterval,
+    ) -> TechnicalIndicatorBuilder<'a> {
+        let mut builder =
+            TechnicalIndicatorBuilder::new(api_client, self.function.clone(), symbol, interval);
+        builder.params = self.params.clone();
+        builder
+    }
+}
+
+/// Fetch technical indicators of symbol with bounded concurrency
+pub(crate) async fn technical_indicators(
+    api_client: &ApiClient,
+    symbol: &str,
+    interval: TechnicalIndicatorInterval,
+    specs: &[IndicatorSpec],
+) -> Result<HashMap<String, IndicatorResult>> {
+    let keys = specs
+        .iter()
+        .map(IndicatorSpec::result_key)
+        .collect::<Vec<_>>();
+    for (index, key) in keys.iter().enumerate() {
+        if keys[..index].contains(key) {
+            return Err(Error::InvalidParameter {
+                parameter: "key".to_string(),
+                value: key.clone(),
+            });
+        }
+    }
+
+    let mut results = futures::stream::iter(specs)
+        .map(|spec| async move { spec.builder(api_client, symbol, interval).json().await })
+        .buffered(MAX_CONCURRENT_INDICATORS)
+        .collect::<Vec<_>>()
+        .await;
+    if !results.is_empty() && results.iter().all(Result::is_err) {
+        return Err(results.swap_remove(0).unwrap_err());
+    }
+    Ok(keys.into_iter().zip(results).collect())
+}
+
+/// Enum for declaring moving average type used by indicator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MovingAverageType {
+    /// simple moving average
+    Sma = 0,
+    /// exponential moving average
+    Ema = 1,
+    /// weighted moving average
+    Wma = 2,
+    /// double exponential moving average
+    Dema = 3,
+    /// triple exponential moving average
+    Tema = 4,
+    /// triangular moving average
+    Trima = 5,
+    /// T3 moving average
+    T3 = 6,
+    /// Kaufman adaptive moving average
+    Kama = 7,
+    /// MESA adaptive moving average
+    Mama = 8,
+}
+
+/// Enum for declaring function of technical indicator
+///
+/// Known functions are validated against the parameters accepted by Alpha
+/// Vantage before sending a request. Function not known by library can be
+/// used with `Custom` variant, which bypass validation.
+///
+/// ```
+/// use alpha_vantage::technical_indicator::TechnicalIndicatorFunction;
+/// assert_eq!(
+///     " macd ".parse::<TechnicalIndicatorFunction>().unwrap(),
+///     TechnicalIndicatorFunction::Macd
+/// );
+/// assert_eq!(TechnicalIndicatorFunction::HtSine.to_string(), "HT_SINE");
+/// let custom = TechnicalIndicatorFunction::from("NEW_INDICATOR");
+/// assert_eq!(
+///     custom,
+///     TechnicalIndicatorFunction::Custom("NEW_INDICATOR".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TechnicalIndicatorFunction {
+    /// simple moving average
+    Sma,
+    /// exponential moving average
+    Ema,
+    /// weighted moving average
+    Wma,
+    /// double exponential moving average
+    Dema,
+    /// triple exponential moving average
+    Tema,
+    /// triangular moving average
+    Trima,
+    /// Kaufman adaptive moving average
+    Kama,
+    /// MESA adaptive moving average
+    Mama,
+    /// volume weighted average price
+    Vwap,
+    /// triple exponential moving average (T3)
+    T3,
+    /// moving average convergence / divergence
+    Macd,
+    /// moving average convergence / divergence with controllable moving average type
+    Macdext,
+    /// stochastic oscillator
+    Stoch,
+    /// stochastic fast
+    Stochf,
+    /// relative strength index
+    Rsi,
+    /// stochastic relative strength index
+    Stochrsi,
+    /// Williams' %R
+    Willr,
+    /// average directional movement index
+    Adx,
+    /// average directional movement index rating
+    Adxr,
+    /// absolute price oscillator
+    Apo,
+    /// percentage price oscillator
+    Ppo,
+    /// momentum
+    Mom,
+    /// balance of power
+    Bop,
+    /// commodity channel index
+    Cci,
+    /// Chande momentum oscillator
+    Cmo,
+    /// rate of change
+    Roc,
+    /// rate of change ratio
+    Rocr,
+    /// Aroon
+    Aroon,
+    /// Aroon oscillator
+    Aroonosc,
+    /// money flow index
+    Mfi,
+    /// 1-day rate of change of a triple smooth exponential moving average
+    Trix,
+    /// ultimate oscillator
+    Ultosc,
+    /// directional movement index
+    Dx,
+    /// minus directional indicator
+    MinusDi,
+    /// plus directional indicator
+    PlusDi,
+    /// minus directional movement
+    MinusDm,
+    /// plus directional movement
+    PlusDm,
+    /// Bollinger bands
+    Bbands,
+    /// midpoint
+    Midpoint,
+    /// midpoint price
+    Midprice,
+    /// parabolic SAR
+    Sar,
+    /// true range
+    Trange,
+    /// average true range
+    Atr,
+    /// normalized average true range
+    Natr,
+    /// Chaikin A/D line
+    Ad,
+    /// Chaikin A/D oscillator
+    Adosc,
+    /// on balance volume
+    Obv,
+    /// Hilbert transform, instantaneous trendline
+    HtTrendline,
+    /// Hilbert transform, sine wave
+    HtSine,
+    /// Hilbert transform, trend vs cycle mode
+    HtTrendmode,
+    /// Hilbert transform, dominant cycle period
+    HtDcperiod,
+    /// Hilbert transform, dominant cycle phase
+    HtDcphase,
+    /// Hilbert transform, phasor components
+    HtPhasor,
+    /// function not known by library
+    Custom(String),
+}
+
+impl TechnicalIndicatorFunction {
+    fn as_str(&self) -> &str {
+        match self {
+            TechnicalIndicatorFunction::Sma => "SMA",
+            TechnicalIndicatorFunction::Ema => "EMA",
+            TechnicalIndicatorFunction::Wma => "WMA",
+            TechnicalIndicatorFunction::Dema => "DEMA",
+            TechnicalIndicatorFunction::Tema => "TEMA",
+            TechnicalIndicatorFunction::Trima => "TRIMA",
+            TechnicalIndicatorFunction::Kama => "KAMA",
+            TechnicalIndicatorFunction::Mama => "MAMA",
+            TechnicalIndicatorFunction::Vwap => "VWAP",
+            TechnicalIndicatorFunction::T3 => "T3",
+            TechnicalIndicatorFunction::Macd => "MACD",
+            TechnicalIndicatorFunction::Macdext => "MACDEXT",
+            TechnicalIndicatorFunction::Stoch => "STOCH",
+            TechnicalIndicatorFunction::Stochf => "STOCHF",
+            TechnicalIndicatorFunction::Rsi => "RSI",
+            TechnicalIndicatorFunction::Stochrsi => "STOCHRSI",
+            TechnicalIndicatorFunction::Willr => "WILLR",
+            TechnicalIndicatorFunction::Adx => "ADX",
+            TechnicalIndicatorFunction::Adxr => "ADXR",
+            TechnicalIndicatorFunction::Apo => "APO",
+            TechnicalIndicatorFunction::Ppo => "PPO",
+            TechnicalIndicatorFunction::Mom => "MOM",
+            TechnicalIndicatorFunction::Bop => "BOP",
+            TechnicalIndicatorFunction::Cci => "CCI",
+            TechnicalIndicatorFunction::Cmo => "CMO",
+            TechnicalIndicatorFunction::Roc => "ROC",
+            TechnicalIndicatorFunction::Rocr => "ROCR",
+            TechnicalIndicatorFunction::Aroon => "AROON",
+            TechnicalIndicatorFunction::Aroonosc => "AROONOSC",
+            TechnicalIndicatorFunction::Mfi => "MFI",
+            TechnicalIndicatorFunction::Trix => "TRIX",
+            TechnicalIndicatorFunction::Ultosc => "ULTOSC",
+            TechnicalIndicatorFunction::Dx => "DX",
+            TechnicalIndicatorFunction::MinusDi => "MINUS_DI",
+            TechnicalIndicatorFunction::PlusDi => "PLUS_DI",
+            TechnicalIndicatorFunction::MinusDm => "MINUS_DM",
+            TechnicalIndicatorFunction::PlusDm => "PLUS_DM",
+            TechnicalIndicatorFunction::Bbands => "BBANDS",
+            TechnicalIndicatorFunction::Midpoint => "MIDPOINT",
+            TechnicalIndicatorFunction::Midprice => "MIDPRICE",
+            TechnicalIndicatorFunction::Sar => "SAR",
+            TechnicalIndicatorFunction::Trange => "TRANGE",
+            TechnicalIndicatorFunction::Atr => "ATR",
+            TechnicalIndicatorFunction::Natr => "NATR",
+            TechnicalIndicatorFunction::Ad => "AD",
+            TechnicalIndicatorFunction::Adosc => "ADOSC",
+            TechnicalIndicatorFunction::Obv => "OBV",
+            TechnicalIndicatorFunction::HtTrendline => "HT_TRENDLINE",
+            TechnicalIndicatorFunction::HtSine => "HT_SINE",
+            TechnicalIndicatorFunction::HtTrendmode => "HT_TRENDMODE",
+            TechnicalIndicatorFunction::HtDcperiod => "HT_DCPERIOD",
+            TechnicalIndicatorFunction::HtDcphase => "HT_DCPHASE",
+            TechnicalIndicatorFunction::HtPhasor => "HT_PHASOR",
+            TechnicalIndicatorFunction::Custom(custom) => custom,
+        }
+    }
+
+    // Required and optional parameters of function. Return None for function
+    // which is not validated
+    fn parameters(&self) -> Option<(&'static [&'static str], &'static [&'static str])> {
+        let parameters: (&'static [&'static str], &'static [&'static str]) = match self {
+            TechnicalIndicatorFunction::Sma
+            | TechnicalIndicatorFunction::Ema
+            | TechnicalIndicatorFunction::Wma
+            | TechnicalIndicatorFunction::Dema
+            | TechnicalIndicatorFunction::Tema
+            | TechnicalIndicatorFunction::Trima
+            | TechnicalIndicatorFunction::Kama
+            | TechnicalIndicatorFunction::T3
+            | TechnicalIndicatorFunction::Rsi
+            | TechnicalIndicatorFunction::Mom
+            | TechnicalIndicatorFunction::Cmo
+            | TechnicalIndicatorFunction::Roc
+            | TechnicalIndicatorFunction::Rocr
+            | TechnicalIndicatorFunction::Trix
+            | TechnicalIndicatorFunction::Midpoint => (&["time_period", "series_type"], &[]),
+            TechnicalIndicatorFunction::Stochrsi => (
+                &["time_period", "series_type"],
+                &["fastkperiod", "fastdperiod", "fastdmatype"],
+            ),
+            TechnicalIndicatorFunction::Bbands => (
+                &["time_period", "series_type"],
+                &["nbdevup", "nbdevdn", "matype"],
+            ),
+            TechnicalIndicatorFunction::Willr
+            | TechnicalIndicatorFunction::Adx
+            | TechnicalIndicatorFunction::Adxr
+            | TechnicalIndicatorFunction::Cci
+            | TechnicalIndicatorFunction::Aroon
+            | TechnicalIndicatorFunction::Aroonosc
+            | TechnicalIndicatorFunction::Mfi
+            | TechnicalIndicatorFunction::Dx
+            | TechnicalIndicatorFunction::MinusDi
+            | TechnicalIndicatorFunction::PlusDi
+            | TechnicalIndicatorFunction::MinusDm
+            | TechnicalIndicatorFunction::PlusDm
+            | TechnicalIndicatorFunction::Midprice
+            | TechnicalIndicatorFunction::Atr
+            | TechnicalIndicatorFunction::Natr => (&["time_period"], &[]),
+            TechnicalIndicatorFunction::Macd | TechnicalIndicatorFunction::Macdext => (
+                &["series_type"],
+                &["fastperiod", "slowperiod", "signalperiod"],
+            ),
+            TechnicalIndicatorFunction::Apo | TechnicalIndicatorFunction::Ppo => {
+                (&["series_type"], &["fastperiod", "slowperiod", "matype"])
+            }
+            TechnicalIndicatorFunction::Mama => (&["series_type"], &["fastlimit", "slowlimit"]),
+            TechnicalIndicatorFunction::HtTrendline
+            | TechnicalIndicatorFunction::HtSine
+            | TechnicalIndicatorFunction::HtTrendmode
+            | TechnicalIndicatorFunction::HtDcperiod
+            | TechnicalIndicatorFunction::HtDcphase
+            | TechnicalIndicatorFunction::HtPhasor => (&["series_type"], &[]),
+            TechnicalIndicatorFunction::Stoch => (
+                &[],
+                &[
+                    "fastkperiod",
+                    "slowkperiod",
+                    "slowdperiod",
+                    "slowkmatype",
+                    "slowdmatype",
+                ],
+            ),
+            TechnicalIndicatorFunction::Stochf => {
+                (&[], &["fastkperiod", "fastdperiod", "fastdmatype"])
+            }
+            TechnicalIndicatorFunction::Adosc => (&[], &["fastperiod", "slowperiod"]),
+            TechnicalIndicatorFunction::Vwap
+            | TechnicalIndicatorFunction::Obv
+            | TechnicalIndicatorFunction::Ad
+            | TechnicalIndicatorFunction::Bop
+            | TechnicalIndicatorFunction::Trange
+            | TechnicalIndicatorFunction::Ultosc => (&[], &[]),
+            TechnicalIndicatorFunction::Sar => (&[], &["acceleration", "maximum"]),
+            TechnicalIndicatorFunction::Custom(_) => return None,
+        };
+        Some(parameters)
+    }
+}
+
+impl From<&str> for TechnicalIndicatorFunction {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+        [
+            TechnicalIndicatorFunction::Sma,
+            TechnicalIndicatorFunction::Ema,
+            TechnicalIndicatorFunction::Wma,
+            TechnicalIndicatorFunction::Dema,
+            TechnicalIndicatorFunction::Tema,
+            TechnicalIndicatorFunction::Trima,
+            TechnicalIndicatorFunction::Kama,
+            TechnicalIndicatorFunction::Mama,
+            TechnicalIndicatorFunction::Vwap,
+            TechnicalIndicatorFunction::T3,
+            TechnicalIndicatorFunction::Macd,
+            TechnicalIndicatorFunction::Macdext,
+            TechnicalIndicatorFunction::Stoch,
+            TechnicalIndicatorFunction::Stochf,
+            TechnicalIndicatorFunction::Rsi,
+            TechnicalIndicatorFunction::Stochrsi,
+            TechnicalIndicatorFunction::Willr,
+            TechnicalIndicatorFunction::Adx,
+            TechnicalIndicatorFunction::Adxr,
+            TechnicalIndicatorFunction::Apo,
+            TechnicalIndicatorFunction::Ppo,
+            TechnicalIndicatorFunction::Mom,
+            TechnicalIndicatorFunction::Bop,
+            TechnicalIndicatorFunction::Cci,
+            TechnicalIndicatorFunction::Cmo,
+            TechnicalIndicatorFunction::Roc,
+            TechnicalIndicatorFunction::Rocr,
+            TechnicalIndicatorFunction::Aroon,
+            TechnicalIndicatorFunction::Aroonosc,
+            TechnicalIndicatorFunction::Mfi,
+            TechnicalIndicatorFunction::Trix,
+            TechnicalIndicatorFunction::Ultosc,
+            TechnicalIndicatorFunction::Dx,
+            TechnicalIndicatorFunction::MinusDi,
+            TechnicalIndicatorFunction::PlusDi,
+            TechnicalIndicatorFunction::MinusDm,
+            TechnicalIndicatorFunction::PlusDm,
+            TechnicalIndicatorFunction::Bbands,
+            TechnicalIndicatorFunction::Midpoint,
+            TechnicalIndicatorFunction::Midprice,
+            TechnicalIndicatorFunction::Sar,
+            TechnicalIndicatorFunction::Trange,
+            TechnicalIndicatorFunction::Atr,
+            TechnicalIndicatorFunction::Natr,
+            TechnicalIndicatorFunction::Ad,
+            TechnicalIndicatorFunction::Adosc,
+            TechnicalIndicatorFunction::Obv,
+            TechnicalIndicatorFunction::HtTrendline,
+            TechnicalIndicatorFunction::HtSine,
+            TechnicalIndicatorFunction::HtTrendmode,
+            TechnicalIndicatorFunction::HtDcperiod,
+            TechnicalIndicatorFunction::HtDcphase,
+            TechnicalIndicatorFunction::HtPhasor,
+        ]
+        .into_iter()
+        .find(|function| function.as_str().eq_ignore_ascii_case(value))
+        .unwrap_or_else(|| TechnicalIndicatorFunction::Custom(value.to_string()))
+    }
+}
+
+impl From<&String> for TechnicalIndicatorFunction {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<String> for TechnicalIndicatorFunction {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::str::FromStr for TechnicalIndicatorFunction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl std::fmt::Display for TechnicalIndicatorFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Enum for declaring interval for technical indicator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TechnicalIndicatorInterval {
+    /// 1 min interval
+    OneMin,
+    /// 5 min interval
+    FiveMin,
+    /// 15 min interval
+    FifteenMin,
+    /// 30 min interval
+    ThirtyMin,
+    /// 60 min interval
+    SixtyMin,
+    /// daily interval
+    Daily,
+    /// weekly interval
+    Weekly,
+    /// monthly interval
+    Monthly,
+}
+
+impl TechnicalIndicatorInterval {
+    fn as_str(self) -> &'static str {
+        match self {
+            TechnicalIndicatorInterval::OneMin => "1min",
+            TechnicalIndicatorInterval::FiveMin => "5min",
+            TechnicalIndicatorInterval::FifteenMin => "15min",
+            TechnicalIndicatorInterval::ThirtyMin => "30min",
+            TechnicalIndicatorInterval::SixtyMin => "60min",
+            TechnicalIndicatorInterval::Daily => "daily",
+            TechnicalIndicatorInterval::Weekly => "weekly",
+            TechnicalIndicatorInterval::Monthly => "monthly",
+        }
+    }
+
+    fn parse(interval: &str) -> Option<Self> {
+        [
+            TechnicalIndicatorInterval::OneMin,
+            TechnicalIndicatorInterval::FiveMin,
+            TechnicalIndicatorInterval::FifteenMin,
+            TechnicalIndicatorInterval::ThirtyMin,
+            TechnicalIndicatorInterval::SixtyMin,
+            TechnicalIndicatorInterval::Daily,
+            TechnicalIndicatorInterval::Weekly,
+            TechnicalIndicatorInterval::Monthly,
+        ]
+        .into_iter()
+        .find(|variant| variant.as_str().eq_ignore_ascii_case(interval.trim()))
+    }
+
+    fn is_intraday(self) -> bool {
+        !matches!(
+            self,
+            TechnicalIndicatorInterval::Daily
+                | TechnicalIndicatorInterval::Weekly
+                | TechnicalIndicatorInterval::Monthly
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AlignMode, FindIndicatorData, MovingAverageType, SingleValueIndicator,
+        TechnicalIndicatorFunction, TechnicalIndicatorInterval, align, align_with,
+    };
+    use crate::error::Error;
+    use crate::stock_time::StockFunction;
+    use crate::test_utils::FixtureClient;
+
+    #[tokio::test]
+    async fn find_indicator_data() {
+        let client = FixtureClient::new("technical_indicator/sma_month.json")
+            .route("function=RSI", "technical_indicator/rsi.json");
+        let api = crate::set_api("demo", client);
+        let rsi = api
+            .technical_indicator("RSI", "IBM", TechnicalIndicatorInterval::Weekly)
+            .time_period(10)
+            .series_type("open")
+            .json()
+            .await
+            .unwrap();
+        let data = rsi.data().unwrap();
+        assert_eq!(data.latest().unwrap().time(), "2024-02-16");
+        assert_eq!(
+            data.find("2024-02-09").unwrap().values()["RSI"].to_string(),
+            "78.1402"
+        );
+        assert!(data.find("2024-02-10").is_none());
+
+        let latest = data.latest_n(2).unwrap();
+        let times = latest.iter().map(|data| data.time()).collect::<Vec<_>>();
+        assert_eq!(times, ["2024-02-16", "2024-02-09"]);
+        assert!(data.latest_n(4).is_err());
+
+        let between = data.between("2024-02-01", "2024-02-09");
+        let times = between.iter().map(|data| data.time()).collect::<Vec<_>>();
+        assert_eq!(times, ["2024-02-02", "2024-02-09"]);
+
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::FiveMin)
+            .time_period(10)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap();
+        let data = sma.data().unwrap();
+        assert_eq!(data.latest().unwrap().time(), "2009-01-30 19:55:00");
+        // date only bound covers whole day of intraday data
+        assert_eq!(data.between("2009-01-30", "2009-01-30").len(), 2);
+        assert_eq!(
+            data.between("2009-01-30 19:50:00", "2009-01-30 19:50:00")[0].time(),
+            "2009-01-30 19:50:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn meta_data() {
+        let client = FixtureClient::new("technical_indicator/sma_month.json")
+            .route("function=MACD", "technical_indicator/macd.json")
+            .route("function=MAMA", "technical_indicator/mama.json");
+        let api = crate::set_api("demo", client);
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::FiveMin)
+            .time_period(10)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap();
+        let meta = sma.meta();
+        assert_eq!(meta.symbol(), "IBM");
+        assert_eq!(meta.last_refreshed(), "2009-01-30 19:55:00");
+        assert_eq!(
+            meta.interval_enum(),
+            Some(TechnicalIndicatorInterval::FiveMin)
+        );
+        assert_eq!(meta.time_period(), Some(10));
+        assert_eq!(meta.series_type(), Some("close"));
+        assert!(meta.raw().is_empty());
+
+        let macd = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("open")
+            .json()
+            .await
+            .unwrap();
+        let meta = macd.meta();
+        assert_eq!(
+            meta.interval_enum(),
+            Some(TechnicalIndicatorInterval::Daily)
+        );
+        assert_eq!(meta.time_period(), None);
+        assert_eq!(meta.fast_period(), Some(12));
+        assert_eq!(meta.slow_period(), Some(26));
+        assert_eq!(meta.series_type(), Some("open"));
+        assert_eq!(meta.time_zone(), "US/Eastern");
+
+        let mama = api
+            .technical_indicator("MAMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("close")
+            .extra_param("fastlimit", 0.02)
+            .extra_param("slowlimit", 0.01)
+            .json()
+            .await
+            .unwrap();
+        let meta = mama.meta();
+        assert_eq!(meta.indicator(), "MESA Adaptive Moving Average (MAMA)");
+        assert_eq!(meta.fast_limit(), Some(0.02));
+        assert_eq!(meta.slow_limit(), Some(0.01));
+        assert_eq!(meta.series_type(), Some("close"));
+        assert_eq!(meta.time_zone(), "US/Eastern Time");
+        assert_eq!(meta.raw()["8: Source"], "realtime");
+    }
+
+    #[tokio::test]
+    async fn align_with_time_series() {
+        let client = FixtureClient::new("stock_time/daily_ibm.json")
+            .route("function=SMA", "technical_indicator/sma_20.json");
+        let api = crate::set_api("demo", client);
+        let series = api
+            .stock_time(StockFunction::Daily, "IBM")
+            .json()
+            .await
+            .unwrap();
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(20)
+            .series_type("close")
+            .json_single()
+            .await
+            .unwrap();
+
+        let rows = align(&series, sma.points());
+        assert_eq!(rows.len(), 25);
+        assert_eq!(rows[0].time(), "2024-01-10");
+        assert!(rows[..19].iter().all(|row| row.indicator().is_none()));
+        assert_eq!(rows[19].time(), "2024-02-07");
+        assert_eq!(rows[19].indicator(), Some(157.3093));
+        assert_eq!(rows[24].time(), "2024-02-14");
+        assert_eq!(rows[24].data().close().to_string(), "159.0928");
+        assert_eq!(
+            align_with(&series, sma.points(), AlignMode::Exact).dropped(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn align_weekly_indicator_as_of() {
+        let api = crate::set_api("demo", FixtureClient::new("stock_time/daily_ibm.json"));
+        let series = api
+            .stock_time(StockFunction::Daily, "IBM")
+            .json()
+            .await
+            .unwrap();
+        // weekly indicator dated on last trading day of week
+        let weekly = [
+            ("2024-01-26".to_string(), 55.1),
+            ("2024-02-02".to_string(), 57.4),
+            ("2024-02-09".to_string(), 61.2),
+            ("2024-02-16".to_string(), 63.0),
+        ];
+
+        let exact = align_with(&series, &weekly, AlignMode::Exact);
+        assert_eq!(exact.dropped(), 1);
+        assert_eq!(
+            exact
+                .rows()
+                .iter()
+                .filter(|row| row.indicator().is_some())
+                .count(),
+            3
+        );
+
+        let as_of = align_with(&series, &weekly, AlignMode::AsOf);
+        assert_eq!(as_of.dropped(), 1);
+        let rows = as_of.rows();
+        let row = rows.iter().find(|row| row.time() == "2024-02-07").unwrap();
+        assert_eq!(row.indicator(), Some(57.4));
+        assert!(rows[0].indicator().is_none());
+    }
+
+    #[tokio::test]
+    async fn time_period_is_validated() {
+        let client = FixtureClient::new("technical_indicator/sma.json");
+        let api = crate::set_api("demo", client.clone());
+        let error = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnexpectedParameter { .. }));
+
+        let error = api
+            .technical_indicator("RSI", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(0)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParameter { .. }));
+        assert_eq!(client.calls(), 0);
+
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(sma.data().unwrap().len(), 3);
+        assert!(client.urls()[0].contains("function=SMA&symbol=IBM&interval=daily&time_period=10"));
+    }
+
+    #[tokio::test]
+    async fn limit_params() {
+        let client = FixtureClient::new("technical_indicator/mama.json")
+            .route("function=SAR", "technical_indicator/sar.json");
+        let api = crate::set_api("demo", client.clone());
+        let mama = api
+            .technical_indicator("MAMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("close")
+            .fast_limit(0.1 + 0.2)
+            .slow_limit(0.05)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(mama.meta().symbol(), "IBM");
+
+        let sar = api
+            .technical_indicator("SAR", "IBM", TechnicalIndicatorInterval::Daily)
+            .acceleration(0.05)
+            .maximum(0.300_000_000_01)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(sar.meta().indicator(), "Parabolic SAR (SAR)");
+
+        // float params are sent without rounding noise
+        let urls = client.urls();
+        assert!(urls[0].contains("&series_type=close&fastlimit=0.3&slowlimit=0.05&"));
+        assert!(urls[1].contains("&acceleration=0.05&maximum=0.3&"));
+
+        let error = api
+            .technical_indicator("MAMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("close")
+            .fast_limit(1.5)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidParameter { parameter, value } if parameter == "fastlimit" && value == "1.5"
+        ));
+
+        let error = api
+            .technical_indicator("SAR", "IBM", TechnicalIndicatorInterval::Daily)
+            .acceleration(0.0)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParameter { .. }));
+
+        let error = api
+            .technical_indicator("EMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .slow_limit(0.05)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedParameter { parameter, .. } if parameter == "slowlimit"
+        ));
+    }
+
+    #[tokio::test]
+    async fn month_of_intraday_data() {
+        let client = FixtureClient::new("technical_indicator/sma_month.json");
+        let api = crate::set_api("demo", client.clone());
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::FiveMin)
+            .time_period(10)
+            .series_type("close")
+            .month("2009-01")
+            .json()
+            .await
+            .unwrap();
+        let data = sma.data().unwrap();
+        assert!(data.iter().all(|data| data.time().starts_with("2009-01")));
+        assert!(client.urls()[0].contains(
+            "function=SMA&symbol=IBM&interval=5min&time_period=10&series_type=close&month=2009-01"
+        ));
+
+        let error = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .month("2009-01")
+            .json()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "month is not supported by SMA with daily interval"
+        );
+
+        let error = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::FiveMin)
+            .time_period(10)
+            .series_type("close")
+            .month("2009-13")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParameter { .. }));
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn params_are_validated_per_function() {
+        let client = FixtureClient::new("technical_indicator/sma.json");
+        let api = crate::set_api("demo", client.clone());
+
+        let error = api
+            .technical_indicator("BBANDS", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(20)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingParameter { parameter, function }
+                if parameter == "series_type" && function == "BBANDS"
+        ));
+
+        let error = api
+            .technical_indicator("ADX", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(14)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedParameter { parameter, function }
+                if parameter == "series_type" && function == "ADX"
+        ));
+
+        let error = api
+            .technical_indicator("STOCH", "IBM", TechnicalIndicatorInterval::Daily)
+            .extra_param("time_period", 14)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedParameter { parameter, .. } if parameter == "time_period"
+        ));
+
+        let error = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("close")
+            .nb_dev_up(2)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnexpectedParameter { parameter, .. } if parameter == "nbdevup"
+        ));
+
+        let error = api
+            .technical_indicator("EMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingParameter { parameter, .. } if parameter == "series_type"
+        ));
+
+        let error = api
+            .technical_indicator("VWAP", "IBM", TechnicalIndicatorInterval::FiveMin)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::UnexpectedParameter { .. }));
+        assert_eq!(client.calls(), 0);
+
+        // custom function is not validated
+        let custom = TechnicalIndicatorFunction::Custom("SMA_CUSTOM".to_string());
+        api.technical_indicator(custom, "IBM", TechnicalIndicatorInterval::Daily)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn fetch_months_merges_months() {
+        let client = FixtureClient::new("error/rate_limit.json")
+            .route("month=2023-12", "technical_indicator/sma_2023_12.json")
+            .route("month=2024-01", "technical_indicator/sma_2024_01.json");
+        let api = crate::set_api("demo", client);
+        let mut builder =
+            api.technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::SixtyMin);
+        builder.time_period(10).series_type("close");
+
+        let sma = builder.fetch_months("2023-12"..="2024-01").await.unwrap();
+        let sma = SingleValueIndicator::try_from(sma).unwrap();
+        let times = sma
+            .points()
+            .iter()
+            .map(|(time, _)| time.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            [
+                "2023-12-29 18:00",
+                "2023-12-29 19:00",
+                "2024-01-02 04:00",
+                "2024-01-02 05:00",
+            ]
+        );
+        assert_eq!(sma.points()[2].1.to_string(), "141.2254");
+        assert_eq!(sma.meta().last_refreshed(), "2024-01-02 05:00");
+
+        let error = builder
+            .fetch_months("2023-12"..="2024-02")
+            .await
+            .unwrap_err();
+        let Error::PartialBackfill { month, fetched, .. } = error else {
+            panic!("expected partial backfill");
+        };
+        assert_eq!(month, "2024-02");
+        assert_eq!(fetched.data().unwrap().len(), 4);
+
+        let error = builder
+            .fetch_months("2024-02"..="2024-03")
+            .await
+            .unwrap_err();
+        assert!(matches!(error.inner(), Error::AlphaVantageInformation(_)));
+    }
+
+    #[tokio::test]
+    async fn single_value_points() {
+        let client = FixtureClient::new("technical_indicator/rsi.json")
+            .route("function=SMA", "technical_indicator/sma.json");
+        let api = crate::set_api("demo", client);
+        let sma = api
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .json_single()
+            .await
+            .unwrap();
+        assert_eq!(sma.meta().symbol(), "IBM");
+        assert_eq!(sma.meta().time_period(), Some(10));
+        assert_eq!(sma.meta().series_type(), Some("close"));
+        assert_eq!(sma.points()[0], ("2024-02-14".to_string(), 185.421));
+        assert_eq!(sma.points()[2], ("2024-02-16".to_string(), 186.309));
+
+        let rsi = api
+            .technical_indicator("RSI", "IBM", TechnicalIndicatorInterval::Weekly)
+            .time_period(10)
+            .series_type("open")
+            .json_single()
+            .await
+            .unwrap();
+        assert_eq!(rsi.meta().interval(), "weekly");
+        assert_eq!(rsi.meta().indicator(), "Relative Strength Index (RSI)");
+        assert_eq!(rsi.points().last().unwrap().1.to_string(), "79.0149");
+    }
+
+    #[tokio::test]
+    async fn macd_points() {
+        let client = FixtureClient::new("technical_indicator/macd.json");
+        let api = crate::set_api("demo", client.clone());
+        let macd = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("open")
+            .fast_period(12)
+            .slow_period(26)
+            .signal_period(9)
+            .json_macd()
+            .await
+            .unwrap();
+        assert!(client.urls()[0].contains("&fastperiod=12&slowperiod=26&signalperiod=9"));
+        assert_eq!(macd.meta().fast_period(), Some(12));
+        assert_eq!(macd.meta().signal_period(), Some(9));
+        let points = macd.points();
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].time(), "2024-02-14");
+        assert_eq!(points[0].macd().to_string(), "6.3117");
+        assert_eq!(points[0].signal().to_string(), "6.0134");
+        assert_eq!(points[0].histogram().to_string(), "0.2983");
+        assert_eq!(points[2].time(), "2024-02-16");
+        assert_eq!(points[2].macd().to_string(), "6.5823");
+
+        let api = crate::set_api(
+            "demo",
+            FixtureClient::new("technical_indicator/macd_missing_signal.json"),
+        );
+        let error = api
+            .technical_indicator("MACD", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("open")
+            .json_macd()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingValue { ref time, ref key } if time == "2024-02-15" && key == "MACD_Signal"
+        ));
+    }
+
+    #[tokio::test]
+    async fn bbands_points() {
+        let client = FixtureClient::new("technical_indicator/bbands.json");
+        let api = crate::set_api("demo", client.clone());
+        let bbands = api
+            .technical_indicator("BBANDS", "IBM", TechnicalIndicatorInterval::Weekly)
+            .time_period(5)
+            .series_type("close")
+            .nb_dev_up(3)
+            .nb_dev_dn(3)
+            .ma_type(MovingAverageType::Ema)
+            .json_bbands()
+            .await
+            .unwrap();
+        assert!(client.urls()[0].contains("&nbdevup=3&nbdevdn=3&matype=1"));
+        let points = bbands.points();
+        assert_eq!(points[0].time(), "2024-02-09");
+        assert_eq!(points[0].upper().to_string(), "195.25");
+        assert_eq!(points[0].middle().to_string(), "175.5");
+        assert_eq!(points[0].lower().to_string(), "155.75");
+
+        let latest = &points[1];
+        assert_eq!(latest.upper().to_string(), "200");
+        assert_eq!(latest.middle().to_string(), "180");
+        assert_eq!(latest.lower().to_string(), "160");
+        assert_eq!(latest.bandwidth(), Some(40.0 / 180.0));
+        assert_eq!(latest.percent_b(190.0), Some(0.75));
+    }
+
+    #[tokio::test]
+    async fn stoch_points() {
+        let client = FixtureClient::new("technical_indicator/stoch.json")
+            .route("function=STOCHF&", "technical_indicator/stochf.json");
+        let api = crate::set_api("demo", client.clone());
+        let stoch = api
+            .technical_indicator("STOCH", "IBM", TechnicalIndicatorInterval::Daily)
+            .fast_k_period(5)
+            .slow_k_period(3)
+            .slow_d_period(3)
+            .slow_k_ma_type(MovingAverageType::Sma)
+            .slow_d_ma_type(MovingAverageType::Ema)
+            .json_stoch()
+            .await
+            .unwrap();
+        assert!(
+            client.urls()[0]
+                .contains("&fastkperiod=5&slowkperiod=3&slowdperiod=3&slowkmatype=0&slowdmatype=1")
+        );
+        let latest = stoch.points().last().unwrap();
+        assert_eq!(latest.time(), "2024-02-16");
+        assert_eq!(latest.k().to_string(), "85.4321");
+        assert_eq!(latest.d().to_string(), "80.1234");
+
+        let stochf = api
+            .technical_indicator("STOCHF", "IBM", TechnicalIndicatorInterval::Daily)
+            .json_stoch()
+            .await
+            .unwrap();
+        assert_eq!(stochf.points()[0].k().to_string(), "88.5");
+        assert_eq!(stochf.points()[0].d().to_string(), "79");
+
+        let error = api
+            .technical_indicator("STOCH", "IBM", TechnicalIndicatorInterval::Daily)
+            .slow_k_period(0)
+            .json_stoch()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidParameter { ref parameter, .. } if parameter == "slowkperiod"
+        ));
+    }
+
+    #[tokio::test]
+    async fn aroon_and_volume_points() {
+        let client = FixtureClient::new("technical_indicator/aroon.json")
+            .route("function=OBV&", "technical_indicator/obv.json");
+        let api = crate::set_api("demo", client.clone());
+        let aroon = api
+            .technical_indicator("AROON", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(14)
+            .json_aroon()
+            .await
+            .unwrap();
+        assert_eq!(aroon.meta().time_period(), Some(14));
+        let latest = aroon.points().last().unwrap();
+        assert_eq!(latest.time(), "2024-02-16");
+        assert_eq!(latest.up().to_string(), "92.8571");
+        assert_eq!(latest.down().to_string(), "7.1429");
+
+        let obv = api
+            .technical_indicator("OBV", "IBM", TechnicalIndicatorInterval::Weekly)
+            .json_single()
+            .await
+            .unwrap();
+        assert_eq!(obv.points().len(), 3);
+        assert_eq!(obv.points()[2], ("2024-02-16".to_string(), 1_231_482_571.0));
+        let urls = client.urls();
+        assert!(urls[0].contains("function=AROON&symbol=IBM&interval=daily&time_period=14&"));
+        assert!(urls.iter().all(|url| !url.contains("series_type")));
+
+        // series type is rejected for function which does not use it
+        for (function, time_period) in [
+            ("AROON", Some(14)),
+            ("ADX", Some(14)),
+            ("OBV", None),
+            ("AD", None),
+        ] {
+            let mut builder =
+                api.technical_indicator(function, "IBM", TechnicalIndicatorInterval::Daily);
+            if let Some(time_period) = time_period {
+                builder.time_period(time_period);
+            }
+            let error = builder.series_type("close").json().await.unwrap_err();
+            assert!(matches!(
+                error,
+                Error::UnexpectedParameter { ref parameter, .. } if parameter == "series_type"
+            ));
+        }
+        assert_eq!(client.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn fast_d_params_are_validated() {
+        let client = FixtureClient::new("technical_indicator/stochf.json");
+        let api = crate::set_api("demo", client.clone());
+        api.technical_indicator("STOCHF", "IBM", TechnicalIndicatorInterval::Daily)
+            .extra_param("fastdperiod", 3)
+            .extra_param("fastdmatype", 0)
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(client.calls(), 1);
+
+        let error = api
+            .technical_indicator("STOCH", "IBM", TechnicalIndicatorInterval::Daily)
+            .extra_param("fastdperiod", 3)
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            Error::UnexpectedParameter { parameter, .. } if parameter == "fastdperiod"
+        ));
+        assert_eq!(client.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn extra_param_replaces_typed_param() {
+        let client = FixtureClient::new("technical_indicator/sma.json");
+        crate::set_api("demo", client.clone())
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .time_period(10)
+            .series_type("close")
+            .extra_param("time_period", 20)
+            .json()
+            .await
+            .unwrap();
+        let url = &client.urls()[0];
+        assert_eq!(url.matches("time_period=").count(), 1);
+        assert!(url.contains("&time_period=20"));
+    }
+
+    #[tokio::test]
+    async fn invalid_params_are_not_wrapped() {
+        let client = FixtureClient::new("technical_indicator/sma.json");
+        let error = crate::set_api("demo", client.clone())
+            .technical_indicator("SMA", "IBM", TechnicalIndicatorInterval::Daily)
+            .series_type("close")
+            .json()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingParameter { parameter, .. } if parameter == "time_period"
+        ));
+        assert_eq!(client.calls(), 0);
+    }
 }
@@ -0,0 +1,174 @@
+//! Fixture backed client shared by unit tests
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::client::{HttpClient, HttpResponse};
+use crate::error::{Error, Result};
+
+/// Read fixture at `path` relative to `fixtures` dir
+pub(crate) fn fixture(path: &str) -> Vec<u8> {
+    let path = format!("{}/fixtures/{path}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read(&path).unwrap_or_else(|_| panic!("missing {path}"))
+}
+
+/// Response served by [`FixtureClient`], `None` fails request
+type Response = Option<HttpResponse<Vec<u8>>>;
+
+/// Request received by [`FixtureClient`]
+#[derive(Debug, Clone)]
+pub(crate) struct Request {
+    pub(crate) url: String,
+    pub(crate) headers: Vec<(String, String)>,
+}
+
+/// Client which answers requests with body of fixtures and records
+/// requests. Queued responses are returned first in order, then response of
+/// first route whose pattern is contained in url and at last fallback
+/// response. Missing response fails request with `GetRequestFailed` same as
+/// client which cannot reach server. Body is kept as bytes so binary fixture
+/// can be served through `get_bytes`. Clone shares queue and record so they
+/// can be checked after client is moved into `ApiClient`
+#[derive(Clone)]
+pub(crate) struct FixtureClient {
+    queue: Arc<Mutex<VecDeque<Response>>>,
+    routes: Vec<(String, Response)>,
+    fallback: Response,
+    delay: Duration,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+// some helpers are only used by tests of optional features
+#[allow(dead_code)]
+impl FixtureClient {
+    /// Create client serving fixture at `path` relative to `fixtures` dir
+    pub(crate) fn new(path: &str) -> Self {
+        Self::response(200, fixture(path))
+    }
+
+    /// Create client serving `body` with `status`
+    pub(crate) fn status(status: u16, body: &str) -> Self {
+        Self::response(status, body.into())
+    }
+
+    fn response(status: u16, body: Vec<u8>) -> Self {
+        Self {
+            queue: Arc::default(),
+            routes: Vec::new(),
+            fallback: Some(HttpResponse { status, body }),
+            delay: Duration::ZERO,
+            requests: Arc::default(),
+        }
+    }
+
+    /// Serve fixture at `path` for url containing `pattern`
+    pub(crate) fn route(mut self, pattern: &str, path: &str) -> Self {
+        self.routes.push((
+            pattern.to_string(),
+            Some(HttpResponse {
+                status: 200,
+                body: fixture(path),
+            }),
+        ));
+        self
+    }
+
+    /// Serve `body` with `status` for url containing `pattern`
+    pub(crate) fn route_status(mut self, pattern: &str, status: u16, body: &str) -> Self {
+        self.routes.push((
+            pattern.to_string(),
+            Some(HttpResponse {
+                status,
+                body: body.into(),
+            }),
+        ));
+        self
+    }
+
+    /// Fail request whose url contains `pattern`
+    pub(crate) fn route_error(mut self, pattern: &str) -> Self {
+        self.routes.push((pattern.to_string(), None));
+        self
+    }
+
+    /// Serve `body` with `status` once before routes, in order of queueing
+    pub(crate) fn queue(self, status: u16, body: &str) -> Self {
+        self.queue.lock().unwrap().push_back(Some(HttpResponse {
+            status,
+            body: body.into(),
+        }));
+        self
+    }
+
+    /// Fail request once before routes, in order of queueing
+    pub(crate) fn queue_error(self) -> Self {
+        self.queue.lock().unwrap().push_back(None);
+        self
+    }
+
+    /// Wait for `delay` before responding to each request
+    pub(crate) fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Return number of requests received by client
+    pub(crate) fn calls(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    /// Return url of requests received by client in order
+    pub(crate) fn urls(&self) -> Vec<String> {
+        self.requests()
+            .into_iter()
+            .map(|request| request.url)
+            .collect()
+    }
+
+    /// Return requests received by client in order
+    pub(crate) fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    async fn respond(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse<Vec<u8>>> {
+        self.requests.lock().unwrap().push(Request {
+            url: url.to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+                .collect(),
+        });
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        let queued = self.queue.lock().unwrap().pop_front();
+        let response = queued.unwrap_or_else(|| {
+            self.routes
+                .iter()
+                .find(|(pattern, _)| url.contains(pattern.as_str()))
+                .map_or(&self.fallback, |(_, response)| response)
+                .clone()
+        });
+        response.ok_or(Error::GetRequestFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for FixtureClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        let response = self.respond(url, headers).await?;
+        Ok(HttpResponse {
+            status: response.status,
+            body: String::from_utf8(response.body).expect("fixture is not valid UTF-8"),
+        })
+    }
+
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<HttpResponse<Vec<u8>>> {
+        self.respond(url, headers).await
+    }
+}